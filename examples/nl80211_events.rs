@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use wl_nl80211::Nl80211MulticastGroup;
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    rt.block_on(watch_events());
+}
+
+async fn watch_events() {
+    let (connection, mut handle, _) = wl_nl80211::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut events = handle
+        .events(&[
+            Nl80211MulticastGroup::Config,
+            Nl80211MulticastGroup::Scan,
+            Nl80211MulticastGroup::Regulatory,
+            Nl80211MulticastGroup::Mlme,
+        ])
+        .await
+        .unwrap();
+
+    while let Some(event) = events.try_next().await.unwrap() {
+        println!("{:?}", event);
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use std::env::args;
+
+use anyhow::{bail, Context, Error};
+use futures::stream::TryStreamExt;
+use wl_nl80211::{Nl80211FrameType, Nl80211FrameTypeMgmt};
+
+fn main() -> Result<(), Error> {
+    let argv: Vec<_> = args().collect();
+
+    if argv.len() < 2 {
+        eprintln!("Usage: nl80211_register_frames <interface index>");
+        bail!("Required arguments not given");
+    }
+
+    let err_msg = format!("Invalid interface index value: {}", argv[1]);
+    let index = argv[1].parse::<u32>().context(err_msg)?;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    rt.block_on(dump_frames(index));
+
+    Ok(())
+}
+
+async fn dump_frames(if_index: u32) {
+    let (connection, handle, _) = wl_nl80211::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    // Probe requests and any action frame
+    let mut frames = handle
+        .interface()
+        .register_frames(
+            if_index,
+            &[
+                (
+                    Nl80211FrameType::Management(
+                        Nl80211FrameTypeMgmt::ProbeReq,
+                    ),
+                    Vec::new(),
+                ),
+                (
+                    Nl80211FrameType::Management(Nl80211FrameTypeMgmt::Action),
+                    Vec::new(),
+                ),
+            ],
+        )
+        .stream()
+        .await
+        .unwrap();
+
+    while let Some(frame) = frames.try_next().await.unwrap() {
+        println!("{:?}", frame);
+    }
+}
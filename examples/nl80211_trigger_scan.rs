@@ -3,7 +3,6 @@
 use std::env::args;
 
 use anyhow::{bail, Context, Error};
-use futures::stream::TryStreamExt;
 
 fn main() -> Result<(), Error> {
     let argv: Vec<_> = args().collect();
@@ -35,21 +34,15 @@ async fn dump_scan(if_index: u32) {
         .passive(true)
         .build();
 
-    let mut scan_handle = handle.scan().trigger(attrs).execute().await;
-
-    let mut msgs = Vec::new();
-    while let Some(msg) = scan_handle.try_next().await.unwrap() {
-        msgs.push(msg);
-    }
-    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+    let bss_list = handle
+        .scan()
+        .trigger_and_wait(attrs)
+        .timeout(std::time::Duration::from_secs(30))
+        .execute()
+        .await
+        .unwrap();
 
-    let mut dump = handle.scan().dump(if_index).execute().await;
-    let mut msgs = Vec::new();
-    while let Some(msg) = dump.try_next().await.unwrap() {
-        msgs.push(msg);
-    }
-    assert!(!msgs.is_empty());
-    for msg in msgs {
-        println!("{:?}", msg);
+    for bss in bss_list {
+        println!("{:?}", bss);
     }
 }
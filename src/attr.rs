@@ -32,12 +32,14 @@
 use anyhow::Context;
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
-    parsers::{parse_string, parse_u16, parse_u32, parse_u64, parse_u8},
+    parsers::{
+        parse_i32, parse_string, parse_u16, parse_u32, parse_u64, parse_u8,
+    },
     DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
 use crate::{
-    bytes::{write_u16, write_u32, write_u64}, reg::{Nl80211RegDomType, Nl80211RegdomInitiator}, scan::{Nla80211ScanFreqNlas, Nla80211ScanSsidNlas}, wiphy::Nl80211Commands, Nl80211AkmSuite, Nl80211AuthType, Nl80211Band, Nl80211BandTypes, Nl80211BssInfo, Nl80211ChannelWidth, Nl80211CipherSuite, Nl80211Command, Nl80211Elements, Nl80211ExtFeature, Nl80211ExtFeatures, Nl80211ExtendedCapability, Nl80211Features, Nl80211FrameType, Nl80211HtCapabilityMask, Nl80211HtWiphyChannelType, Nl80211IfMode, Nl80211IfTypeExtCapa, Nl80211IfTypeExtCapas, Nl80211IfaceComb, Nl80211IfaceFrameType, Nl80211InterfaceType, Nl80211InterfaceTypes, Nl80211MloLink, Nl80211ScanFlags, Nl80211SchedScanMatch, Nl80211SchedScanPlan, Nl80211StationInfo, Nl80211TransmitQueueStat, Nl80211VhtCapability, Nl80211WowlanTrigersSupport
+    bytes::{write_i32, write_u16, write_u32, write_u64}, reg::{Nl80211DfsRegion, Nl80211RegDomType, Nl80211RegRule, Nl80211RegdomInitiator}, scan::{Nla80211ScanFreqNlas, Nla80211ScanSsidNlas}, wiphy::Nl80211Commands, Nl80211AkmSuite, Nl80211AuthType, Nl80211Band, Nl80211BandTypes, Nl80211BssInfo, Nl80211BssSelect, Nl80211ChannelWidth, Nl80211CipherSuite, Nl80211Command, Nl80211CqmAttr, Nl80211ElementHtCap, Nl80211Elements, Nl80211ExtFeature, Nl80211ExtFeatures, Nl80211ExtendedCapability, Nl80211Features, Nl80211FrameType, Nl80211He6GhzCapa, Nl80211HtCapabilityMask, Nl80211HtWiphyChannelType, Nl80211IfMode, Nl80211IfTypeExtCapa, Nl80211IfTypeExtCapas, Nl80211IfaceComb, Nl80211IfaceFrameType, Nl80211InterfaceType, Nl80211InterfaceTypes, Nl80211KeyAttr, Nl80211Mfp, Nl80211MloLink, Nl80211MonitorFlags, Nl80211PsState, Nl80211RadarEventType, Nl80211ReasonCode, Nl80211RxFrameFlags, Nl80211ScanFlags, Nl80211SchedScanMatch, Nl80211SchedScanPlan, Nl80211StationFlagUpdate, Nl80211StationInfo, Nl80211StatusCode, Nl80211SurveyInfoAttr, Nl80211TransmitQueueStat, Nl80211TxPowerSetting, Nl80211VhtCapability, Nl80211WowlanTrigersSupport
 };
 
 const ETH_ALEN: usize = 6;
@@ -117,7 +119,7 @@ const NL80211_ATTR_IFNAME: u16 = 4;
 const NL80211_ATTR_IFTYPE: u16 = 5;
 const NL80211_ATTR_MAC: u16 = 6;
 // const NL80211_ATTR_KEY_DATA:u16 = 7;
-// const NL80211_ATTR_KEY_IDX:u16 = 8;
+const NL80211_ATTR_KEY_IDX: u16 = 8;
 // const NL80211_ATTR_KEY_CIPHER:u16 = 9;
 // const NL80211_ATTR_KEY_SEQ:u16 = 10;
 // const NL80211_ATTR_KEY_DEFAULT:u16 = 11;
@@ -125,14 +127,14 @@ const NL80211_ATTR_BEACON_INTERVAL:u16 = 12;
 const NL80211_ATTR_DTIM_PERIOD:u16 = 13;
 const NL80211_ATTR_BEACON_HEAD:u16 = 14;
 const NL80211_ATTR_BEACON_TAIL:u16 = 15;
-// const NL80211_ATTR_STA_AID:u16 = 16;
+const NL80211_ATTR_STA_AID: u16 = 16;
 // const NL80211_ATTR_STA_FLAGS:u16 = 17;
-// const NL80211_ATTR_STA_LISTEN_INTERVAL:u16 = 18;
-// const NL80211_ATTR_STA_SUPPORTED_RATES:u16 = 19;
+const NL80211_ATTR_STA_LISTEN_INTERVAL: u16 = 18;
+const NL80211_ATTR_STA_SUPPORTED_RATES: u16 = 19;
 // const NL80211_ATTR_STA_VLAN:u16 = 20;
 const NL80211_ATTR_STA_INFO: u16 = 21;
 const NL80211_ATTR_WIPHY_BANDS: u16 = 22;
-// const NL80211_ATTR_MNTR_FLAGS:u16 = 23;
+const NL80211_ATTR_MNTR_FLAGS: u16 = 23;
 // const NL80211_ATTR_MESH_ID:u16 = 24;
 // const NL80211_ATTR_STA_PLINK_ACTION:u16 = 25;
 // const NL80211_ATTR_MPATH_NEXT_HOP:u16 = 26;
@@ -140,17 +142,17 @@ const NL80211_ATTR_WIPHY_BANDS: u16 = 22;
 // const NL80211_ATTR_BSS_CTS_PROT:u16 = 28;
 // const NL80211_ATTR_BSS_SHORT_PREAMBLE:u16 = 29;
 // const NL80211_ATTR_BSS_SHORT_SLOT_TIME:u16 = 30;
-// const NL80211_ATTR_HT_CAPABILITY:u16 = 31;
+const NL80211_ATTR_HT_CAPABILITY: u16 = 31;
 const NL80211_ATTR_SUPPORTED_IFTYPES: u16 = 32;
 const NL80211_ATTR_REG_ALPHA2:u16 = 33;
-// const NL80211_ATTR_REG_RULES:u16 = 34;
+const NL80211_ATTR_REG_RULES: u16 = 34;
 // const NL80211_ATTR_MESH_CONFIG:u16 = 35;
 // const NL80211_ATTR_BSS_BASIC_RATES:u16 = 36;
 // const NL80211_ATTR_WIPHY_TXQ_PARAMS:u16 = 37;
 const NL80211_ATTR_WIPHY_FREQ: u16 = 38;
 const NL80211_ATTR_WIPHY_CHANNEL_TYPE: u16 = 39;
 // const NL80211_ATTR_KEY_DEFAULT_MGMT:u16 = 40;
-// const NL80211_ATTR_MGMT_SUBTYPE:u16 = 41;
+const NL80211_ATTR_MGMT_SUBTYPE: u16 = 41;
 const NL80211_ATTR_IE:u16 = 42;
 const NL80211_ATTR_MAX_NUM_SCAN_SSIDS: u16 = 43;
 const NL80211_ATTR_SCAN_FREQUENCIES: u16 = 44;
@@ -163,7 +165,7 @@ const NL80211_ATTR_SUPPORTED_COMMANDS: u16 = 50;
 const NL80211_ATTR_FRAME:u16 = 51;
 const NL80211_ATTR_SSID: u16 = 52;
 const NL80211_ATTR_AUTH_TYPE:u16 = 53;
-// const NL80211_ATTR_REASON_CODE:u16 = 54;
+const NL80211_ATTR_REASON_CODE: u16 = 54;
 // const NL80211_ATTR_KEY_TYPE:u16 = 55;
 const NL80211_ATTR_MAX_SCAN_IE_LEN: u16 = 56;
 const NL80211_ATTR_CIPHER_SUITES: u16 = 57;
@@ -174,39 +176,39 @@ const NL80211_ATTR_WIPHY_RETRY_SHORT: u16 = 61;
 const NL80211_ATTR_WIPHY_RETRY_LONG: u16 = 62;
 const NL80211_ATTR_WIPHY_FRAG_THRESHOLD: u16 = 63;
 const NL80211_ATTR_WIPHY_RTS_THRESHOLD: u16 = 64;
-// const NL80211_ATTR_TIMED_OUT:u16 = 65;
-// const NL80211_ATTR_USE_MFP:u16 = 66;
-// const NL80211_ATTR_STA_FLAGS2:u16 = 67;
-// const NL80211_ATTR_CONTROL_PORT:u16 = 68;
+const NL80211_ATTR_TIMED_OUT: u16 = 65;
+const NL80211_ATTR_USE_MFP: u16 = 66;
+const NL80211_ATTR_STA_FLAGS2: u16 = 67;
+const NL80211_ATTR_CONTROL_PORT: u16 = 68;
 // const NL80211_ATTR_TESTDATA:u16 = 69;
 const NL80211_ATTR_PRIVACY:u16 = 70;
-// const NL80211_ATTR_DISCONNECTED_BY_AP:u16 = 71;
-// const NL80211_ATTR_STATUS_CODE:u16 = 72;
+const NL80211_ATTR_DISCONNECTED_BY_AP: u16 = 71;
+const NL80211_ATTR_STATUS_CODE: u16 = 72;
 const NL80211_ATTR_CIPHER_SUITES_PAIRWISE:u16 = 73;
 const NL80211_ATTR_CIPHER_SUITE_GROUP:u16 = 74;
 const NL80211_ATTR_WPA_VERSIONS:u16 = 75;
 const NL80211_ATTR_AKM_SUITES:u16 = 76;
-// const NL80211_ATTR_REQ_IE:u16 = 77;
-// const NL80211_ATTR_RESP_IE:u16 = 78;
-// const NL80211_ATTR_PREV_BSSID:u16 = 79;
-// const NL80211_ATTR_KEY:u16 = 80;
+const NL80211_ATTR_REQ_IE: u16 = 77;
+const NL80211_ATTR_RESP_IE: u16 = 78;
+const NL80211_ATTR_PREV_BSSID: u16 = 79;
+const NL80211_ATTR_KEY: u16 = 80;
 // const NL80211_ATTR_KEYS:u16 = 81;
 // const NL80211_ATTR_PID:u16 = 82;
 const NL80211_ATTR_4ADDR: u16 = 83;
-// const NL80211_ATTR_SURVEY_INFO:u16 = 84;
+const NL80211_ATTR_SURVEY_INFO: u16 = 84;
 // const NL80211_ATTR_PMKID:u16 = 85;
 const NL80211_ATTR_MAX_NUM_PMKIDS: u16 = 86;
-// const NL80211_ATTR_DURATION:u16 = 87;
-// const NL80211_ATTR_COOKIE:u16 = 88;
+const NL80211_ATTR_DURATION: u16 = 87;
+const NL80211_ATTR_COOKIE: u16 = 88;
 const NL80211_ATTR_WIPHY_COVERAGE_CLASS: u16 = 89;
 // const NL80211_ATTR_TX_RATES:u16 = 90;
 const NL80211_ATTR_FRAME_MATCH:u16 = 91;
-// const NL80211_ATTR_ACK:u16 = 92;
+const NL80211_ATTR_ACK: u16 = 92;
 const NL80211_ATTR_PS_STATE:u16 = 93;
-// const NL80211_ATTR_CQM:u16 = 94;
-// const NL80211_ATTR_LOCAL_STATE_CHANGE:u16 = 95;
+const NL80211_ATTR_CQM: u16 = 94;
+const NL80211_ATTR_LOCAL_STATE_CHANGE: u16 = 95;
 // const NL80211_ATTR_AP_ISOLATE:u16 = 96;
-// const NL80211_ATTR_WIPHY_TX_POWER_SETTING:u16 = 97;
+const NL80211_ATTR_WIPHY_TX_POWER_SETTING: u16 = 97;
 const NL80211_ATTR_WIPHY_TX_POWER_LEVEL: u16 = 98;
 const NL80211_ATTR_TX_FRAME_TYPES: u16 = 99;
 const NL80211_ATTR_RX_FRAME_TYPES: u16 = 100;
@@ -245,28 +247,28 @@ const NL80211_ATTR_ROAM_SUPPORT: u16 = 131;
 const NL80211_ATTR_SCHED_SCAN_MATCH: u16 = 132;
 const NL80211_ATTR_MAX_MATCH_SETS: u16 = 133;
 // const NL80211_ATTR_PMKSA_CANDIDATE:u16 = 134;
-// const NL80211_ATTR_TX_NO_CCK_RATE:u16 = 135;
+const NL80211_ATTR_TX_NO_CCK_RATE: u16 = 135;
 // const NL80211_ATTR_TDLS_ACTION:u16 = 136;
 // const NL80211_ATTR_TDLS_DIALOG_TOKEN:u16 = 137;
 // const NL80211_ATTR_TDLS_OPERATION:u16 = 138;
 const NL80211_ATTR_TDLS_SUPPORT: u16 = 139;
 const NL80211_ATTR_TDLS_EXTERNAL_SETUP: u16 = 140;
 // const NL80211_ATTR_DEVICE_AP_SME:u16 = 141;
-// const NL80211_ATTR_DONT_WAIT_FOR_ACK:u16 = 142;
+const NL80211_ATTR_DONT_WAIT_FOR_ACK: u16 = 142;
 const NL80211_ATTR_FEATURE_FLAGS: u16 = 143;
 const NL80211_ATTR_PROBE_RESP_OFFLOAD: u16 = 144;
 // const NL80211_ATTR_PROBE_RESP:u16 = 145;
-// const NL80211_ATTR_DFS_REGION:u16 = 146;
+const NL80211_ATTR_DFS_REGION: u16 = 146;
 // const NL80211_ATTR_DISABLE_HT:u16 = 147;
 const NL80211_ATTR_HT_CAPABILITY_MASK: u16 = 148;
 // const NL80211_ATTR_NOACK_MAP:u16 = 149;
 // const NL80211_ATTR_INACTIVITY_TIMEOUT:u16 = 150;
-// const NL80211_ATTR_RX_SIGNAL_DBM:u16 = 151;
-// const NL80211_ATTR_BG_SCAN_PERIOD:u16 = 152;
+const NL80211_ATTR_RX_SIGNAL_DBM: u16 = 151;
+const NL80211_ATTR_BG_SCAN_PERIOD: u16 = 152;
 const NL80211_ATTR_WDEV: u16 = 153;
 // const NL80211_ATTR_USER_REG_HINT_TYPE:u16 = 154;
 // const NL80211_ATTR_CONN_FAILED_REASON:u16 = 155;
-// const NL80211_ATTR_AUTH_DATA:u16 = 156;
+const NL80211_ATTR_AUTH_DATA: u16 = 156;
 const NL80211_ATTR_VHT_CAPABILITY: u16 = 157;
 const NL80211_ATTR_SCAN_FLAGS: u16 = 158;
 const NL80211_ATTR_CHANNEL_WIDTH: u16 = 159;
@@ -278,11 +280,11 @@ const NL80211_ATTR_CENTER_FREQ2: u16 = 161;
 // const NL80211_ATTR_ACL_POLICY:u16 = 165;
 const NL80211_ATTR_MAC_ADDRS: u16 = 166;
 // const NL80211_ATTR_MAC_ACL_MAX:u16 = 167;
-// const NL80211_ATTR_RADAR_EVENT:u16 = 168;
+const NL80211_ATTR_RADAR_EVENT: u16 = 168;
 const NL80211_ATTR_EXT_CAPA: u16 = 169;
 const NL80211_ATTR_EXT_CAPA_MASK: u16 = 170;
-// const NL80211_ATTR_STA_CAPABILITY:u16 = 171;
-// const NL80211_ATTR_STA_EXT_CAPABILITY:u16 = 172;
+const NL80211_ATTR_STA_CAPABILITY: u16 = 171;
+const NL80211_ATTR_STA_EXT_CAPABILITY: u16 = 172;
 // const NL80211_ATTR_PROTOCOL_FEATURES:u16 = 173;
 const NL80211_ATTR_SPLIT_WIPHY_DUMP: u16 = 174;
 // const NL80211_ATTR_DISABLE_VHT:u16 = 175;
@@ -293,12 +295,12 @@ const NL80211_ATTR_VHT_CAPABILITY_MASK: u16 = 176;
 // const NL80211_ATTR_MAX_CRIT_PROT_DURATION:u16 = 180;
 // const NL80211_ATTR_PEER_AID:u16 = 181;
 // const NL80211_ATTR_COALESCE_RULE:u16 = 182;
-// const NL80211_ATTR_CH_SWITCH_COUNT:u16 = 183;
-// const NL80211_ATTR_CH_SWITCH_BLOCK_TX:u16 = 184;
-// const NL80211_ATTR_CSA_IES:u16 = 185;
-// const NL80211_ATTR_CNTDWN_OFFS_BEACON:u16 = 186;
-// const NL80211_ATTR_CNTDWN_OFFS_PRESP:u16 = 187;
-// const NL80211_ATTR_RXMGMT_FLAGS:u16 = 188;
+const NL80211_ATTR_CH_SWITCH_COUNT: u16 = 183;
+const NL80211_ATTR_CH_SWITCH_BLOCK_TX: u16 = 184;
+const NL80211_ATTR_CSA_IES: u16 = 185;
+const NL80211_ATTR_CNTDWN_OFFS_BEACON: u16 = 186;
+const NL80211_ATTR_CNTDWN_OFFS_PRESP: u16 = 187;
+const NL80211_ATTR_RXMGMT_FLAGS: u16 = 188;
 // const NL80211_ATTR_STA_SUPPORTED_CHANNELS:u16 = 189;
 // const NL80211_ATTR_STA_SUPPORTED_OPER_CLASSES:u16 = 190;
 // const NL80211_ATTR_HANDLE_DFS:u16 = 191;
@@ -310,8 +312,8 @@ const NL80211_ATTR_VHT_CAPABILITY_MASK: u16 = 176;
 // const NL80211_ATTR_VENDOR_DATA:u16 = 197;
 // const NL80211_ATTR_VENDOR_EVENTS:u16 = 198;
 // const NL80211_ATTR_QOS_MAP:u16 = 199;
-// const NL80211_ATTR_MAC_HINT:u16 = 200;
-// const NL80211_ATTR_WIPHY_FREQ_HINT:u16 = 201;
+const NL80211_ATTR_MAC_HINT: u16 = 200;
+const NL80211_ATTR_WIPHY_FREQ_HINT: u16 = 201;
 // const NL80211_ATTR_MAX_AP_ASSOC_STA:u16 = 202;
 // const NL80211_ATTR_TDLS_PEER_CAPABILITY:u16 = 203;
 const NL80211_ATTR_SOCKET_OWNER:u16 = 204;
@@ -319,7 +321,7 @@ const NL80211_ATTR_SOCKET_OWNER:u16 = 204;
 const NL80211_ATTR_MAX_CSA_COUNTERS: u16 = 206;
 // const NL80211_ATTR_TDLS_INITIATOR:u16 = 207;
 // const NL80211_ATTR_USE_RRM:u16 = 208;
-// const NL80211_ATTR_WIPHY_DYN_ACK:u16 = 209;
+const NL80211_ATTR_WIPHY_DYN_ACK: u16 = 209;
 // const NL80211_ATTR_TSID:u16 = 210;
 // const NL80211_ATTR_USER_PRIO:u16 = 211;
 // const NL80211_ATTR_ADMITTED_TIME:u16 = 212;
@@ -328,7 +330,7 @@ const NL80211_ATTR_MAX_CSA_COUNTERS: u16 = 206;
 const NL80211_ATTR_MAC_MASK: u16 = 215;
 const NL80211_ATTR_WIPHY_SELF_MANAGED_REG: u16 = 216;
 const NL80211_ATTR_EXT_FEATURES: u16 = 217;
-// const NL80211_ATTR_SURVEY_RADIO_STATS:u16 = 218;
+const NL80211_ATTR_SURVEY_RADIO_STATS: u16 = 218;
 // const NL80211_ATTR_NETNS_FD:u16 = 219;
 const NL80211_ATTR_SCHED_SCAN_DELAY: u16 = 220;
 // const NL80211_ATTR_REG_INDOOR:u16 = 221;
@@ -337,12 +339,12 @@ const NL80211_ATTR_MAX_SCAN_PLAN_INTERVAL: u16 = 223;
 const NL80211_ATTR_MAX_SCAN_PLAN_ITERATIONS: u16 = 224;
 const NL80211_ATTR_SCHED_SCAN_PLANS: u16 = 225;
 // const NL80211_ATTR_PBSS:u16 = 226;
-// const NL80211_ATTR_BSS_SELECT:u16 = 227;
+const NL80211_ATTR_BSS_SELECT: u16 = 227;
 // const NL80211_ATTR_STA_SUPPORT_P2P_PS:u16 = 228;
 // const NL80211_ATTR_PAD:u16 = 229;
 const NL80211_ATTR_IFTYPE_EXT_CAPA: u16 = 230;
-// const NL80211_ATTR_MU_MIMO_GROUP_DATA:u16 = 231;
-// const NL80211_ATTR_MU_MIMO_FOLLOW_MAC_ADDR:u16 = 232;
+const NL80211_ATTR_MU_MIMO_GROUP_DATA: u16 = 231;
+const NL80211_ATTR_MU_MIMO_FOLLOW_MAC_ADDR: u16 = 232;
 // const NL80211_ATTR_SCAN_START_TIME_TSF:u16 = 233;
 // const NL80211_ATTR_SCAN_START_TIME_TSF_BSSID:u16 = 234;
 const NL80211_ATTR_MEASUREMENT_DURATION: u16 = 235;
@@ -358,7 +360,7 @@ const NL80211_ATTR_BANDS: u16 = 239;
 // const NL80211_ATTR_BSSID:u16 = 245;
 // const NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI:u16 = 246;
 // const NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST:u16 = 247;
-// const NL80211_ATTR_TIMEOUT_REASON:u16 = 248;
+const NL80211_ATTR_TIMEOUT_REASON: u16 = 248;
 // const NL80211_ATTR_FILS_ERP_USERNAME:u16 = 249;
 // const NL80211_ATTR_FILS_ERP_REALM:u16 = 250;
 // const NL80211_ATTR_FILS_ERP_NEXT_SEQ_NUM:u16 = 251;
@@ -373,13 +375,13 @@ const NL80211_ATTR_SCHED_SCAN_MAX_REQS: u16 = 256;
 // const NL80211_ATTR_EXTERNAL_AUTH_ACTION:u16 = 260;
 // const NL80211_ATTR_EXTERNAL_AUTH_SUPPORT:u16 = 261;
 // const NL80211_ATTR_NSS:u16 = 262;
-// const NL80211_ATTR_ACK_SIGNAL:u16 = 263;
+const NL80211_ATTR_ACK_SIGNAL: u16 = 263;
 const NL80211_ATTR_CONTROL_PORT_OVER_NL80211:u16 = 264;
 const NL80211_ATTR_TXQ_STATS: u16 = 265;
 const NL80211_ATTR_TXQ_LIMIT: u16 = 266;
 const NL80211_ATTR_TXQ_MEMORY_LIMIT: u16 = 267;
 const NL80211_ATTR_TXQ_QUANTUM: u16 = 268;
-// const NL80211_ATTR_HE_CAPABILITY:u16 = 269;
+const NL80211_ATTR_HE_CAPABILITY: u16 = 269;
 // const NL80211_ATTR_FTM_RESPONDER:u16 = 270;
 // const NL80211_ATTR_FTM_RESPONDER_STATS:u16 = 271;
 // const NL80211_ATTR_TIMEOUT:u16 = 272;
@@ -387,7 +389,7 @@ const NL80211_ATTR_TXQ_QUANTUM: u16 = 268;
 // const NL80211_ATTR_AIRTIME_WEIGHT:u16 = 274;
 // const NL80211_ATTR_STA_TX_POWER_SETTING:u16 = 275;
 // const NL80211_ATTR_STA_TX_POWER:u16 = 276;
-// const NL80211_ATTR_SAE_PASSWORD:u16 = 277;
+const NL80211_ATTR_SAE_PASSWORD: u16 = 277;
 // const NL80211_ATTR_TWT_RESPONDER:u16 = 278;
 // const NL80211_ATTR_HE_OBSS_PD:u16 = 279;
 // const NL80211_ATTR_WIPHY_EDMG_CHANNELS:u16 = 280;
@@ -403,7 +405,7 @@ const NL80211_ATTR_CONTROL_PORT_NO_PREAUTH:u16 = 286;
 const NL80211_ATTR_WIPHY_FREQ_OFFSET: u16 = 290;
 // const NL80211_ATTR_CENTER_FREQ1_OFFSET:u16 = 291;
 // const NL80211_ATTR_SCAN_FREQ_KHZ:u16 = 292;
-// const NL80211_ATTR_HE_6GHZ_CAPABILITY:u16 = 293;
+const NL80211_ATTR_HE_6GHZ_CAPABILITY: u16 = 293;
 // const NL80211_ATTR_FILS_DISCOVERY:u16 = 294;
 // const NL80211_ATTR_UNSOL_BCAST_PROBE_RESP:u16 = 295;
 // const NL80211_ATTR_S1G_CAPABILITY:u16 = 296;
@@ -418,13 +420,13 @@ const NL80211_ATTR_WIPHY_FREQ_OFFSET: u16 = 290;
 // const NL80211_ATTR_COLOR_CHANGE_ELEMS:u16 = 305;
 // const NL80211_ATTR_MBSSID_CONFIG:u16 = 306;
 // const NL80211_ATTR_MBSSID_ELEMS:u16 = 307;
-// const NL80211_ATTR_RADAR_BACKGROUND:u16 = 308;
+const NL80211_ATTR_RADAR_BACKGROUND: u16 = 308;
 const NL80211_ATTR_AP_SETTINGS_FLAGS:u16 = 309;
-// const NL80211_ATTR_EHT_CAPABILITY:u16 = 310;
+const NL80211_ATTR_EHT_CAPABILITY: u16 = 310;
 // const NL80211_ATTR_DISABLE_EHT:u16 = 311;
 const NL80211_ATTR_MLO_LINKS: u16 = 312;
 // Covered in mlo.rs
-// const NL80211_ATTR_MLO_LINK_ID: u16 = 313;
+const NL80211_ATTR_MLO_LINK_ID: u16 = 313;
 // const NL80211_ATTR_MLD_ADDR:u16 = 314;
 // const NL80211_ATTR_MLO_SUPPORT:u16 = 315;
 const NL80211_ATTR_MAX_NUM_AKM_SUITES: u16 = 316;
@@ -460,6 +462,7 @@ pub enum Nl80211Attr {
     Wdev(u64),
     Generation(u32),
     Use4Addr(bool),
+    MntrFlags(Nl80211MonitorFlags),
     WiphyFreq(u32),
     WiphyFreqOffset(u32),
     WiphyChannelType(Nl80211HtWiphyChannelType),
@@ -467,7 +470,7 @@ pub enum Nl80211Attr {
     CenterFreq1(u32),
     CenterFreq2(u32),
     WiphyTxPowerLevel(u32),
-    PsState(u32),
+    PsState(Nl80211PsState),
     Ssid(String),
     AuthType(Nl80211AuthType),
     StationInfo(Vec<Nl80211StationInfo>),
@@ -591,6 +594,94 @@ pub enum Nl80211Attr {
     ApSettingsFlags(u32),           // NL80211_ATTR_AP_SETTINGS_FLAGS
     Pmk(Vec<u8>),                       // NL80211_ATTR_PMK
     Frame(Vec<u8>),
+    ReasonCode(Nl80211ReasonCode),
+    TimedOut,
+    UseMfp(Nl80211Mfp),
+    ControlPort,
+    DisconnectedByAp,
+    StatusCode(Nl80211StatusCode),
+    ReqIe(Nl80211Elements),
+    RespIe(Nl80211Elements),
+    MacHint([u8; ETH_ALEN]),
+    WiphyFreqHint(u32),
+    BssSelect(Vec<Nl80211BssSelect>),
+    TimeoutReason(u32),
+    SaePassword(Vec<u8>),
+    PrevBssid([u8; ETH_ALEN]),
+    LocalStateChange,
+    /// SAE authentication data, starting with the authentication
+    /// transaction sequence number field
+    AuthData(Vec<u8>),
+    KeyIdx(u8),
+    Key(Vec<Nl80211KeyAttr>),
+    MloLinkId(u8),
+    DfsRegion(Nl80211DfsRegion),
+    RegRules(Vec<Nl80211RegRule>),
+    /// Duration in milliseconds
+    Duration(u32),
+    Cookie(u64),
+    /// The transmitted frame was acknowledged by the recipient
+    Ack,
+    TxNoCckRate,
+    DontWaitForAck,
+    /// Signal strength of the ACK frame in dBm
+    AckSignal(i32),
+    /// Signal strength of the received frame in dBm
+    RxSignalDbm(i32),
+    RxmgmtFlags(Nl80211RxFrameFlags),
+    /// Association ID of the station
+    StaAid(u16),
+    /// Listen interval of the station in beacon intervals
+    StaListenInterval(u16),
+    /// Supported rates of the station in 500 kbps units, with the basic
+    /// rate bit (0x80)
+    StaSupportedRates(Vec<u8>),
+    HtCapability(Nl80211ElementHtCap),
+    /// Management frame subtype (frame control bits 4-7)
+    MgmtSubtype(u8),
+    StaFlags2(Nl80211StationFlagUpdate),
+    /// Capability information field of the station
+    StaCapability(u16),
+    /// Extended capabilities element body of the station
+    StaExtCapability(Vec<u8>),
+    /// HE capabilities element body (without the element extension ID)
+    HeCapability(Vec<u8>),
+    He6GhzCapability(Nl80211He6GhzCapa),
+    /// EHT capabilities element body (without the element extension ID)
+    EhtCapability(Vec<u8>),
+    /// Number of TBTTs until the channel switch happens
+    ChSwitchCount(u32),
+    /// Block transmission until the channel switch completed
+    ChSwitchBlockTx,
+    /// Beacon data used during the channel switch, including
+    /// `CntdwnOffsBeacon` and `CntdwnOffsPresp`
+    CsaIes(Vec<Nl80211Attr>),
+    /// Offsets of the countdown counters in the beacon tail
+    CntdwnOffsBeacon(Vec<u16>),
+    /// Offsets of the countdown counters in the probe response
+    CntdwnOffsPresp(Vec<u16>),
+    /// Type of the radar event
+    RadarEvent(Nl80211RadarEventType),
+    /// Radar detection runs on the background (offchannel) radar chain
+    RadarBackground,
+    SurveyInfo(Vec<Nl80211SurveyInfoAttr>),
+    /// Request the radio-wide statistics in the survey dump
+    SurveyRadioStats,
+    /// Connection quality monitor configuration or event
+    Cqm(Vec<Nl80211CqmAttr>),
+    /// How the TX power level is applied
+    WiphyTxPowerSetting(Nl80211TxPowerSetting),
+    /// Enable dynamic ACK timeout estimation instead of the coverage
+    /// class
+    WiphyDynAck,
+    /// VHT MU-MIMO group membership (8 bytes) and user position (16 bytes)
+    /// arrays to follow in monitor mode
+    MuMimoGroupData(Vec<u8>),
+    /// MAC address of the station to follow the VHT MU-MIMO groups of in
+    /// monitor mode
+    MuMimoFollowMacAddr([u8; ETH_ALEN]),
+    /// Background scan period in seconds, `0` disables background scan
+    BgScanPeriod(u16),
     Other(DefaultNla),
 }
 
@@ -635,6 +726,7 @@ impl Nla for Nl80211Attr {
                 MacAddressNlas::from(s).as_slice().buffer_len()
             }
             Self::Use4Addr(_) => 1,
+            Self::MntrFlags(d) => d.buffer_len(),
             Self::WiphyRetryShort(_)
             | Self::WiphyRetryLong(_)
             | Self::WiphyCoverageClass(_)
@@ -717,6 +809,61 @@ impl Nla for Nl80211Attr {
             Self::Ie(data) => data.buffer_len(),
             Self::IeProbeResp(data) => data.buffer_len(),
             Self::IeAssocResp(data) => data.buffer_len(),
+            Self::ReasonCode(_) => 2,
+            Self::TimedOut => 0,
+            Self::UseMfp(_) => 4,
+            Self::ControlPort => 0,
+            Self::DisconnectedByAp => 0,
+            Self::StatusCode(_) => 2,
+            Self::ReqIe(data) => data.buffer_len(),
+            Self::RespIe(data) => data.buffer_len(),
+            Self::MacHint(_) => ETH_ALEN,
+            Self::WiphyFreqHint(_) => 4,
+            Self::BssSelect(v) => v.as_slice().buffer_len(),
+            Self::TimeoutReason(_) => 4,
+            Self::SaePassword(data) => data.len(),
+            Self::PrevBssid(_) => ETH_ALEN,
+            Self::LocalStateChange => 0,
+            Self::AuthData(data) => data.len(),
+            Self::KeyIdx(_) => 1,
+            Self::Key(v) => v.as_slice().buffer_len(),
+            Self::MloLinkId(_) => 1,
+            Self::DfsRegion(d) => d.buffer_len(),
+            Self::RegRules(v) => Nl80211RegRule::list_len(v),
+            Self::Duration(_) => 4,
+            Self::Cookie(_) => 8,
+            Self::Ack => 0,
+            Self::TxNoCckRate => 0,
+            Self::DontWaitForAck => 0,
+            Self::AckSignal(_) => 4,
+            Self::RxSignalDbm(_) => 4,
+            Self::RxmgmtFlags(_) => 4,
+            Self::StaAid(_) => 2,
+            Self::StaListenInterval(_) => 2,
+            Self::StaSupportedRates(d) => d.len(),
+            Self::HtCapability(d) => d.buffer_len(),
+            Self::MgmtSubtype(_) => 1,
+            Self::StaFlags2(d) => d.buffer_len(),
+            Self::StaCapability(_) => 2,
+            Self::StaExtCapability(d) => d.len(),
+            Self::HeCapability(d) => d.len(),
+            Self::He6GhzCapability(d) => d.buffer_len(),
+            Self::EhtCapability(d) => d.len(),
+            Self::ChSwitchCount(_) => 4,
+            Self::ChSwitchBlockTx => 0,
+            Self::CsaIes(attrs) => attrs.as_slice().buffer_len(),
+            Self::CntdwnOffsBeacon(d) => d.len() * 2,
+            Self::CntdwnOffsPresp(d) => d.len() * 2,
+            Self::RadarEvent(_) => 4,
+            Self::RadarBackground => 0,
+            Self::SurveyInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::SurveyRadioStats => 0,
+            Self::Cqm(nlas) => nlas.as_slice().buffer_len(),
+            Self::WiphyTxPowerSetting(_) => 4,
+            Self::WiphyDynAck => 0,
+            Self::MuMimoGroupData(d) => d.len(),
+            Self::MuMimoFollowMacAddr(_) => ETH_ALEN,
+            Self::BgScanPeriod(_) => 2,
             Self::Other(attr) => attr.value_len(),
         }
     }
@@ -734,6 +881,7 @@ impl Nla for Nl80211Attr {
             Self::Wdev(_) => NL80211_ATTR_WDEV,
             Self::Generation(_) => NL80211_ATTR_GENERATION,
             Self::Use4Addr(_) => NL80211_ATTR_4ADDR,
+            Self::MntrFlags(_) => NL80211_ATTR_MNTR_FLAGS,
             Self::WiphyFreq(_) => NL80211_ATTR_WIPHY_FREQ,
             Self::PsState(_) => NL80211_ATTR_PS_STATE,
             Self::WiphyFreqOffset(_) => NL80211_ATTR_WIPHY_FREQ_OFFSET,
@@ -849,6 +997,63 @@ impl Nla for Nl80211Attr {
             Self::ControlPortOverNl80211 => NL80211_ATTR_CONTROL_PORT_OVER_NL80211,
             Self::SocketOwner => NL80211_ATTR_SOCKET_OWNER,
             Self::ControlPortNoPreauth => NL80211_ATTR_CONTROL_PORT_NO_PREAUTH,
+            Self::ReasonCode(_) => NL80211_ATTR_REASON_CODE,
+            Self::TimedOut => NL80211_ATTR_TIMED_OUT,
+            Self::UseMfp(_) => NL80211_ATTR_USE_MFP,
+            Self::ControlPort => NL80211_ATTR_CONTROL_PORT,
+            Self::DisconnectedByAp => NL80211_ATTR_DISCONNECTED_BY_AP,
+            Self::StatusCode(_) => NL80211_ATTR_STATUS_CODE,
+            Self::ReqIe(_) => NL80211_ATTR_REQ_IE,
+            Self::RespIe(_) => NL80211_ATTR_RESP_IE,
+            Self::MacHint(_) => NL80211_ATTR_MAC_HINT,
+            Self::WiphyFreqHint(_) => NL80211_ATTR_WIPHY_FREQ_HINT,
+            Self::BssSelect(_) => NL80211_ATTR_BSS_SELECT,
+            Self::TimeoutReason(_) => NL80211_ATTR_TIMEOUT_REASON,
+            Self::SaePassword(_) => NL80211_ATTR_SAE_PASSWORD,
+            Self::PrevBssid(_) => NL80211_ATTR_PREV_BSSID,
+            Self::LocalStateChange => NL80211_ATTR_LOCAL_STATE_CHANGE,
+            Self::AuthData(_) => NL80211_ATTR_AUTH_DATA,
+            Self::KeyIdx(_) => NL80211_ATTR_KEY_IDX,
+            Self::Key(_) => NL80211_ATTR_KEY,
+            Self::MloLinkId(_) => NL80211_ATTR_MLO_LINK_ID,
+            Self::DfsRegion(_) => NL80211_ATTR_DFS_REGION,
+            Self::RegRules(_) => NL80211_ATTR_REG_RULES,
+            Self::Duration(_) => NL80211_ATTR_DURATION,
+            Self::Cookie(_) => NL80211_ATTR_COOKIE,
+            Self::Ack => NL80211_ATTR_ACK,
+            Self::TxNoCckRate => NL80211_ATTR_TX_NO_CCK_RATE,
+            Self::DontWaitForAck => NL80211_ATTR_DONT_WAIT_FOR_ACK,
+            Self::AckSignal(_) => NL80211_ATTR_ACK_SIGNAL,
+            Self::RxSignalDbm(_) => NL80211_ATTR_RX_SIGNAL_DBM,
+            Self::RxmgmtFlags(_) => NL80211_ATTR_RXMGMT_FLAGS,
+            Self::StaAid(_) => NL80211_ATTR_STA_AID,
+            Self::StaListenInterval(_) => NL80211_ATTR_STA_LISTEN_INTERVAL,
+            Self::StaSupportedRates(_) => NL80211_ATTR_STA_SUPPORTED_RATES,
+            Self::HtCapability(_) => NL80211_ATTR_HT_CAPABILITY,
+            Self::MgmtSubtype(_) => NL80211_ATTR_MGMT_SUBTYPE,
+            Self::StaFlags2(_) => NL80211_ATTR_STA_FLAGS2,
+            Self::StaCapability(_) => NL80211_ATTR_STA_CAPABILITY,
+            Self::StaExtCapability(_) => NL80211_ATTR_STA_EXT_CAPABILITY,
+            Self::HeCapability(_) => NL80211_ATTR_HE_CAPABILITY,
+            Self::He6GhzCapability(_) => NL80211_ATTR_HE_6GHZ_CAPABILITY,
+            Self::EhtCapability(_) => NL80211_ATTR_EHT_CAPABILITY,
+            Self::ChSwitchCount(_) => NL80211_ATTR_CH_SWITCH_COUNT,
+            Self::ChSwitchBlockTx => NL80211_ATTR_CH_SWITCH_BLOCK_TX,
+            Self::CsaIes(_) => NL80211_ATTR_CSA_IES,
+            Self::CntdwnOffsBeacon(_) => NL80211_ATTR_CNTDWN_OFFS_BEACON,
+            Self::CntdwnOffsPresp(_) => NL80211_ATTR_CNTDWN_OFFS_PRESP,
+            Self::RadarEvent(_) => NL80211_ATTR_RADAR_EVENT,
+            Self::RadarBackground => NL80211_ATTR_RADAR_BACKGROUND,
+            Self::SurveyInfo(_) => NL80211_ATTR_SURVEY_INFO,
+            Self::SurveyRadioStats => NL80211_ATTR_SURVEY_RADIO_STATS,
+            Self::Cqm(_) => NL80211_ATTR_CQM,
+            Self::WiphyTxPowerSetting(_) => NL80211_ATTR_WIPHY_TX_POWER_SETTING,
+            Self::WiphyDynAck => NL80211_ATTR_WIPHY_DYN_ACK,
+            Self::MuMimoGroupData(_) => NL80211_ATTR_MU_MIMO_GROUP_DATA,
+            Self::MuMimoFollowMacAddr(_) => {
+                NL80211_ATTR_MU_MIMO_FOLLOW_MAC_ADDR
+            }
+            Self::BgScanPeriod(_) => NL80211_ATTR_BG_SCAN_PERIOD,
             Self::Other(attr) => attr.kind(),
         }
     }
@@ -865,7 +1070,6 @@ impl Nla for Nl80211Attr {
             | Self::WiphyTxPowerLevel(d)
             | Self::WiphyFragThreshold(d)
             | Self::WiphyRtsThreshold(d)
-            | Self::PsState(d)
             | Self::WiphyAntennaAvailTx(d)
             | Self::WiphyAntennaAvailRx(d)
             | Self::ApProbeRespOffload(d)
@@ -904,6 +1108,7 @@ impl Nla for Nl80211Attr {
                 write_u32(buffer, u32::from(*d));
             }
             Self::Use4Addr(d) => buffer[0] = *d as u8,
+            Self::MntrFlags(d) => d.emit(buffer),
             Self::SupportIbssRsn
             | Self::SupportMeshAuth
             | Self::SupportApUapsd
@@ -914,6 +1119,7 @@ impl Nla for Nl80211Attr {
             | Self::WiphySelfManagedReg => (),
             Self::WiphyChannelType(d) => write_u32(buffer, (*d).into()),
             Self::ChannelWidth(d) => write_u32(buffer, (*d).into()),
+            Self::PsState(d) => write_u32(buffer, (*d).into()),
             Self::StationInfo(nlas) => nlas.as_slice().emit(buffer),
             Self::TransmitQueueStats(nlas) => nlas.as_slice().emit(buffer),
             Self::MloLinks(links) => links.as_slice().emit(buffer),
@@ -1004,12 +1210,12 @@ impl Nla for Nl80211Attr {
             Self::WpaVersions(value) => {
                 write_u32(buffer, *value);
             }
-            Self::CipherSuiteGroup(value )=> {
-                write_u32(buffer, u32::from(*value).to_be());
+            Self::CipherSuiteGroup(value) => {
+                write_u32(buffer, u32::from(*value));
             }
             Self::CipherSuitesPairwise(values) => {
                 for (i, v) in values.iter().enumerate() {
-                    write_u32(&mut buffer[i * 4..(i + 1) * 4], u32::from(*v).to_be());
+                    write_u32(&mut buffer[i * 4..(i + 1) * 4], u32::from(*v));
                 }
             }
             Self::AkmSuites(values) => {
@@ -1017,6 +1223,69 @@ impl Nla for Nl80211Attr {
                     write_u32(&mut buffer[i * 4..(i + 1) * 4], u32::from(*v).to_be());
                 }
             }
+            Self::ReasonCode(d) => write_u16(buffer, u16::from(*d)),
+            Self::TimedOut => (),
+            Self::UseMfp(d) => write_u32(buffer, u32::from(*d)),
+            Self::ControlPort => (),
+            Self::DisconnectedByAp => (),
+            Self::StatusCode(d) => write_u16(buffer, u16::from(*d)),
+            Self::ReqIe(data) => data.emit(buffer),
+            Self::RespIe(data) => data.emit(buffer),
+            Self::MacHint(s) => buffer.copy_from_slice(s),
+            Self::WiphyFreqHint(d) => write_u32(buffer, *d),
+            Self::BssSelect(v) => v.as_slice().emit(buffer),
+            Self::TimeoutReason(d) => write_u32(buffer, *d),
+            Self::SaePassword(data) => buffer.copy_from_slice(data),
+            Self::PrevBssid(s) => buffer.copy_from_slice(s),
+            Self::LocalStateChange => (),
+            Self::AuthData(data) => buffer.copy_from_slice(data),
+            Self::KeyIdx(d) => buffer[0] = *d,
+            Self::Key(v) => v.as_slice().emit(buffer),
+            Self::MloLinkId(d) => buffer[0] = *d,
+            Self::DfsRegion(d) => d.emit(buffer),
+            Self::RegRules(v) => Nl80211RegRule::emit_list(v, buffer),
+            Self::Duration(d) => write_u32(buffer, *d),
+            Self::Cookie(d) => write_u64(buffer, *d),
+            Self::Ack => (),
+            Self::TxNoCckRate => (),
+            Self::DontWaitForAck => (),
+            Self::AckSignal(d) => write_i32(buffer, *d),
+            Self::RxSignalDbm(d) => write_i32(buffer, *d),
+            Self::RxmgmtFlags(d) => write_u32(buffer, d.bits()),
+            Self::StaAid(d) => write_u16(buffer, *d),
+            Self::StaListenInterval(d) => write_u16(buffer, *d),
+            Self::StaSupportedRates(d) => buffer.copy_from_slice(d),
+            Self::HtCapability(d) => d.emit(buffer),
+            Self::MgmtSubtype(d) => buffer[0] = *d,
+            Self::StaFlags2(d) => d.emit(buffer),
+            Self::StaCapability(d) => write_u16(buffer, *d),
+            Self::StaExtCapability(d) => buffer.copy_from_slice(d),
+            Self::HeCapability(d) => buffer.copy_from_slice(d),
+            Self::He6GhzCapability(d) => d.emit(buffer),
+            Self::EhtCapability(d) => buffer.copy_from_slice(d),
+            Self::ChSwitchCount(d) => write_u32(buffer, *d),
+            Self::ChSwitchBlockTx => (),
+            Self::CsaIes(attrs) => attrs.as_slice().emit(buffer),
+            Self::CntdwnOffsBeacon(d) => {
+                for (i, offset) in d.iter().enumerate() {
+                    write_u16(&mut buffer[i * 2..], *offset);
+                }
+            }
+            Self::CntdwnOffsPresp(d) => {
+                for (i, offset) in d.iter().enumerate() {
+                    write_u16(&mut buffer[i * 2..], *offset);
+                }
+            }
+            Self::RadarEvent(d) => write_u32(buffer, (*d).into()),
+            Self::RadarBackground => (),
+            Self::SurveyInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::SurveyRadioStats => (),
+            Self::Cqm(nlas) => nlas.as_slice().emit(buffer),
+            Self::WiphyTxPowerSetting(d) => write_u32(buffer, (*d).into()),
+            Self::WiphyDynAck => (),
+            Self::MuMimoGroupData(d) => buffer.copy_from_slice(d),
+            Self::MuMimoFollowMacAddr(s) => buffer.copy_from_slice(s),
+            Self::BgScanPeriod(d) => write_u16(buffer, *d),
             Self::Other(attr) => attr.emit(buffer),
         }
     }
@@ -1056,7 +1325,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
             NL80211_ATTR_PS_STATE => {
                 let err_msg =
                     format!("Invalid NL80211_ATTR_PS_STATE value {:?}", payload);
-                Self::PsState(parse_u32(payload).context(err_msg)?)
+                Self::PsState(parse_u32(payload).context(err_msg)?.into())
             }
             NL80211_ATTR_IFTYPE => {
                 Self::IfType(Nl80211InterfaceType::parse(payload)?)
@@ -1117,6 +1386,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                     format!("Invalid NL80211_ATTR_4ADDR value {:?}", payload);
                 Self::Use4Addr(parse_u8(payload).context(err_msg)? > 0)
             }
+            NL80211_ATTR_MNTR_FLAGS => {
+                Self::MntrFlags(Nl80211MonitorFlags::parse(payload)?)
+            }
             NL80211_ATTR_WIPHY_FREQ => {
                 let err_msg = format!(
                     "Invalid NL80211_ATTR_WIPHY_FREQ value {:?}",
@@ -1693,9 +1965,391 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
             NL80211_ATTR_CONTROL_PORT_NO_PREAUTH => {
                 Self::ControlPortNoPreauth
             }
+            NL80211_ATTR_REASON_CODE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_REASON_CODE value {:?}",
+                    payload
+                );
+                Self::ReasonCode(parse_u16(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_TIMED_OUT => Self::TimedOut,
+            NL80211_ATTR_USE_MFP => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_USE_MFP value {:?}", payload);
+                Self::UseMfp(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_CONTROL_PORT => Self::ControlPort,
+            NL80211_ATTR_DISCONNECTED_BY_AP => Self::DisconnectedByAp,
+            NL80211_ATTR_STATUS_CODE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_STATUS_CODE value {:?}",
+                    payload
+                );
+                Self::StatusCode(parse_u16(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_REQ_IE => {
+                Self::ReqIe(
+                    Nl80211Elements::parse(payload)
+                        .context("Invalid NL80211_ATTR_REQ_IE")?,
+                )
+            }
+            NL80211_ATTR_RESP_IE => {
+                Self::RespIe(
+                    Nl80211Elements::parse(payload)
+                        .context("Invalid NL80211_ATTR_RESP_IE")?,
+                )
+            }
+            NL80211_ATTR_MAC_HINT => Self::MacHint(if payload.len() == ETH_ALEN {
+                let mut ret = [0u8; ETH_ALEN];
+                ret.copy_from_slice(&payload[..ETH_ALEN]);
+                ret
+            } else {
+                return Err(format!(
+                    "Invalid length of NL80211_ATTR_MAC_HINT, \
+                    expected length {} got {:?}",
+                    ETH_ALEN, payload
+                )
+                .into());
+            }),
+            NL80211_ATTR_WIPHY_FREQ_HINT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_FREQ_HINT value {:?}",
+                    payload
+                );
+                Self::WiphyFreqHint(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_BSS_SELECT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_BSS_SELECT value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211BssSelect::parse(nla)?);
+                }
+                Self::BssSelect(nlas)
+            }
+            NL80211_ATTR_TIMEOUT_REASON => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_TIMEOUT_REASON value {:?}",
+                    payload
+                );
+                Self::TimeoutReason(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_SAE_PASSWORD => Self::SaePassword(payload.to_vec()),
+            NL80211_ATTR_PREV_BSSID => {
+                Self::PrevBssid(if payload.len() == ETH_ALEN {
+                    let mut ret = [0u8; ETH_ALEN];
+                    ret.copy_from_slice(&payload[..ETH_ALEN]);
+                    ret
+                } else {
+                    return Err(format!(
+                        "Invalid length of NL80211_ATTR_PREV_BSSID, \
+                        expected length {} got {:?}",
+                        ETH_ALEN, payload
+                    )
+                    .into());
+                })
+            }
+            NL80211_ATTR_LOCAL_STATE_CHANGE => Self::LocalStateChange,
+            NL80211_ATTR_AUTH_DATA => Self::AuthData(payload.to_vec()),
+            NL80211_ATTR_KEY_IDX => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_KEY_IDX value {:?}", payload);
+                Self::KeyIdx(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_KEY => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_KEY value {:?}", payload);
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211KeyAttr::parse(nla)?);
+                }
+                Self::Key(nlas)
+            }
+            NL80211_ATTR_MLO_LINK_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MLO_LINK_ID value {:?}",
+                    payload
+                );
+                Self::MloLinkId(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_DFS_REGION => {
+                if payload.is_empty() {
+                    return Err(format!(
+                        "Invalid NL80211_ATTR_DFS_REGION value {:?}",
+                        payload
+                    )
+                    .into());
+                }
+                Self::DfsRegion(Nl80211DfsRegion::parse(payload)?)
+            }
+            NL80211_ATTR_REG_RULES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_REG_RULES value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211RegRule::parse(nla)?);
+                }
+                Self::RegRules(nlas)
+            }
+            NL80211_ATTR_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_DURATION value {:?}",
+                    payload
+                );
+                Self::Duration(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_COOKIE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COOKIE value {:?}",
+                    payload
+                );
+                Self::Cookie(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_ACK => Self::Ack,
+            NL80211_ATTR_TX_NO_CCK_RATE => Self::TxNoCckRate,
+            NL80211_ATTR_DONT_WAIT_FOR_ACK => Self::DontWaitForAck,
+            NL80211_ATTR_ACK_SIGNAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_ACK_SIGNAL value {:?}",
+                    payload
+                );
+                Self::AckSignal(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_RX_SIGNAL_DBM => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_RX_SIGNAL_DBM value {:?}",
+                    payload
+                );
+                Self::RxSignalDbm(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_RXMGMT_FLAGS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_RXMGMT_FLAGS value {:?}",
+                    payload
+                );
+                Self::RxmgmtFlags(Nl80211RxFrameFlags::from_bits_retain(
+                    parse_u32(payload).context(err_msg)?,
+                ))
+            }
+            NL80211_ATTR_STA_AID => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_STA_AID value {:?}", payload);
+                Self::StaAid(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_STA_LISTEN_INTERVAL => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_STA_LISTEN_INTERVAL value {:?}", payload);
+                Self::StaListenInterval(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_STA_SUPPORTED_RATES => Self::StaSupportedRates(payload.to_vec()),
+            NL80211_ATTR_HT_CAPABILITY => {
+                Self::HtCapability(Nl80211ElementHtCap::parse(payload)?)
+            }
+            NL80211_ATTR_MGMT_SUBTYPE => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_MGMT_SUBTYPE value {:?}", payload);
+                Self::MgmtSubtype(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_STA_FLAGS2 => {
+                Self::StaFlags2(Nl80211StationFlagUpdate::parse(payload)?)
+            }
+            NL80211_ATTR_STA_CAPABILITY => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_STA_CAPABILITY value {:?}", payload);
+                Self::StaCapability(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_STA_EXT_CAPABILITY => Self::StaExtCapability(payload.to_vec()),
+            NL80211_ATTR_HE_CAPABILITY => Self::HeCapability(payload.to_vec()),
+            NL80211_ATTR_HE_6GHZ_CAPABILITY => {
+                Self::He6GhzCapability(Nl80211He6GhzCapa::new(payload))
+            }
+            NL80211_ATTR_EHT_CAPABILITY => Self::EhtCapability(payload.to_vec()),
+            NL80211_ATTR_CH_SWITCH_COUNT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CH_SWITCH_COUNT value {:?}",
+                    payload
+                );
+                Self::ChSwitchCount(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CH_SWITCH_BLOCK_TX => Self::ChSwitchBlockTx,
+            NL80211_ATTR_CSA_IES => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_CSA_IES value {:?}", payload);
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    attrs.push(Self::parse(nla).context(err_msg.clone())?);
+                }
+                Self::CsaIes(attrs)
+            }
+            NL80211_ATTR_CNTDWN_OFFS_BEACON => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CNTDWN_OFFS_BEACON value {:?}",
+                    payload
+                );
+                let mut offsets = Vec::new();
+                for chunk in payload.chunks(2) {
+                    offsets.push(parse_u16(chunk).context(err_msg.clone())?);
+                }
+                Self::CntdwnOffsBeacon(offsets)
+            }
+            NL80211_ATTR_CNTDWN_OFFS_PRESP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CNTDWN_OFFS_PRESP value {:?}",
+                    payload
+                );
+                let mut offsets = Vec::new();
+                for chunk in payload.chunks(2) {
+                    offsets.push(parse_u16(chunk).context(err_msg.clone())?);
+                }
+                Self::CntdwnOffsPresp(offsets)
+            }
+            NL80211_ATTR_RADAR_EVENT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_RADAR_EVENT value {:?}",
+                    payload
+                );
+                Self::RadarEvent(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_RADAR_BACKGROUND => Self::RadarBackground,
+            NL80211_ATTR_SURVEY_INFO => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SURVEY_INFO value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211SurveyInfoAttr::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::SurveyInfo(nlas)
+            }
+            NL80211_ATTR_SURVEY_RADIO_STATS => Self::SurveyRadioStats,
+            NL80211_ATTR_CQM => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_CQM value {:?}", payload);
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211CqmAttr::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::Cqm(nlas)
+            }
+            NL80211_ATTR_WIPHY_TX_POWER_SETTING => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_TX_POWER_SETTING value {:?}",
+                    payload
+                );
+                Self::WiphyTxPowerSetting(
+                    parse_u32(payload).context(err_msg)?.into(),
+                )
+            }
+            NL80211_ATTR_WIPHY_DYN_ACK => Self::WiphyDynAck,
+            NL80211_ATTR_MU_MIMO_GROUP_DATA => {
+                Self::MuMimoGroupData(payload.to_vec())
+            }
+            NL80211_ATTR_MU_MIMO_FOLLOW_MAC_ADDR => {
+                Self::MuMimoFollowMacAddr(if payload.len() == ETH_ALEN {
+                    let mut ret = [0u8; ETH_ALEN];
+                    ret.copy_from_slice(&payload[..ETH_ALEN]);
+                    ret
+                } else {
+                    return Err(format!(
+                        "Invalid length of \
+                        NL80211_ATTR_MU_MIMO_FOLLOW_MAC_ADDR, \
+                        expected length {} got {:?}",
+                        ETH_ALEN, payload
+                    )
+                    .into());
+                })
+            }
+            NL80211_ATTR_BG_SCAN_PERIOD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_BG_SCAN_PERIOD value {:?}",
+                    payload
+                );
+                Self::BgScanPeriod(parse_u16(payload).context(err_msg)?)
+            }
             _ => Self::Other(
                 DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
             ),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use netlink_packet_utils::nla::NLA_HEADER_SIZE;
+
+    use super::*;
+
+    fn emit(attr: &Nl80211Attr) -> Vec<u8> {
+        let mut buffer = vec![0u8; attr.buffer_len()];
+        attr.emit(&mut buffer);
+        buffer
+    }
+
+    fn parse(buffer: &[u8]) -> Nl80211Attr {
+        Nl80211Attr::parse(&NlaBuffer::new(buffer)).unwrap()
+    }
+
+    // Kernel reads the cipher suite selectors as host order u32, e.g.
+    // 00-0F-AC:4 (CCMP) is 0x000FAC04
+    #[test]
+    fn cipher_suite_group_round_trip() {
+        let attr = Nl80211Attr::CipherSuiteGroup(Nl80211CipherSuite::Ccmp);
+        let buffer = emit(&attr);
+        assert_eq!(&buffer[NLA_HEADER_SIZE..], &0x000fac04u32.to_ne_bytes());
+        assert_eq!(parse(&buffer), attr);
+    }
+
+    #[test]
+    fn cipher_suites_pairwise_round_trip() {
+        let attr = Nl80211Attr::CipherSuitesPairwise(vec![
+            Nl80211CipherSuite::Ccmp,
+            Nl80211CipherSuite::Gcmp256,
+        ]);
+        let buffer = emit(&attr);
+        assert_eq!(
+            &buffer[NLA_HEADER_SIZE..NLA_HEADER_SIZE + 4],
+            &0x000fac04u32.to_ne_bytes()
+        );
+        assert_eq!(
+            &buffer[NLA_HEADER_SIZE + 4..],
+            &0x000fac09u32.to_ne_bytes()
+        );
+        assert_eq!(parse(&buffer), attr);
+    }
+
+    #[test]
+    fn key_round_trip() {
+        let attr = Nl80211Attr::Key(vec![
+            Nl80211KeyAttr::Idx(1),
+            Nl80211KeyAttr::Cipher(Nl80211CipherSuite::Ccmp),
+            Nl80211KeyAttr::Data(vec![0x5a; 16]),
+            Nl80211KeyAttr::Seq(vec![1, 0, 0, 0, 0, 0]),
+            Nl80211KeyAttr::Type(crate::Nl80211KeyType::Pairwise),
+            Nl80211KeyAttr::Mode(crate::Nl80211KeyMode::NoTx),
+            Nl80211KeyAttr::Default,
+        ]);
+        assert_eq!(parse(&emit(&attr)), attr);
+
+        for attr in [Nl80211Attr::KeyIdx(6), Nl80211Attr::MloLinkId(2)] {
+            let buffer = emit(&attr);
+            assert_eq!(buffer.len(), NLA_HEADER_SIZE + 4);
+            assert_eq!(parse(&buffer), attr);
+        }
+    }
+}
@@ -0,0 +1,731 @@
+// SPDX-License-Identifier: MIT
+
+use std::collections::HashMap;
+
+use crate::{
+    Nl80211Attr, Nl80211BandInfo, Nl80211BandType, Nl80211ChannelWidth,
+    Nl80211Error, Nl80211FrequencyInfo, Nl80211HtWiphyChannelType,
+    Nl80211Wiphy,
+};
+
+// Channel 0 of each band in MHz
+const BAND_2GHZ_BASE: u32 = 2407;
+const BAND_5GHZ_BASE: u32 = 5000;
+const BAND_6GHZ_BASE: u32 = 5950;
+
+const CHANNEL_2GHZ_14_FREQ: u32 = 2484;
+const CHANNEL_2GHZ_MAX: u32 = 13;
+// 6 GHz channel 2 (5935 MHz) is 20 MHz only and not on the 6 GHz grid
+const CHANNEL_6GHZ_2_FREQ: u32 = 5935;
+const CHANNEL_6GHZ_MAX: u32 = 233;
+
+// First 20 MHz channel of each 5 GHz 40/80/160 MHz channel,
+// IEEE 802.11-2020 Annex E
+const BLOCKS_5GHZ_40MHZ: [u32; 14] = [
+    36, 44, 52, 60, 100, 108, 116, 124, 132, 140, 149, 157, 165, 173,
+];
+const BLOCKS_5GHZ_80MHZ: [u32; 7] = [36, 52, 100, 116, 132, 149, 165];
+const BLOCKS_5GHZ_160MHZ: [u32; 3] = [36, 100, 149];
+
+// First 20 MHz channel of each 6 GHz 320 MHz channel, IEEE 802.11be
+// Annex E
+const BLOCKS_6GHZ_320MHZ_1: [u32; 3] = [1, 65, 129];
+const BLOCKS_6GHZ_320MHZ_2: [u32; 3] = [33, 97, 161];
+
+/// The two overlapping sets of 6 GHz 320 MHz channels (320MHz-1 centered
+/// on channel 31, 95 and 159, 320MHz-2 centered on 63, 127 and 191)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211Eht320Channelization {
+    One,
+    Two,
+}
+
+/// Channel definition, the control channel with the center frequencies of
+/// the whole channel derived from the width.
+///
+/// The center frequencies are computed for the 2.4, 5 and 6 GHz channel
+/// plans, use [Nl80211Chandef::validate()] to check it against the
+/// regulatory flags of a wiphy before using it in
+/// [crate::Nl80211InterfaceSetChannelRequest::as_chandef()],
+/// [crate::Nl80211StartAp::with_chandef()],
+/// [crate::Nl80211ChannelSwitchRequest::as_chandef()] or
+/// [crate::Nl80211RadarDetectRequest::as_chandef()].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub struct Nl80211Chandef {
+    pub band: Nl80211BandType,
+    /// Control channel frequency in MHz
+    pub control_freq: u32,
+    pub width: Nl80211ChannelWidth,
+    /// Center frequency of the first segment in MHz
+    pub center_freq1: u32,
+    /// Center frequency of the second segment in MHz, only for 80+80 MHz
+    pub center_freq2: Option<u32>,
+}
+
+impl Nl80211Chandef {
+    /// Channel definition of the control channel frequency (MHz) with the
+    /// width. The HT40 secondary channel of 2.4 GHz is above the control
+    /// channel when possible, use [Nl80211Chandef::new_ht40()] to choose
+    /// it. The 6 GHz 320 MHz channel is 320MHz-1 when possible, use
+    /// [Nl80211Chandef::new_eht320()] to choose it. The 80+80 MHz width
+    /// needs [Nl80211Chandef::new_80p80()].
+    pub fn new(
+        control_freq: u32,
+        width: Nl80211ChannelWidth,
+    ) -> Result<Self, Nl80211Error> {
+        let (band, chan) = freq_to_channel(control_freq)?;
+        let mhz = match width {
+            Nl80211ChannelWidth::NoHt20 | Nl80211ChannelWidth::Mhz(20) => {
+                return Ok(Self {
+                    band,
+                    control_freq,
+                    width,
+                    center_freq1: control_freq,
+                    center_freq2: None,
+                });
+            }
+            Nl80211ChannelWidth::Mhz(40)
+                if band == Nl80211BandType::Band2GHz =>
+            {
+                let channel_type = if chan + 4 <= CHANNEL_2GHZ_MAX {
+                    Nl80211HtWiphyChannelType::Ht40Plus
+                } else {
+                    Nl80211HtWiphyChannelType::Ht40Minus
+                };
+                return Self::new_ht40(control_freq, channel_type);
+            }
+            Nl80211ChannelWidth::Mhz(320) => {
+                return Self::new_eht320(
+                    control_freq,
+                    Nl80211Eht320Channelization::One,
+                )
+                .or_else(|_| {
+                    Self::new_eht320(
+                        control_freq,
+                        Nl80211Eht320Channelization::Two,
+                    )
+                });
+            }
+            Nl80211ChannelWidth::Mhz(d @ (40 | 80 | 160)) => d,
+            _ => {
+                return Err(Nl80211Error::RequestFailed(format!(
+                    "Channel width {width:?} is not supported by \
+                    Nl80211Chandef::new()"
+                )));
+            }
+        };
+        let center_chan = block_center(band, chan, mhz, None)?;
+        Ok(Self {
+            band,
+            control_freq,
+            width,
+            center_freq1: channel_to_freq(band, center_chan),
+            center_freq2: None,
+        })
+    }
+
+    /// HT40 channel definition with the secondary channel above
+    /// ([Nl80211HtWiphyChannelType::Ht40Plus]) or below
+    /// ([Nl80211HtWiphyChannelType::Ht40Minus]) the control channel.
+    /// On 5 and 6 GHz the direction must match the 40 MHz channel plan.
+    pub fn new_ht40(
+        control_freq: u32,
+        channel_type: Nl80211HtWiphyChannelType,
+    ) -> Result<Self, Nl80211Error> {
+        let (band, chan) = freq_to_channel(control_freq)?;
+        let center_freq1 = match channel_type {
+            Nl80211HtWiphyChannelType::Ht40Plus => control_freq + 10,
+            Nl80211HtWiphyChannelType::Ht40Minus => control_freq - 10,
+            _ => {
+                return Err(Nl80211Error::RequestFailed(format!(
+                    "{channel_type:?} is not a HT40 channel type"
+                )));
+            }
+        };
+        let valid = if band == Nl80211BandType::Band2GHz {
+            match channel_type {
+                Nl80211HtWiphyChannelType::Ht40Plus => {
+                    chan + 4 <= CHANNEL_2GHZ_MAX
+                }
+                _ => (5..=CHANNEL_2GHZ_MAX).contains(&chan),
+            }
+        } else {
+            block_center(band, chan, 40, None)
+                .map(|c| channel_to_freq(band, c) == center_freq1)
+                .unwrap_or(false)
+        };
+        if !valid {
+            return Err(Nl80211Error::RequestFailed(format!(
+                "{channel_type:?} is not possible on {control_freq} MHz"
+            )));
+        }
+        Ok(Self {
+            band,
+            control_freq,
+            width: Nl80211ChannelWidth::Mhz(40),
+            center_freq1,
+            center_freq2: None,
+        })
+    }
+
+    /// 80+80 MHz channel definition, the second 80 MHz segment is given
+    /// by its center frequency (MHz) and must not be adjacent to the one
+    /// of the control channel
+    pub fn new_80p80(
+        control_freq: u32,
+        center_freq2: u32,
+    ) -> Result<Self, Nl80211Error> {
+        let primary = Self::new(control_freq, Nl80211ChannelWidth::Mhz(80))?;
+        let (band, chan2) = center_freq2
+            .checked_sub(30)
+            .and_then(|d| freq_to_channel(d).ok())
+            .ok_or_else(|| invalid_center_freq2(center_freq2))?;
+        if band != primary.band
+            || channel_to_freq(band, block_center(band, chan2, 80, None)?)
+                != center_freq2
+            || primary.center_freq1.abs_diff(center_freq2) <= 80
+        {
+            return Err(invalid_center_freq2(center_freq2));
+        }
+        Ok(Self {
+            width: Nl80211ChannelWidth::Mhz80Plus80,
+            center_freq2: Some(center_freq2),
+            ..primary
+        })
+    }
+
+    /// 6 GHz 320 MHz channel definition of the channelization
+    pub fn new_eht320(
+        control_freq: u32,
+        channelization: Nl80211Eht320Channelization,
+    ) -> Result<Self, Nl80211Error> {
+        let (band, chan) = freq_to_channel(control_freq)?;
+        let center_chan = block_center(band, chan, 320, Some(channelization))?;
+        Ok(Self {
+            band,
+            control_freq,
+            width: Nl80211ChannelWidth::Mhz(320),
+            center_freq1: channel_to_freq(band, center_chan),
+            center_freq2: None,
+        })
+    }
+
+    /// Frequencies (MHz) of all the 20 MHz channels covered by this
+    /// channel definition
+    pub fn subchannel_freqs(&self) -> Vec<u32> {
+        let segment = match self.width {
+            Nl80211ChannelWidth::Mhz(d @ (40 | 80 | 160 | 320)) => d,
+            Nl80211ChannelWidth::Mhz80Plus80 => 80,
+            _ => return vec![self.control_freq],
+        };
+        let mut ret = Vec::new();
+        for center in
+            std::iter::once(self.center_freq1).chain(self.center_freq2)
+        {
+            let first = center - segment / 2 + 10;
+            ret.extend((0..segment / 20).map(|i| first + i * 20));
+        }
+        ret
+    }
+
+    /// Check every 20 MHz channel covered is supported and enabled by the
+    /// wiphy, and none of them prohibits the width by
+    /// [Nl80211FrequencyInfo::NoHt40Plus], [Nl80211FrequencyInfo::NoHt40Minus],
+    /// [Nl80211FrequencyInfo::No80Mhz], [Nl80211FrequencyInfo::No160Mhz] or
+    /// [Nl80211FrequencyInfo::No320Mhz]
+    pub fn validate(&self, wiphy: &Nl80211Wiphy) -> Result<(), Nl80211Error> {
+        let mut freqs: HashMap<u32, &[Nl80211FrequencyInfo]> = HashMap::new();
+        for band in wiphy.bands.iter().filter(|b| b.kind == self.band) {
+            for info in band.info.as_slice() {
+                if let Nl80211BandInfo::Freqs(band_freqs) = info {
+                    for freq in band_freqs {
+                        if let Some(d) = freq.info.iter().find_map(|i| {
+                            if let Nl80211FrequencyInfo::Freq(d) = i {
+                                Some(*d)
+                            } else {
+                                None
+                            }
+                        }) {
+                            freqs.insert(d, freq.info.as_slice());
+                        }
+                    }
+                }
+            }
+        }
+
+        let prohibited = match self.width {
+            Nl80211ChannelWidth::Mhz(80) | Nl80211ChannelWidth::Mhz80Plus80 => {
+                Some(Nl80211FrequencyInfo::No80Mhz)
+            }
+            Nl80211ChannelWidth::Mhz(160) => {
+                Some(Nl80211FrequencyInfo::No160Mhz)
+            }
+            Nl80211ChannelWidth::Mhz(320) => {
+                Some(Nl80211FrequencyInfo::No320Mhz)
+            }
+            _ => None,
+        };
+
+        for freq in self.subchannel_freqs() {
+            let info = freqs.get(&freq).ok_or_else(|| {
+                Nl80211Error::RequestFailed(format!(
+                    "Frequency {freq} MHz is not supported by wiphy {}",
+                    wiphy.name
+                ))
+            })?;
+            if info.contains(&Nl80211FrequencyInfo::Disabled) {
+                return Err(Nl80211Error::RequestFailed(format!(
+                    "Frequency {freq} MHz is disabled on wiphy {}",
+                    wiphy.name
+                )));
+            }
+            if let Some(flag) = prohibited.as_ref() {
+                if info.contains(flag) {
+                    return Err(Nl80211Error::RequestFailed(format!(
+                        "Frequency {freq} MHz of wiphy {} is flagged as \
+                        {flag:?}",
+                        wiphy.name
+                    )));
+                }
+            }
+        }
+
+        if self.width == Nl80211ChannelWidth::Mhz(40) {
+            let flag = if self.center_freq1 > self.control_freq {
+                Nl80211FrequencyInfo::NoHt40Plus
+            } else {
+                Nl80211FrequencyInfo::NoHt40Minus
+            };
+            if freqs
+                .get(&self.control_freq)
+                .map(|info| info.contains(&flag))
+                .unwrap_or(false)
+            {
+                return Err(Nl80211Error::RequestFailed(format!(
+                    "Frequency {} MHz of wiphy {} is flagged as {flag:?}",
+                    self.control_freq, wiphy.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Channel definition of the frequencies (MHz) as given, without
+    /// checking them against the channel plans
+    pub(crate) fn from_freqs(
+        control_freq: u32,
+        width: Nl80211ChannelWidth,
+        center_freq1: u32,
+        center_freq2: Option<u32>,
+    ) -> Self {
+        Self {
+            band: freq_to_band(control_freq),
+            control_freq,
+            width,
+            center_freq1,
+            center_freq2,
+        }
+    }
+
+    /// The attributes of the channel definition used by
+    /// `NL80211_CMD_SET_CHANNEL`, `NL80211_CMD_START_AP` and
+    /// `NL80211_CMD_CHANNEL_SWITCH`
+    pub(crate) fn to_attrs(&self) -> Vec<Nl80211Attr> {
+        let mut ret = vec![
+            Nl80211Attr::WiphyFreq(self.control_freq),
+            Nl80211Attr::ChannelWidth(self.width),
+            Nl80211Attr::CenterFreq1(self.center_freq1),
+        ];
+        if let Some(d) = self.center_freq2 {
+            ret.push(Nl80211Attr::CenterFreq2(d));
+        }
+        ret
+    }
+}
+
+/// Implement the `as_*_channel()` helpers of a request on top of its
+/// `as_chandef()`, `$verb` leads the doc of each helper
+macro_rules! impl_chandef_helpers {
+    ($request:ty, $verb:literal) => {
+        impl $request {
+            #[doc = concat!($verb, " basic 20MHz channel (no HT)")]
+            pub fn as_channel(self, freq: u32) -> Self {
+                self.as_chandef(&$crate::Nl80211Chandef::from_freqs(
+                    freq,
+                    $crate::Nl80211ChannelWidth::NoHt20,
+                    freq,
+                    None,
+                ))
+            }
+
+            #[doc = concat!($verb, " HT40+ channel")]
+            pub fn as_ht40_plus_channel(self, freq: u32) -> Self {
+                self.as_chandef(&$crate::Nl80211Chandef::from_freqs(
+                    freq,
+                    $crate::Nl80211ChannelWidth::Mhz(40),
+                    freq + 10,
+                    None,
+                ))
+            }
+
+            #[doc = concat!($verb, " HT40- channel")]
+            pub fn as_ht40_minus_channel(self, freq: u32) -> Self {
+                self.as_chandef(&$crate::Nl80211Chandef::from_freqs(
+                    freq,
+                    $crate::Nl80211ChannelWidth::Mhz(40),
+                    freq - 10,
+                    None,
+                ))
+            }
+
+            #[doc = concat!($verb, " VHT 80MHz channel")]
+            pub fn as_vht80_channel(
+                self,
+                freq: u32,
+                center_freq1: u32,
+            ) -> Self {
+                self.as_chandef(&$crate::Nl80211Chandef::from_freqs(
+                    freq,
+                    $crate::Nl80211ChannelWidth::Mhz(80),
+                    center_freq1,
+                    None,
+                ))
+            }
+
+            #[doc = concat!($verb, " VHT 160MHz channel")]
+            pub fn as_vht160_channel(
+                self,
+                freq: u32,
+                center_freq1: u32,
+            ) -> Self {
+                self.as_chandef(&$crate::Nl80211Chandef::from_freqs(
+                    freq,
+                    $crate::Nl80211ChannelWidth::Mhz(160),
+                    center_freq1,
+                    None,
+                ))
+            }
+
+            #[doc = concat!($verb, " VHT 80+80MHz channel")]
+            pub fn as_vht80p80_channel(
+                self,
+                freq: u32,
+                center_freq1: u32,
+                center_freq2: u32,
+            ) -> Self {
+                self.as_chandef(&$crate::Nl80211Chandef::from_freqs(
+                    freq,
+                    $crate::Nl80211ChannelWidth::Mhz80Plus80,
+                    center_freq1,
+                    Some(center_freq2),
+                ))
+            }
+
+            #[doc = concat!($verb, " EHT 320MHz channel")]
+            pub fn as_eht320_channel(
+                self,
+                freq: u32,
+                center_freq1: u32,
+            ) -> Self {
+                self.as_chandef(&$crate::Nl80211Chandef::from_freqs(
+                    freq,
+                    $crate::Nl80211ChannelWidth::Mhz(320),
+                    center_freq1,
+                    None,
+                ))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_chandef_helpers;
+
+// Band of the frequency (MHz), unlike freq_to_channel() it accepts any
+// frequency of the band
+fn freq_to_band(freq: u32) -> Nl80211BandType {
+    match freq {
+        0..=1000 => Nl80211BandType::BandS1GHz,
+        1001..=2500 => Nl80211BandType::Band2GHz,
+        2501..=5925 => Nl80211BandType::Band5GHz,
+        5926..=7125 => Nl80211BandType::Band6GHz,
+        _ => Nl80211BandType::Band60GHz,
+    }
+}
+
+fn invalid_center_freq2(center_freq2: u32) -> Nl80211Error {
+    Nl80211Error::RequestFailed(format!(
+        "{center_freq2} MHz is not a valid center frequency of the second \
+        80 MHz segment"
+    ))
+}
+
+fn freq_to_channel(freq: u32) -> Result<(Nl80211BandType, u32), Nl80211Error> {
+    match freq {
+        CHANNEL_2GHZ_14_FREQ => Ok((Nl80211BandType::Band2GHz, 14)),
+        CHANNEL_6GHZ_2_FREQ => Ok((Nl80211BandType::Band6GHz, 2)),
+        2412..=2472 if (freq - BAND_2GHZ_BASE) % 5 == 0 => {
+            Ok((Nl80211BandType::Band2GHz, (freq - BAND_2GHZ_BASE) / 5))
+        }
+        5160..=5885 if freq % 5 == 0 => {
+            Ok((Nl80211BandType::Band5GHz, (freq - BAND_5GHZ_BASE) / 5))
+        }
+        5955..=7115 if (freq - BAND_6GHZ_BASE) % 20 == 5 => {
+            Ok((Nl80211BandType::Band6GHz, (freq - BAND_6GHZ_BASE) / 5))
+        }
+        _ => Err(Nl80211Error::RequestFailed(format!(
+            "{freq} MHz is not a 2.4, 5 or 6 GHz channel"
+        ))),
+    }
+}
+
+fn channel_to_freq(band: Nl80211BandType, chan: u32) -> u32 {
+    match band {
+        Nl80211BandType::Band2GHz => BAND_2GHZ_BASE + chan * 5,
+        Nl80211BandType::Band5GHz => BAND_5GHZ_BASE + chan * 5,
+        _ => BAND_6GHZ_BASE + chan * 5,
+    }
+}
+
+/// Center channel number of the `mhz` wide channel containing the 20 MHz
+/// channel `chan`
+fn block_center(
+    band: Nl80211BandType,
+    chan: u32,
+    mhz: u32,
+    channelization: Option<Nl80211Eht320Channelization>,
+) -> Result<u32, Nl80211Error> {
+    // Number of channel numbers spanned by the 20 MHz channels of the
+    // block, e.g. 36, 40, 44, 48 for 80 MHz
+    let span = mhz / 5 - 4;
+    let starts: Vec<u32> = match (band, mhz) {
+        (Nl80211BandType::Band5GHz, 40) => BLOCKS_5GHZ_40MHZ.to_vec(),
+        (Nl80211BandType::Band5GHz, 80) => BLOCKS_5GHZ_80MHZ.to_vec(),
+        (Nl80211BandType::Band5GHz, 160) => BLOCKS_5GHZ_160MHZ.to_vec(),
+        (Nl80211BandType::Band6GHz, 320) => match channelization {
+            Some(Nl80211Eht320Channelization::Two) => {
+                BLOCKS_6GHZ_320MHZ_2.to_vec()
+            }
+            _ => BLOCKS_6GHZ_320MHZ_1.to_vec(),
+        },
+        (Nl80211BandType::Band6GHz, 40 | 80 | 160) => (1..=CHANNEL_6GHZ_MAX)
+            .step_by((mhz / 5) as usize)
+            .filter(|s| s + span <= CHANNEL_6GHZ_MAX)
+            .collect(),
+        _ => Vec::new(),
+    };
+    starts
+        .into_iter()
+        .find(|s| chan >= *s && chan <= s + span && (chan - s) % 4 == 0)
+        .map(|s| s + span / 2)
+        .ok_or_else(|| {
+            Nl80211Error::RequestFailed(format!(
+                "Channel {chan} of {band:?} is not part of any {mhz} MHz \
+                channel{}",
+                match channelization {
+                    Some(c) => format!(" of channelization {c:?}"),
+                    None => String::new(),
+                }
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Nl80211Band, Nl80211Frequency};
+
+    #[test]
+    fn center_freq1_of_known_channels() {
+        let cases = [
+            // (control freq, width, center_freq1)
+            (2412, Nl80211ChannelWidth::Mhz(40), 2422),
+            (2472, Nl80211ChannelWidth::Mhz(40), 2462),
+            (5200, Nl80211ChannelWidth::Mhz(40), 5190),
+            (5180, Nl80211ChannelWidth::Mhz(80), 5210),
+            (5500, Nl80211ChannelWidth::Mhz(80), 5530),
+            (5745, Nl80211ChannelWidth::Mhz(80), 5775),
+            (5180, Nl80211ChannelWidth::Mhz(160), 5250),
+            (5745, Nl80211ChannelWidth::Mhz(160), 5815),
+            (5955, Nl80211ChannelWidth::Mhz(80), 5985),
+            (6115, Nl80211ChannelWidth::Mhz(80), 6145),
+            (5955, Nl80211ChannelWidth::Mhz(160), 6025),
+            (5955, Nl80211ChannelWidth::Mhz(320), 6105),
+            (6435, Nl80211ChannelWidth::Mhz(320), 6425),
+        ];
+        for (control_freq, width, center_freq1) in cases {
+            let chandef = Nl80211Chandef::new(control_freq, width).unwrap();
+            assert_eq!(
+                chandef.center_freq1, center_freq1,
+                "{control_freq} MHz {width:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn center_freq1_of_eht320_channelization() {
+        let one =
+            Nl80211Chandef::new_eht320(6115, Nl80211Eht320Channelization::One)
+                .unwrap();
+        assert_eq!(one.center_freq1, 6105);
+        let two =
+            Nl80211Chandef::new_eht320(6115, Nl80211Eht320Channelization::Two)
+                .unwrap();
+        assert_eq!(two.center_freq1, 6265);
+        assert!(Nl80211Chandef::new_eht320(
+            5955,
+            Nl80211Eht320Channelization::Two
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn center_freq2_of_80p80() {
+        let chandef = Nl80211Chandef::new_80p80(5180, 5530).unwrap();
+        assert_eq!(chandef.center_freq1, 5210);
+        assert_eq!(chandef.center_freq2, Some(5530));
+        // Adjacent to the primary 80 MHz segment
+        assert!(Nl80211Chandef::new_80p80(5180, 5290).is_err());
+    }
+
+    // Wiphy supporting the frequencies (MHz) every 20 MHz from first to
+    // last of the band, with the flag set on the flagged frequency
+    fn wiphy(
+        kind: Nl80211BandType,
+        first: u32,
+        last: u32,
+        flagged: u32,
+        flag: Nl80211FrequencyInfo,
+    ) -> Nl80211Wiphy {
+        let freqs = (first..=last)
+            .step_by(20)
+            .enumerate()
+            .map(|(i, freq)| {
+                let mut info = vec![Nl80211FrequencyInfo::Freq(freq)];
+                if freq == flagged {
+                    info.push(flag.clone());
+                }
+                Nl80211Frequency {
+                    index: i as u16,
+                    info,
+                }
+            })
+            .collect();
+        Nl80211Wiphy {
+            name: "phy0".to_string(),
+            bands: vec![Nl80211Band {
+                kind,
+                info: vec![Nl80211BandInfo::Freqs(freqs)],
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn wiphy_5ghz(disabled: u32) -> Nl80211Wiphy {
+        wiphy(
+            Nl80211BandType::Band5GHz,
+            5180,
+            5240,
+            disabled,
+            Nl80211FrequencyInfo::Disabled,
+        )
+    }
+
+    #[test]
+    fn validate_rejects_disabled_channel() {
+        let chandef =
+            Nl80211Chandef::new(5180, Nl80211ChannelWidth::Mhz(80)).unwrap();
+        assert!(chandef.validate(&wiphy_5ghz(0)).is_ok());
+        // Channel 48 is not the control channel but part of the 80 MHz
+        // channel
+        assert!(chandef.validate(&wiphy_5ghz(5240)).is_err());
+        // Channel 48 is outside of the 36/40 HT40 channel
+        let chandef =
+            Nl80211Chandef::new(5180, Nl80211ChannelWidth::Mhz(40)).unwrap();
+        assert!(chandef.validate(&wiphy_5ghz(5240)).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_prohibited_width() {
+        let cases = [
+            // (control freq, width, flagged freq, flag, valid)
+            (5180, 40, 5180, Nl80211FrequencyInfo::NoHt40Plus, false),
+            (5180, 40, 5180, Nl80211FrequencyInfo::NoHt40Minus, true),
+            (5200, 40, 5200, Nl80211FrequencyInfo::NoHt40Minus, false),
+            (5200, 40, 5200, Nl80211FrequencyInfo::NoHt40Plus, true),
+            // Only the flags of the HT40 control channel apply
+            (5180, 40, 5200, Nl80211FrequencyInfo::NoHt40Plus, true),
+            (5180, 80, 5240, Nl80211FrequencyInfo::No80Mhz, false),
+            (5180, 80, 5240, Nl80211FrequencyInfo::No160Mhz, true),
+            (5180, 160, 5320, Nl80211FrequencyInfo::No160Mhz, false),
+            (5180, 160, 5320, Nl80211FrequencyInfo::No80Mhz, true),
+            (5180, 160, 5320, Nl80211FrequencyInfo::No320Mhz, true),
+            (5955, 320, 6255, Nl80211FrequencyInfo::No320Mhz, false),
+            (5955, 320, 6255, Nl80211FrequencyInfo::No160Mhz, true),
+        ];
+        for (control_freq, mhz, flagged, flag, valid) in cases {
+            let (kind, first, last) = if control_freq < 5950 {
+                (Nl80211BandType::Band5GHz, 5180, 5320)
+            } else {
+                (Nl80211BandType::Band6GHz, 5955, 6255)
+            };
+            let chandef = Nl80211Chandef::new(
+                control_freq,
+                Nl80211ChannelWidth::Mhz(mhz),
+            )
+            .unwrap();
+            assert_eq!(
+                chandef
+                    .validate(&wiphy(kind, first, last, flagged, flag.clone()))
+                    .is_ok(),
+                valid,
+                "{control_freq} MHz {mhz} MHz with {flagged} MHz {flag:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn ht40_fallback_of_2ghz_upper_channels() {
+        let cases = [
+            // (control freq, center_freq1 of Nl80211Chandef::new())
+            // Channel 9 is the last one with HT40+
+            (2452, Some(2462)),
+            (2457, Some(2447)),
+            (2462, Some(2452)),
+            (2467, Some(2457)),
+            (2472, Some(2462)),
+            // Channel 14 has no HT40 channel
+            (2484, None),
+        ];
+        for (control_freq, center_freq1) in cases {
+            let chandef =
+                Nl80211Chandef::new(control_freq, Nl80211ChannelWidth::Mhz(40));
+            assert_eq!(
+                chandef.ok().map(|c| c.center_freq1),
+                center_freq1,
+                "{control_freq} MHz"
+            );
+            // HT40+ up to channel 9, HT40- from channel 5 to 13
+            assert_eq!(
+                Nl80211Chandef::new_ht40(
+                    control_freq,
+                    Nl80211HtWiphyChannelType::Ht40Plus
+                )
+                .map(|c| c.center_freq1)
+                .ok(),
+                (control_freq <= 2452).then_some(control_freq + 10),
+                "{control_freq} MHz"
+            );
+            assert_eq!(
+                Nl80211Chandef::new_ht40(
+                    control_freq,
+                    Nl80211HtWiphyChannelType::Ht40Minus
+                )
+                .map(|c| c.center_freq1)
+                .ok(),
+                (control_freq != 2484).then_some(control_freq - 10),
+                "{control_freq} MHz"
+            );
+        }
+    }
+}
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{bytes::write_u32, Nl80211BandType};
+
+const NL80211_BSS_SELECT_ATTR_RSSI: u16 = 1;
+const NL80211_BSS_SELECT_ATTR_BAND_PREF: u16 = 2;
+const NL80211_BSS_SELECT_ATTR_RSSI_ADJUST: u16 = 3;
+
+/// BSS selection behaviour of driver for `NL80211_CMD_CONNECT`, used when
+/// driver handles BSS selection and roaming (background scan) by itself.
+/// Only one of them should be provided.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Nl80211BssSelect {
+    /// Select BSS with highest RSSI
+    Rssi,
+    /// Prefer BSS on specified band when RSSI is similar
+    BandPref(Nl80211BandType),
+    /// Adjust RSSI of BSS on specified band by delta in dB before
+    /// selection
+    RssiAdjust(Nl80211BandType, i8),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211BssSelect {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Rssi => 0,
+            Self::BandPref(_) => 4,
+            Self::RssiAdjust(_, _) => 2,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Rssi => (),
+            Self::BandPref(band) => write_u32(buffer, u16::from(*band) as u32),
+            Self::RssiAdjust(band, delta) => {
+                buffer[0] = u16::from(*band) as u8;
+                buffer[1] = *delta as u8;
+            }
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Rssi => NL80211_BSS_SELECT_ATTR_RSSI,
+            Self::BandPref(_) => NL80211_BSS_SELECT_ATTR_BAND_PREF,
+            Self::RssiAdjust(_, _) => NL80211_BSS_SELECT_ATTR_RSSI_ADJUST,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211BssSelect
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_BSS_SELECT_ATTR_RSSI => Self::Rssi,
+            NL80211_BSS_SELECT_ATTR_BAND_PREF => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_SELECT_ATTR_BAND_PREF value {:?}",
+                    payload
+                );
+                let band = parse_u32(payload).context(err_msg)?;
+                Self::BandPref(Nl80211BandType::from(band as u16))
+            }
+            NL80211_BSS_SELECT_ATTR_RSSI_ADJUST => {
+                if payload.len() < 2 {
+                    return Err(format!(
+                        "Invalid NL80211_BSS_SELECT_ATTR_RSSI_ADJUST value \
+                        {:?}",
+                        payload
+                    )
+                    .into());
+                }
+                Self::RssiAdjust(
+                    Nl80211BandType::from(payload[0] as u16),
+                    payload[1] as i8,
+                )
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,313 @@
+// SPDX-License-Identifier: MIT
+
+// Reason codes defined in IEEE 802.11-2020 table 9-49
+const WLAN_REASON_UNSPECIFIED: u16 = 1;
+const WLAN_REASON_PREV_AUTH_NOT_VALID: u16 = 2;
+const WLAN_REASON_DEAUTH_LEAVING: u16 = 3;
+const WLAN_REASON_DISASSOC_DUE_TO_INACTIVITY: u16 = 4;
+const WLAN_REASON_DISASSOC_AP_BUSY: u16 = 5;
+const WLAN_REASON_CLASS2_FRAME_FROM_NONAUTH_STA: u16 = 6;
+const WLAN_REASON_CLASS3_FRAME_FROM_NONASSOC_STA: u16 = 7;
+const WLAN_REASON_DISASSOC_STA_HAS_LEFT: u16 = 8;
+const WLAN_REASON_STA_REQ_ASSOC_WITHOUT_AUTH: u16 = 9;
+const WLAN_REASON_INVALID_IE: u16 = 13;
+const WLAN_REASON_MICHAEL_MIC_FAILURE: u16 = 14;
+const WLAN_REASON_4WAY_HANDSHAKE_TIMEOUT: u16 = 15;
+const WLAN_REASON_GROUP_KEY_UPDATE_TIMEOUT: u16 = 16;
+const WLAN_REASON_IE_DIFFERENT: u16 = 17;
+const WLAN_REASON_INVALID_GROUP_CIPHER: u16 = 18;
+const WLAN_REASON_INVALID_PAIRWISE_CIPHER: u16 = 19;
+const WLAN_REASON_INVALID_AKMP: u16 = 20;
+const WLAN_REASON_UNSUPP_RSN_VERSION: u16 = 21;
+const WLAN_REASON_INVALID_RSN_IE_CAP: u16 = 22;
+const WLAN_REASON_IEEE8021X_FAILED: u16 = 23;
+const WLAN_REASON_CIPHER_SUITE_REJECTED: u16 = 24;
+
+/// Reason code carried by deauthentication and disassociation
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Nl80211ReasonCode {
+    Unspecified,
+    PrevAuthNotValid,
+    DeauthLeaving,
+    DisassocDueToInactivity,
+    DisassocApBusy,
+    Class2FrameFromNonauthSta,
+    Class3FrameFromNonassocSta,
+    DisassocStaHasLeft,
+    StaReqAssocWithoutAuth,
+    InvalidIe,
+    MichaelMicFailure,
+    FourWayHandshakeTimeout,
+    GroupKeyUpdateTimeout,
+    IeDifferent,
+    InvalidGroupCipher,
+    InvalidPairwiseCipher,
+    InvalidAkmp,
+    UnsupportedRsnVersion,
+    InvalidRsnIeCapability,
+    Ieee8021xFailed,
+    CipherSuiteRejected,
+    Other(u16),
+}
+
+impl From<u16> for Nl80211ReasonCode {
+    fn from(d: u16) -> Self {
+        match d {
+            WLAN_REASON_UNSPECIFIED => Self::Unspecified,
+            WLAN_REASON_PREV_AUTH_NOT_VALID => Self::PrevAuthNotValid,
+            WLAN_REASON_DEAUTH_LEAVING => Self::DeauthLeaving,
+            WLAN_REASON_DISASSOC_DUE_TO_INACTIVITY => {
+                Self::DisassocDueToInactivity
+            }
+            WLAN_REASON_DISASSOC_AP_BUSY => Self::DisassocApBusy,
+            WLAN_REASON_CLASS2_FRAME_FROM_NONAUTH_STA => {
+                Self::Class2FrameFromNonauthSta
+            }
+            WLAN_REASON_CLASS3_FRAME_FROM_NONASSOC_STA => {
+                Self::Class3FrameFromNonassocSta
+            }
+            WLAN_REASON_DISASSOC_STA_HAS_LEFT => Self::DisassocStaHasLeft,
+            WLAN_REASON_STA_REQ_ASSOC_WITHOUT_AUTH => {
+                Self::StaReqAssocWithoutAuth
+            }
+            WLAN_REASON_INVALID_IE => Self::InvalidIe,
+            WLAN_REASON_MICHAEL_MIC_FAILURE => Self::MichaelMicFailure,
+            WLAN_REASON_4WAY_HANDSHAKE_TIMEOUT => Self::FourWayHandshakeTimeout,
+            WLAN_REASON_GROUP_KEY_UPDATE_TIMEOUT => Self::GroupKeyUpdateTimeout,
+            WLAN_REASON_IE_DIFFERENT => Self::IeDifferent,
+            WLAN_REASON_INVALID_GROUP_CIPHER => Self::InvalidGroupCipher,
+            WLAN_REASON_INVALID_PAIRWISE_CIPHER => Self::InvalidPairwiseCipher,
+            WLAN_REASON_INVALID_AKMP => Self::InvalidAkmp,
+            WLAN_REASON_UNSUPP_RSN_VERSION => Self::UnsupportedRsnVersion,
+            WLAN_REASON_INVALID_RSN_IE_CAP => Self::InvalidRsnIeCapability,
+            WLAN_REASON_IEEE8021X_FAILED => Self::Ieee8021xFailed,
+            WLAN_REASON_CIPHER_SUITE_REJECTED => Self::CipherSuiteRejected,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211ReasonCode> for u16 {
+    fn from(v: Nl80211ReasonCode) -> u16 {
+        match v {
+            Nl80211ReasonCode::Unspecified => WLAN_REASON_UNSPECIFIED,
+            Nl80211ReasonCode::PrevAuthNotValid => {
+                WLAN_REASON_PREV_AUTH_NOT_VALID
+            }
+            Nl80211ReasonCode::DeauthLeaving => WLAN_REASON_DEAUTH_LEAVING,
+            Nl80211ReasonCode::DisassocDueToInactivity => {
+                WLAN_REASON_DISASSOC_DUE_TO_INACTIVITY
+            }
+            Nl80211ReasonCode::DisassocApBusy => WLAN_REASON_DISASSOC_AP_BUSY,
+            Nl80211ReasonCode::Class2FrameFromNonauthSta => {
+                WLAN_REASON_CLASS2_FRAME_FROM_NONAUTH_STA
+            }
+            Nl80211ReasonCode::Class3FrameFromNonassocSta => {
+                WLAN_REASON_CLASS3_FRAME_FROM_NONASSOC_STA
+            }
+            Nl80211ReasonCode::DisassocStaHasLeft => {
+                WLAN_REASON_DISASSOC_STA_HAS_LEFT
+            }
+            Nl80211ReasonCode::StaReqAssocWithoutAuth => {
+                WLAN_REASON_STA_REQ_ASSOC_WITHOUT_AUTH
+            }
+            Nl80211ReasonCode::InvalidIe => WLAN_REASON_INVALID_IE,
+            Nl80211ReasonCode::MichaelMicFailure => {
+                WLAN_REASON_MICHAEL_MIC_FAILURE
+            }
+            Nl80211ReasonCode::FourWayHandshakeTimeout => {
+                WLAN_REASON_4WAY_HANDSHAKE_TIMEOUT
+            }
+            Nl80211ReasonCode::GroupKeyUpdateTimeout => {
+                WLAN_REASON_GROUP_KEY_UPDATE_TIMEOUT
+            }
+            Nl80211ReasonCode::IeDifferent => WLAN_REASON_IE_DIFFERENT,
+            Nl80211ReasonCode::InvalidGroupCipher => {
+                WLAN_REASON_INVALID_GROUP_CIPHER
+            }
+            Nl80211ReasonCode::InvalidPairwiseCipher => {
+                WLAN_REASON_INVALID_PAIRWISE_CIPHER
+            }
+            Nl80211ReasonCode::InvalidAkmp => WLAN_REASON_INVALID_AKMP,
+            Nl80211ReasonCode::UnsupportedRsnVersion => {
+                WLAN_REASON_UNSUPP_RSN_VERSION
+            }
+            Nl80211ReasonCode::InvalidRsnIeCapability => {
+                WLAN_REASON_INVALID_RSN_IE_CAP
+            }
+            Nl80211ReasonCode::Ieee8021xFailed => WLAN_REASON_IEEE8021X_FAILED,
+            Nl80211ReasonCode::CipherSuiteRejected => {
+                WLAN_REASON_CIPHER_SUITE_REJECTED
+            }
+            Nl80211ReasonCode::Other(d) => d,
+        }
+    }
+}
+
+// Status codes defined in IEEE 802.11-2020 table 9-50
+const WLAN_STATUS_SUCCESS: u16 = 0;
+const WLAN_STATUS_UNSPECIFIED_FAILURE: u16 = 1;
+const WLAN_STATUS_CAPS_UNSUPPORTED: u16 = 10;
+const WLAN_STATUS_REASSOC_NO_ASSOC: u16 = 11;
+const WLAN_STATUS_ASSOC_DENIED_UNSPEC: u16 = 12;
+const WLAN_STATUS_NOT_SUPPORTED_AUTH_ALG: u16 = 13;
+const WLAN_STATUS_UNKNOWN_AUTH_TRANSACTION: u16 = 14;
+const WLAN_STATUS_CHALLENGE_FAIL: u16 = 15;
+const WLAN_STATUS_AUTH_TIMEOUT: u16 = 16;
+const WLAN_STATUS_AP_UNABLE_TO_HANDLE_NEW_STA: u16 = 17;
+const WLAN_STATUS_ASSOC_DENIED_RATES: u16 = 18;
+const WLAN_STATUS_ASSOC_REJECTED_TEMPORARILY: u16 = 30;
+const WLAN_STATUS_ROBUST_MGMT_FRAME_POLICY_VIOLATION: u16 = 31;
+const WLAN_STATUS_INVALID_IE: u16 = 40;
+const WLAN_STATUS_INVALID_GROUP_CIPHER: u16 = 41;
+const WLAN_STATUS_INVALID_PAIRWISE_CIPHER: u16 = 42;
+const WLAN_STATUS_INVALID_AKMP: u16 = 43;
+const WLAN_STATUS_UNSUPP_RSN_VERSION: u16 = 44;
+const WLAN_STATUS_INVALID_RSN_IE_CAP: u16 = 45;
+const WLAN_STATUS_CIPHER_SUITE_REJECTED: u16 = 46;
+const WLAN_STATUS_INVALID_PMKID: u16 = 53;
+const WLAN_STATUS_ANTI_CLOG_REQUIRED: u16 = 76;
+const WLAN_STATUS_FINITE_CYCLIC_GROUP_NOT_SUPPORTED: u16 = 77;
+const WLAN_STATUS_SAE_HASH_TO_ELEMENT: u16 = 126;
+const WLAN_STATUS_SAE_PK: u16 = 127;
+
+/// Status code carried by authentication and association responses
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Nl80211StatusCode {
+    Success,
+    UnspecifiedFailure,
+    CapsUnsupported,
+    ReassocNoAssoc,
+    AssocDeniedUnspec,
+    NotSupportedAuthAlg,
+    UnknownAuthTransaction,
+    ChallengeFail,
+    AuthTimeout,
+    ApUnableToHandleNewSta,
+    AssocDeniedRates,
+    AssocRejectedTemporarily,
+    RobustMgmtFramePolicyViolation,
+    InvalidIe,
+    InvalidGroupCipher,
+    InvalidPairwiseCipher,
+    InvalidAkmp,
+    UnsupportedRsnVersion,
+    InvalidRsnIeCapability,
+    CipherSuiteRejected,
+    InvalidPmkid,
+    AntiCloggingTokenRequired,
+    FiniteCyclicGroupNotSupported,
+    SaeHashToElement,
+    SaePk,
+    Other(u16),
+}
+
+impl From<u16> for Nl80211StatusCode {
+    fn from(d: u16) -> Self {
+        match d {
+            WLAN_STATUS_SUCCESS => Self::Success,
+            WLAN_STATUS_UNSPECIFIED_FAILURE => Self::UnspecifiedFailure,
+            WLAN_STATUS_CAPS_UNSUPPORTED => Self::CapsUnsupported,
+            WLAN_STATUS_REASSOC_NO_ASSOC => Self::ReassocNoAssoc,
+            WLAN_STATUS_ASSOC_DENIED_UNSPEC => Self::AssocDeniedUnspec,
+            WLAN_STATUS_NOT_SUPPORTED_AUTH_ALG => Self::NotSupportedAuthAlg,
+            WLAN_STATUS_UNKNOWN_AUTH_TRANSACTION => {
+                Self::UnknownAuthTransaction
+            }
+            WLAN_STATUS_CHALLENGE_FAIL => Self::ChallengeFail,
+            WLAN_STATUS_AUTH_TIMEOUT => Self::AuthTimeout,
+            WLAN_STATUS_AP_UNABLE_TO_HANDLE_NEW_STA => {
+                Self::ApUnableToHandleNewSta
+            }
+            WLAN_STATUS_ASSOC_DENIED_RATES => Self::AssocDeniedRates,
+            WLAN_STATUS_ASSOC_REJECTED_TEMPORARILY => {
+                Self::AssocRejectedTemporarily
+            }
+            WLAN_STATUS_ROBUST_MGMT_FRAME_POLICY_VIOLATION => {
+                Self::RobustMgmtFramePolicyViolation
+            }
+            WLAN_STATUS_INVALID_IE => Self::InvalidIe,
+            WLAN_STATUS_INVALID_GROUP_CIPHER => Self::InvalidGroupCipher,
+            WLAN_STATUS_INVALID_PAIRWISE_CIPHER => Self::InvalidPairwiseCipher,
+            WLAN_STATUS_INVALID_AKMP => Self::InvalidAkmp,
+            WLAN_STATUS_UNSUPP_RSN_VERSION => Self::UnsupportedRsnVersion,
+            WLAN_STATUS_INVALID_RSN_IE_CAP => Self::InvalidRsnIeCapability,
+            WLAN_STATUS_CIPHER_SUITE_REJECTED => Self::CipherSuiteRejected,
+            WLAN_STATUS_INVALID_PMKID => Self::InvalidPmkid,
+            WLAN_STATUS_ANTI_CLOG_REQUIRED => Self::AntiCloggingTokenRequired,
+            WLAN_STATUS_FINITE_CYCLIC_GROUP_NOT_SUPPORTED => {
+                Self::FiniteCyclicGroupNotSupported
+            }
+            WLAN_STATUS_SAE_HASH_TO_ELEMENT => Self::SaeHashToElement,
+            WLAN_STATUS_SAE_PK => Self::SaePk,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211StatusCode> for u16 {
+    fn from(v: Nl80211StatusCode) -> u16 {
+        match v {
+            Nl80211StatusCode::Success => WLAN_STATUS_SUCCESS,
+            Nl80211StatusCode::UnspecifiedFailure => {
+                WLAN_STATUS_UNSPECIFIED_FAILURE
+            }
+            Nl80211StatusCode::CapsUnsupported => WLAN_STATUS_CAPS_UNSUPPORTED,
+            Nl80211StatusCode::ReassocNoAssoc => WLAN_STATUS_REASSOC_NO_ASSOC,
+            Nl80211StatusCode::AssocDeniedUnspec => {
+                WLAN_STATUS_ASSOC_DENIED_UNSPEC
+            }
+            Nl80211StatusCode::NotSupportedAuthAlg => {
+                WLAN_STATUS_NOT_SUPPORTED_AUTH_ALG
+            }
+            Nl80211StatusCode::UnknownAuthTransaction => {
+                WLAN_STATUS_UNKNOWN_AUTH_TRANSACTION
+            }
+            Nl80211StatusCode::ChallengeFail => WLAN_STATUS_CHALLENGE_FAIL,
+            Nl80211StatusCode::AuthTimeout => WLAN_STATUS_AUTH_TIMEOUT,
+            Nl80211StatusCode::ApUnableToHandleNewSta => {
+                WLAN_STATUS_AP_UNABLE_TO_HANDLE_NEW_STA
+            }
+            Nl80211StatusCode::AssocDeniedRates => {
+                WLAN_STATUS_ASSOC_DENIED_RATES
+            }
+            Nl80211StatusCode::AssocRejectedTemporarily => {
+                WLAN_STATUS_ASSOC_REJECTED_TEMPORARILY
+            }
+            Nl80211StatusCode::RobustMgmtFramePolicyViolation => {
+                WLAN_STATUS_ROBUST_MGMT_FRAME_POLICY_VIOLATION
+            }
+            Nl80211StatusCode::InvalidIe => WLAN_STATUS_INVALID_IE,
+            Nl80211StatusCode::InvalidGroupCipher => {
+                WLAN_STATUS_INVALID_GROUP_CIPHER
+            }
+            Nl80211StatusCode::InvalidPairwiseCipher => {
+                WLAN_STATUS_INVALID_PAIRWISE_CIPHER
+            }
+            Nl80211StatusCode::InvalidAkmp => WLAN_STATUS_INVALID_AKMP,
+            Nl80211StatusCode::UnsupportedRsnVersion => {
+                WLAN_STATUS_UNSUPP_RSN_VERSION
+            }
+            Nl80211StatusCode::InvalidRsnIeCapability => {
+                WLAN_STATUS_INVALID_RSN_IE_CAP
+            }
+            Nl80211StatusCode::CipherSuiteRejected => {
+                WLAN_STATUS_CIPHER_SUITE_REJECTED
+            }
+            Nl80211StatusCode::InvalidPmkid => WLAN_STATUS_INVALID_PMKID,
+            Nl80211StatusCode::AntiCloggingTokenRequired => {
+                WLAN_STATUS_ANTI_CLOG_REQUIRED
+            }
+            Nl80211StatusCode::FiniteCyclicGroupNotSupported => {
+                WLAN_STATUS_FINITE_CYCLIC_GROUP_NOT_SUPPORTED
+            }
+            Nl80211StatusCode::SaeHashToElement => {
+                WLAN_STATUS_SAE_HASH_TO_ELEMENT
+            }
+            Nl80211StatusCode::SaePk => WLAN_STATUS_SAE_PK,
+            Nl80211StatusCode::Other(d) => d,
+        }
+    }
+}
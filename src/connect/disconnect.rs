@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211ReasonCode,
+};
+
+/// Disconnect from current network
+/// (equivalent to `iw dev DEVICE disconnect`).
+pub struct Nl80211DisconnectRequest {
+    handle: Nl80211Handle,
+    attrs: Vec<Nl80211Attr>,
+}
+
+impl Nl80211DisconnectRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        reason: Nl80211ReasonCode,
+    ) -> Self {
+        Nl80211DisconnectRequest {
+            handle,
+            attrs: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::ReasonCode(reason),
+            ],
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211DisconnectRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::Disconnect,
+            attributes: attrs,
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
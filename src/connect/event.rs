@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211Element, Nl80211ReasonCode, Nl80211StatusCode,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Result of `NL80211_CMD_CONNECT` or `NL80211_CMD_ROAM`, sent by kernel to
+/// the "mlme" multicast group.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211ConnectEvent {
+    pub wiphy: Option<u32>,
+    pub if_index: Option<u32>,
+    pub bssid: Option<[u8; ETH_ALEN]>,
+    /// Frequency in MHz of the BSS connected to
+    pub frequency: Option<u32>,
+    /// Status code of the (re)association response, not included in roam
+    /// event
+    pub status: Option<Nl80211StatusCode>,
+    /// No response received from the AP
+    pub timed_out: bool,
+    /// `NL80211_TIMEOUT_*` reason when `timed_out` is true
+    pub timeout_reason: Option<u32>,
+    /// IEs of the (re)association request
+    pub req_ies: Vec<Nl80211Element>,
+    /// IEs of the (re)association response
+    pub resp_ies: Vec<Nl80211Element>,
+    /// All the attributes of the event
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl Nl80211ConnectEvent {
+    /// Whether the connection is established, roam event has no status
+    /// code and always means success.
+    pub fn is_success(&self) -> bool {
+        !self.timed_out
+            && matches!(self.status, None | Some(Nl80211StatusCode::Success))
+    }
+}
+
+impl From<Vec<Nl80211Attr>> for Nl80211ConnectEvent {
+    fn from(attributes: Vec<Nl80211Attr>) -> Self {
+        let mut ret = Self::default();
+        for attr in attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Mac(d) => ret.bssid = Some(*d),
+                Nl80211Attr::WiphyFreq(d) => ret.frequency = Some(*d),
+                Nl80211Attr::StatusCode(d) => ret.status = Some(*d),
+                Nl80211Attr::TimedOut => ret.timed_out = true,
+                Nl80211Attr::TimeoutReason(d) => {
+                    ret.timeout_reason = Some(*d)
+                }
+                Nl80211Attr::ReqIe(d) => ret.req_ies = d.clone().into(),
+                Nl80211Attr::RespIe(d) => ret.resp_ies = d.clone().into(),
+                _ => (),
+            }
+        }
+        ret.attributes = attributes;
+        ret
+    }
+}
+
+/// Notification of connection lost, sent by kernel to the "mlme"
+/// multicast group.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211DisconnectEvent {
+    pub wiphy: Option<u32>,
+    pub if_index: Option<u32>,
+    pub reason: Option<Nl80211ReasonCode>,
+    /// Disconnection was initiated by the AP instead of local request
+    pub by_ap: bool,
+    /// IEs of the deauthentication or disassociation frame
+    pub ies: Vec<Nl80211Element>,
+    /// All the attributes of the event
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl From<Vec<Nl80211Attr>> for Nl80211DisconnectEvent {
+    fn from(attributes: Vec<Nl80211Attr>) -> Self {
+        let mut ret = Self::default();
+        for attr in attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::ReasonCode(d) => ret.reason = Some(*d),
+                Nl80211Attr::DisconnectedByAp => ret.by_ap = true,
+                Nl80211Attr::Ie(d) => ret.ies = d.clone().into(),
+                _ => (),
+            }
+        }
+        ret.attributes = attributes;
+        ret
+    }
+}
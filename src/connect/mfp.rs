@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_MFP_NO: u32 = 0;
+const NL80211_MFP_REQUIRED: u32 = 1;
+const NL80211_MFP_OPTIONAL: u32 = 2;
+
+/// Management frame protection (802.11w) state used for connection
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Nl80211Mfp {
+    /// Management frame protection not used
+    No,
+    /// Management frame protection required
+    Required,
+    /// Management frame protection is optional
+    Optional,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211Mfp {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_MFP_NO => Self::No,
+            NL80211_MFP_REQUIRED => Self::Required,
+            NL80211_MFP_OPTIONAL => Self::Optional,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211Mfp> for u32 {
+    fn from(v: Nl80211Mfp) -> u32 {
+        match v {
+            Nl80211Mfp::No => NL80211_MFP_NO,
+            Nl80211Mfp::Required => NL80211_MFP_REQUIRED,
+            Nl80211Mfp::Optional => NL80211_MFP_OPTIONAL,
+            Nl80211Mfp::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod bss_select;
+mod code;
+mod disconnect;
+mod event;
+mod mfp;
+mod request;
+
+pub use self::bss_select::Nl80211BssSelect;
+pub use self::code::{Nl80211ReasonCode, Nl80211StatusCode};
+pub use self::disconnect::Nl80211DisconnectRequest;
+pub use self::event::{Nl80211ConnectEvent, Nl80211DisconnectEvent};
+pub use self::mfp::Nl80211Mfp;
+pub use self::request::Nl80211ConnectRequest;
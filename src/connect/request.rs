@@ -0,0 +1,212 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211AkmSuite, Nl80211Attr, Nl80211AttrsBuilder,
+    Nl80211AuthType, Nl80211BssSelect, Nl80211CipherSuite, Nl80211Command,
+    Nl80211Element, Nl80211Elements, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211Mfp,
+};
+
+const ETH_ALEN: usize = 6;
+const ETH_P_PAE: u16 = 0x888E;
+
+const NL80211_WPA_VERSION_2: u32 = 1 << 1;
+const NL80211_WPA_VERSION_3: u32 = 1 << 2;
+
+/// Connect to a network with driver handling the SME
+/// (equivalent to `iw dev DEVICE connect`).
+///
+/// The result of connection is reported asynchronously as
+/// [crate::Nl80211Event::Connect] to the "mlme" multicast group.
+pub struct Nl80211ConnectRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211ConnectRequest>,
+}
+
+impl Nl80211ConnectRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        ssid: String,
+    ) -> Self {
+        Nl80211ConnectRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::Ssid(ssid)),
+        }
+    }
+
+    /// Only connect to the specified BSSID
+    pub fn with_bssid(mut self, bssid: [u8; ETH_ALEN]) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Mac(bssid));
+        self
+    }
+
+    /// Suggest the BSSID, driver may still choose another BSS of the same
+    /// network
+    pub fn with_bssid_hint(mut self, bssid: [u8; ETH_ALEN]) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MacHint(bssid));
+        self
+    }
+
+    /// Only connect on specified frequency in MHz
+    pub fn with_frequency(mut self, freq: u32) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::WiphyFreq(freq));
+        self
+    }
+
+    /// Suggest the frequency in MHz, driver may still choose another one
+    pub fn with_frequency_hint(mut self, freq: u32) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::WiphyFreqHint(freq));
+        self
+    }
+
+    /// Authentication type, kernel will try all supported types when not
+    /// defined
+    pub fn with_auth_type(mut self, auth_type: Nl80211AuthType) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::AuthType(auth_type));
+        self
+    }
+
+    /// Bitmap of `NL80211_WPA_VERSION_*`
+    pub fn with_wpa_versions(mut self, versions: u32) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::WpaVersions(versions));
+        self
+    }
+
+    pub fn with_pairwise_ciphers(
+        mut self,
+        ciphers: Vec<Nl80211CipherSuite>,
+    ) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::CipherSuitesPairwise(ciphers));
+        self
+    }
+
+    pub fn with_group_cipher(mut self, cipher: Nl80211CipherSuite) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::CipherSuiteGroup(cipher));
+        self
+    }
+
+    pub fn with_akm_suites(mut self, akm_suites: Vec<Nl80211AkmSuite>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::AkmSuites(akm_suites));
+        self
+    }
+
+    /// Indicate the network is protected
+    pub fn with_privacy(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Privacy);
+        self
+    }
+
+    /// Pairwise master key for driver offloaded 4-way handshake
+    pub fn with_pmk(mut self, pmk: Vec<u8>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Pmk(pmk));
+        self
+    }
+
+    /// SAE password for driver offloaded SAE authentication
+    pub fn with_sae_password(mut self, password: Vec<u8>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::SaePassword(password));
+        self
+    }
+
+    /// Extra information elements for the (re)association request
+    pub fn with_ies(mut self, ies: &[Nl80211Element]) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::Ie(Nl80211Elements::from(ies)));
+        self
+    }
+
+    /// Userspace handles IEEE 802.1X/EAPOL frames, the kernel will not
+    /// forward data frames before the port is authorized
+    pub fn with_control_port(mut self) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::ControlPort)
+            .replace(Nl80211Attr::ControlPortEthertype(ETH_P_PAE));
+        self
+    }
+
+    /// Deliver the EAPOL frames over nl80211 instead of the network
+    /// interface
+    pub fn with_control_port_over_nl80211(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::ControlPortOverNl80211);
+        self
+    }
+
+    /// Management frame protection
+    pub fn with_mfp(mut self, mfp: Nl80211Mfp) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::UseMfp(mfp));
+        self
+    }
+
+    /// Background scan period in seconds for roaming, `0` disables the
+    /// background scan. Only honoured by drivers roaming in firmware.
+    pub fn with_bg_scan_period(mut self, seconds: u16) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::BgScanPeriod(seconds));
+        self
+    }
+
+    /// BSS selection and roaming behaviour of driver
+    pub fn with_bss_select(mut self, select: Vec<Nl80211BssSelect>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::BssSelect(select));
+        self
+    }
+
+    /// Kernel will disconnect once the netlink socket of this handle is
+    /// closed
+    pub fn with_socket_owner(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::SocketOwner);
+        self
+    }
+
+    /// Connect to WPA2-PSK network with CCMP cipher, the 4-way handshake
+    /// is offloaded to driver using the specified PMK.
+    /// Require [crate::Nl80211ExtFeature::FourWayHandshakeStaPsk].
+    pub fn wpa2_psk(self, pmk: Vec<u8>) -> Self {
+        self.with_wpa_versions(NL80211_WPA_VERSION_2)
+            .with_pairwise_ciphers(vec![Nl80211CipherSuite::Ccmp])
+            .with_group_cipher(Nl80211CipherSuite::Ccmp)
+            .with_akm_suites(vec![Nl80211AkmSuite::Psk])
+            .with_privacy()
+            .with_pmk(pmk)
+    }
+
+    /// Connect to WPA3-SAE network with CCMP cipher, the SAE
+    /// authentication is offloaded to driver using the specified password.
+    /// Require [crate::Nl80211ExtFeature::SaeOffload].
+    pub fn wpa3_sae(self, password: Vec<u8>) -> Self {
+        self.with_wpa_versions(NL80211_WPA_VERSION_3)
+            .with_auth_type(Nl80211AuthType::SAE)
+            .with_pairwise_ciphers(vec![Nl80211CipherSuite::Ccmp])
+            .with_group_cipher(Nl80211CipherSuite::Ccmp)
+            .with_akm_suites(vec![Nl80211AkmSuite::Sae])
+            .with_privacy()
+            .with_mfp(Nl80211Mfp::Required)
+            .with_sae_password(password)
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211ConnectRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::Connect,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
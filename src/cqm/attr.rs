@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_i32, parse_u32},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::bytes::{write_i32, write_u32};
+#[cfg(doc)]
+use crate::Nl80211Attr;
+
+const NL80211_ATTR_CQM_RSSI_THOLD: u16 = 1;
+const NL80211_ATTR_CQM_RSSI_HYST: u16 = 2;
+const NL80211_ATTR_CQM_RSSI_THRESHOLD_EVENT: u16 = 3;
+const NL80211_ATTR_CQM_PKT_LOSS_EVENT: u16 = 4;
+const NL80211_ATTR_CQM_TXE_RATE: u16 = 5;
+const NL80211_ATTR_CQM_TXE_PKTS: u16 = 6;
+const NL80211_ATTR_CQM_TXE_INTVL: u16 = 7;
+const NL80211_ATTR_CQM_BEACON_LOSS_EVENT: u16 = 8;
+const NL80211_ATTR_CQM_RSSI_LEVEL: u16 = 9;
+
+const NL80211_CQM_RSSI_THRESHOLD_EVENT_LOW: u32 = 0;
+const NL80211_CQM_RSSI_THRESHOLD_EVENT_HIGH: u32 = 1;
+const NL80211_CQM_RSSI_BEACON_LOSS_EVENT: u32 = 2;
+
+/// RSSI threshold event type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Nl80211CqmRssiThresholdEvent {
+    /// The RSSI level went below the threshold
+    Low,
+    /// The RSSI level went above the threshold
+    High,
+    /// Obsoleted, beacon loss is reported by
+    /// [Nl80211CqmAttr::BeaconLossEvent]
+    BeaconLoss,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211CqmRssiThresholdEvent {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_CQM_RSSI_THRESHOLD_EVENT_LOW => Self::Low,
+            NL80211_CQM_RSSI_THRESHOLD_EVENT_HIGH => Self::High,
+            NL80211_CQM_RSSI_BEACON_LOSS_EVENT => Self::BeaconLoss,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211CqmRssiThresholdEvent> for u32 {
+    fn from(v: Nl80211CqmRssiThresholdEvent) -> u32 {
+        match v {
+            Nl80211CqmRssiThresholdEvent::Low => {
+                NL80211_CQM_RSSI_THRESHOLD_EVENT_LOW
+            }
+            Nl80211CqmRssiThresholdEvent::High => {
+                NL80211_CQM_RSSI_THRESHOLD_EVENT_HIGH
+            }
+            Nl80211CqmRssiThresholdEvent::BeaconLoss => {
+                NL80211_CQM_RSSI_BEACON_LOSS_EVENT
+            }
+            Nl80211CqmRssiThresholdEvent::Other(d) => d,
+        }
+    }
+}
+
+/// Connection quality monitor attributes
+///
+/// These attribute types are used with [`Nl80211Attr::Cqm`] when
+/// configuring the connection quality monitor or notified by it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211CqmAttr {
+    /// RSSI thresholds in dBm, sorted in ascending order. A single value
+    /// `0` disables the RSSI monitor. More than one threshold requires
+    /// [crate::Nl80211ExtFeature::CqmRssiList].
+    RssiThold(Vec<i32>),
+    /// RSSI hysteresis in dBm, the minimum change of RSSI before another
+    /// event is reported
+    RssiHyst(u32),
+    /// Type of the RSSI threshold event
+    RssiThresholdEvent(Nl80211CqmRssiThresholdEvent),
+    /// Number of consecutive packets not acknowledged by the peer
+    PktLossEvent(u32),
+    /// TX error rate in percent to trigger the event
+    TxeRate(u32),
+    /// Number of packets to be sent in `TxeIntvl` before the TX error rate
+    /// is checked
+    TxePkts(u32),
+    /// Interval in seconds to check the TX error rate
+    TxeIntvl(u32),
+    /// Beacons from the connected AP are lost
+    BeaconLossEvent,
+    /// Current RSSI level in dBm when the threshold event is reported
+    RssiLevel(i32),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211CqmAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RssiThold(d) => d.len() * 4,
+            Self::BeaconLossEvent => 0,
+            Self::RssiHyst(_)
+            | Self::RssiThresholdEvent(_)
+            | Self::PktLossEvent(_)
+            | Self::TxeRate(_)
+            | Self::TxePkts(_)
+            | Self::TxeIntvl(_)
+            | Self::RssiLevel(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RssiThold(_) => NL80211_ATTR_CQM_RSSI_THOLD,
+            Self::RssiHyst(_) => NL80211_ATTR_CQM_RSSI_HYST,
+            Self::RssiThresholdEvent(_) => {
+                NL80211_ATTR_CQM_RSSI_THRESHOLD_EVENT
+            }
+            Self::PktLossEvent(_) => NL80211_ATTR_CQM_PKT_LOSS_EVENT,
+            Self::TxeRate(_) => NL80211_ATTR_CQM_TXE_RATE,
+            Self::TxePkts(_) => NL80211_ATTR_CQM_TXE_PKTS,
+            Self::TxeIntvl(_) => NL80211_ATTR_CQM_TXE_INTVL,
+            Self::BeaconLossEvent => NL80211_ATTR_CQM_BEACON_LOSS_EVENT,
+            Self::RssiLevel(_) => NL80211_ATTR_CQM_RSSI_LEVEL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RssiThold(d) => {
+                for (i, thold) in d.iter().enumerate() {
+                    write_i32(&mut buffer[i * 4..], *thold);
+                }
+            }
+            Self::RssiHyst(d)
+            | Self::PktLossEvent(d)
+            | Self::TxeRate(d)
+            | Self::TxePkts(d)
+            | Self::TxeIntvl(d) => write_u32(buffer, *d),
+            Self::RssiThresholdEvent(d) => write_u32(buffer, (*d).into()),
+            Self::BeaconLossEvent => (),
+            Self::RssiLevel(d) => write_i32(buffer, *d),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211CqmAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_ATTR_CQM_RSSI_THOLD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_RSSI_THOLD value {:?}",
+                    payload
+                );
+                let mut tholds = Vec::new();
+                for chunk in payload.chunks(4) {
+                    tholds.push(parse_i32(chunk).context(err_msg.clone())?);
+                }
+                Self::RssiThold(tholds)
+            }
+            NL80211_ATTR_CQM_RSSI_HYST => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_RSSI_HYST value {:?}",
+                    payload
+                );
+                Self::RssiHyst(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_RSSI_THRESHOLD_EVENT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_RSSI_THRESHOLD_EVENT value {:?}",
+                    payload
+                );
+                Self::RssiThresholdEvent(
+                    parse_u32(payload).context(err_msg)?.into(),
+                )
+            }
+            NL80211_ATTR_CQM_PKT_LOSS_EVENT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_PKT_LOSS_EVENT value {:?}",
+                    payload
+                );
+                Self::PktLossEvent(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_TXE_RATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_TXE_RATE value {:?}",
+                    payload
+                );
+                Self::TxeRate(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_TXE_PKTS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_TXE_PKTS value {:?}",
+                    payload
+                );
+                Self::TxePkts(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_TXE_INTVL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_TXE_INTVL value {:?}",
+                    payload
+                );
+                Self::TxeIntvl(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_BEACON_LOSS_EVENT => Self::BeaconLossEvent,
+            NL80211_ATTR_CQM_RSSI_LEVEL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_RSSI_LEVEL value {:?}",
+                    payload
+                );
+                Self::RssiLevel(parse_i32(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Nl80211Attr, Nl80211CqmAttr, Nl80211CqmRssiThresholdEvent};
+
+const ETH_ALEN: usize = 6;
+
+/// Connection quality event reported by [Nl80211CqmEvent]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Nl80211CqmNotify {
+    /// The RSSI went below the threshold, with the current level (dBm) if
+    /// reported by driver
+    RssiLow { level: Option<i32> },
+    /// The RSSI went above the threshold, with the current level (dBm) if
+    /// reported by driver
+    RssiHigh { level: Option<i32> },
+    /// Number of consecutive packets not acknowledged by the peer
+    PacketLoss { count: u32 },
+    /// Beacons from the connected AP are lost
+    BeaconLoss,
+    /// The TX error rate (percent) was reached within `interval` seconds
+    /// while `packets` packets were sent
+    TxError {
+        rate: u32,
+        packets: u32,
+        interval: u32,
+    },
+}
+
+/// Notification of `NL80211_CMD_NOTIFY_CQM` sent by kernel to the "mlme"
+/// multicast group once a condition configured by
+/// [crate::Nl80211SetCqmRequest] is triggered.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211CqmEvent {
+    pub wiphy: Option<u32>,
+    pub if_index: Option<u32>,
+    /// Address of the peer, only included for packet loss and TX error
+    pub mac: Option<[u8; ETH_ALEN]>,
+    pub notify: Option<Nl80211CqmNotify>,
+    /// All the connection quality monitor attributes
+    pub cqm: Vec<Nl80211CqmAttr>,
+    /// All the attributes of the event
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl From<Vec<Nl80211Attr>> for Nl80211CqmEvent {
+    fn from(attributes: Vec<Nl80211Attr>) -> Self {
+        let mut ret = Self::default();
+        for attr in attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Mac(d) => ret.mac = Some(*d),
+                Nl80211Attr::Cqm(d) => ret.cqm = d.clone(),
+                _ => (),
+            }
+        }
+        ret.notify = notify_of(&ret.cqm);
+        ret.attributes = attributes;
+        ret
+    }
+}
+
+fn notify_of(cqm: &[Nl80211CqmAttr]) -> Option<Nl80211CqmNotify> {
+    let mut threshold_event = None;
+    let mut level = None;
+    let mut txe_rate = None;
+    let mut txe_pkts = None;
+    let mut txe_intvl = None;
+    for attr in cqm {
+        match attr {
+            Nl80211CqmAttr::RssiThresholdEvent(d) => threshold_event = Some(*d),
+            Nl80211CqmAttr::RssiLevel(d) => level = Some(*d),
+            Nl80211CqmAttr::PktLossEvent(d) => {
+                return Some(Nl80211CqmNotify::PacketLoss { count: *d })
+            }
+            Nl80211CqmAttr::BeaconLossEvent => {
+                return Some(Nl80211CqmNotify::BeaconLoss)
+            }
+            Nl80211CqmAttr::TxeRate(d) => txe_rate = Some(*d),
+            Nl80211CqmAttr::TxePkts(d) => txe_pkts = Some(*d),
+            Nl80211CqmAttr::TxeIntvl(d) => txe_intvl = Some(*d),
+            _ => (),
+        }
+    }
+    match threshold_event {
+        Some(Nl80211CqmRssiThresholdEvent::Low) => {
+            return Some(Nl80211CqmNotify::RssiLow { level })
+        }
+        Some(Nl80211CqmRssiThresholdEvent::High) => {
+            return Some(Nl80211CqmNotify::RssiHigh { level })
+        }
+        Some(Nl80211CqmRssiThresholdEvent::BeaconLoss) => {
+            return Some(Nl80211CqmNotify::BeaconLoss)
+        }
+        _ => (),
+    }
+    match (txe_rate, txe_pkts, txe_intvl) {
+        (Some(rate), Some(packets), Some(interval)) => {
+            Some(Nl80211CqmNotify::TxError {
+                rate,
+                packets,
+                interval,
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AP: [u8; ETH_ALEN] = [0x02, 0, 0, 0, 0, 0x01];
+
+    #[test]
+    fn notify_rssi_threshold() {
+        let event = Nl80211CqmEvent::from(vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::Cqm(vec![
+                Nl80211CqmAttr::RssiThresholdEvent(
+                    Nl80211CqmRssiThresholdEvent::Low,
+                ),
+                Nl80211CqmAttr::RssiLevel(-78),
+            ]),
+        ]);
+        assert_eq!(event.if_index, Some(3));
+        assert_eq!(
+            event.notify,
+            Some(Nl80211CqmNotify::RssiLow { level: Some(-78) })
+        );
+
+        let cqm = vec![Nl80211CqmAttr::RssiThresholdEvent(
+            Nl80211CqmRssiThresholdEvent::High,
+        )];
+        assert_eq!(
+            notify_of(&cqm),
+            Some(Nl80211CqmNotify::RssiHigh { level: None })
+        );
+    }
+
+    #[test]
+    fn notify_beacon_loss() {
+        let cqm = vec![Nl80211CqmAttr::BeaconLossEvent];
+        assert_eq!(notify_of(&cqm), Some(Nl80211CqmNotify::BeaconLoss));
+
+        // Obsoleted threshold event of old kernels
+        let cqm = vec![Nl80211CqmAttr::RssiThresholdEvent(
+            Nl80211CqmRssiThresholdEvent::BeaconLoss,
+        )];
+        assert_eq!(notify_of(&cqm), Some(Nl80211CqmNotify::BeaconLoss));
+    }
+
+    #[test]
+    fn notify_packet_loss() {
+        let event = Nl80211CqmEvent::from(vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::Mac(AP),
+            Nl80211Attr::Cqm(vec![Nl80211CqmAttr::PktLossEvent(50)]),
+        ]);
+        assert_eq!(event.mac, Some(AP));
+        assert_eq!(
+            event.notify,
+            Some(Nl80211CqmNotify::PacketLoss { count: 50 })
+        );
+    }
+
+    #[test]
+    fn notify_txe() {
+        let cqm = vec![
+            Nl80211CqmAttr::TxeRate(20),
+            Nl80211CqmAttr::TxePkts(100),
+            Nl80211CqmAttr::TxeIntvl(5),
+        ];
+        assert_eq!(
+            notify_of(&cqm),
+            Some(Nl80211CqmNotify::TxError {
+                rate: 20,
+                packets: 100,
+                interval: 5,
+            })
+        );
+
+        // Incomplete TX error report
+        assert_eq!(notify_of(&cqm[..2]), None);
+        assert_eq!(notify_of(&[]), None);
+    }
+}
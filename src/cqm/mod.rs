@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod event;
+mod request;
+
+pub use self::attr::{Nl80211CqmAttr, Nl80211CqmRssiThresholdEvent};
+pub use self::event::{Nl80211CqmEvent, Nl80211CqmNotify};
+pub use self::request::Nl80211SetCqmRequest;
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211CqmAttr, Nl80211Error,
+    Nl80211Handle, Nl80211Message,
+};
+
+/// Configure the connection quality monitor (`NL80211_CMD_SET_CQM`) of a
+/// connected station interface, the triggered events are reported by
+/// [crate::Nl80211CqmEvent] on the "mlme" multicast group.
+///
+/// Kernel applies either the RSSI or the TX error configuration per
+/// request and silently ignores the TX error one when both are set, hence
+/// the last of them configured replaces the other, send another request to
+/// configure both. Packet loss and beacon loss events are reported without
+/// configuration.
+pub struct Nl80211SetCqmRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    cqm: Vec<Nl80211CqmAttr>,
+}
+
+impl Nl80211SetCqmRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211SetCqmRequest {
+            handle,
+            if_index,
+            cqm: Vec::new(),
+        }
+    }
+
+    /// Report when the RSSI crosses the threshold (dBm), with the
+    /// hysteresis (dB) applied before reporting again. Replaces the TX
+    /// error configuration of [Self::with_txe()].
+    pub fn with_rssi_threshold(self, threshold: i32, hysteresis: u32) -> Self {
+        self.with_rssi_thresholds(&[threshold], hysteresis)
+    }
+
+    /// Report when the RSSI crosses any of the thresholds (dBm), requires
+    /// [crate::Nl80211ExtFeature::CqmRssiList] when more than one
+    /// threshold given
+    pub fn with_rssi_thresholds(
+        mut self,
+        thresholds: &[i32],
+        hysteresis: u32,
+    ) -> Self {
+        let mut thresholds = thresholds.to_vec();
+        thresholds.sort_unstable();
+        thresholds.dedup();
+        self.cqm.retain(|a| !is_rssi(a) && !is_txe(a));
+        self.cqm.push(Nl80211CqmAttr::RssiThold(thresholds));
+        self.cqm.push(Nl80211CqmAttr::RssiHyst(hysteresis));
+        self
+    }
+
+    /// Report when the RSSI leaves the range between `low` and `high`
+    /// (dBm), requires [crate::Nl80211ExtFeature::CqmRssiList]
+    pub fn with_rssi_range(self, low: i32, high: i32, hysteresis: u32) -> Self {
+        self.with_rssi_thresholds(&[low, high], hysteresis)
+    }
+
+    /// Disable the RSSI monitor
+    pub fn disable_rssi(self) -> Self {
+        self.with_rssi_thresholds(&[0], 0)
+    }
+
+    /// Report when the TX error rate (percent) is reached, checked every
+    /// `interval` seconds once `packets` packets are sent. Setting all of
+    /// them to `0` disables the TX error monitor. Replaces the RSSI
+    /// configuration.
+    pub fn with_txe(mut self, rate: u32, packets: u32, interval: u32) -> Self {
+        self.cqm.retain(|a| !is_rssi(a) && !is_txe(a));
+        self.cqm.push(Nl80211CqmAttr::TxeRate(rate));
+        self.cqm.push(Nl80211CqmAttr::TxePkts(packets));
+        self.cqm.push(Nl80211CqmAttr::TxeIntvl(interval));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211SetCqmRequest {
+            mut handle,
+            if_index,
+            cqm,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetCqm,
+            attributes: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::Cqm(cqm),
+            ],
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+fn is_rssi(attr: &Nl80211CqmAttr) -> bool {
+    matches!(
+        attr,
+        Nl80211CqmAttr::RssiThold(_) | Nl80211CqmAttr::RssiHyst(_)
+    )
+}
+
+fn is_txe(attr: &Nl80211CqmAttr) -> bool {
+    matches!(
+        attr,
+        Nl80211CqmAttr::TxeRate(_)
+            | Nl80211CqmAttr::TxePkts(_)
+            | Nl80211CqmAttr::TxeIntvl(_)
+    )
+}
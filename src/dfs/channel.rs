@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Band, Nl80211BandInfo, Nl80211BandType, Nl80211DfsState,
+    Nl80211FrequencyInfo,
+};
+
+/// DFS state of a channel requiring radar detection, decoded from the
+/// frequency list of a wiphy dump, see [crate::Nl80211Wiphy::dfs_channels]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct Nl80211DfsChannel {
+    pub band: Nl80211BandType,
+    /// Frequency in MHz
+    pub freq: u32,
+    /// Channel is disabled in current regulatory domain
+    pub disabled: bool,
+    pub state: Option<Nl80211DfsState>,
+    /// How long the channel has been in `state`, in milliseconds
+    pub state_time: Option<u32>,
+    /// CAC time in milliseconds
+    pub cac_time: Option<u32>,
+}
+
+impl Nl80211DfsChannel {
+    /// The channel can be used for AP, IBSS or mesh without starting
+    /// the channel availability check (CAC) first
+    pub fn is_available(&self) -> bool {
+        !self.disabled && self.state == Some(Nl80211DfsState::Available)
+    }
+
+    /// The channel availability check (CAC) can be started on the channel
+    pub fn is_usable(&self) -> bool {
+        !self.disabled && self.state == Some(Nl80211DfsState::Usable)
+    }
+
+    pub(crate) fn from_band(band: &Nl80211Band) -> Vec<Self> {
+        let mut ret = Vec::new();
+        for info in band.info.as_slice() {
+            if let Nl80211BandInfo::Freqs(freqs) = info {
+                for freq in freqs {
+                    if let Some(channel) =
+                        Self::from_freq_info(band.kind, &freq.info)
+                    {
+                        ret.push(channel);
+                    }
+                }
+            }
+        }
+        ret
+    }
+
+    fn from_freq_info(
+        band: Nl80211BandType,
+        infos: &[Nl80211FrequencyInfo],
+    ) -> Option<Self> {
+        let mut radar = false;
+        let mut ret = Self {
+            band,
+            freq: 0,
+            disabled: false,
+            state: None,
+            state_time: None,
+            cac_time: None,
+        };
+        for info in infos {
+            match info {
+                Nl80211FrequencyInfo::Freq(d) => ret.freq = *d,
+                Nl80211FrequencyInfo::Radar => radar = true,
+                Nl80211FrequencyInfo::Disabled => ret.disabled = true,
+                Nl80211FrequencyInfo::DfsState(d) => ret.state = Some(*d),
+                Nl80211FrequencyInfo::DfsTime(d) => ret.state_time = Some(*d),
+                Nl80211FrequencyInfo::DfsCacTime(d) => ret.cac_time = Some(*d),
+                _ => (),
+            }
+        }
+        if radar && ret.freq != 0 {
+            Some(ret)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Nl80211Frequency;
+
+    fn freq(index: u16, info: Vec<Nl80211FrequencyInfo>) -> Nl80211Frequency {
+        Nl80211Frequency { index, info }
+    }
+
+    fn band_5ghz() -> Nl80211Band {
+        Nl80211Band {
+            kind: Nl80211BandType::Band5GHz,
+            info: vec![Nl80211BandInfo::Freqs(vec![
+                // Not a DFS channel
+                freq(0, vec![Nl80211FrequencyInfo::Freq(5180)]),
+                freq(
+                    1,
+                    vec![
+                        Nl80211FrequencyInfo::Freq(5260),
+                        Nl80211FrequencyInfo::Radar,
+                        Nl80211FrequencyInfo::DfsState(Nl80211DfsState::Usable),
+                        Nl80211FrequencyInfo::DfsTime(1000),
+                        Nl80211FrequencyInfo::DfsCacTime(60000),
+                    ],
+                ),
+                freq(
+                    2,
+                    vec![
+                        Nl80211FrequencyInfo::Freq(5280),
+                        Nl80211FrequencyInfo::Radar,
+                        Nl80211FrequencyInfo::DfsState(
+                            Nl80211DfsState::Available,
+                        ),
+                    ],
+                ),
+                freq(
+                    3,
+                    vec![
+                        Nl80211FrequencyInfo::Freq(5300),
+                        Nl80211FrequencyInfo::Radar,
+                        Nl80211FrequencyInfo::DfsState(
+                            Nl80211DfsState::Unavailable,
+                        ),
+                    ],
+                ),
+                freq(
+                    4,
+                    vec![
+                        Nl80211FrequencyInfo::Freq(5320),
+                        Nl80211FrequencyInfo::Disabled,
+                        Nl80211FrequencyInfo::Radar,
+                        Nl80211FrequencyInfo::DfsState(
+                            Nl80211DfsState::Available,
+                        ),
+                    ],
+                ),
+            ])],
+        }
+    }
+
+    #[test]
+    fn dfs_channels_of_band() {
+        let channels = Nl80211DfsChannel::from_band(&band_5ghz());
+        let freqs: Vec<u32> = channels.iter().map(|c| c.freq).collect();
+        assert_eq!(freqs, vec![5260, 5280, 5300, 5320]);
+        assert_eq!(
+            channels[0],
+            Nl80211DfsChannel {
+                band: Nl80211BandType::Band5GHz,
+                freq: 5260,
+                disabled: false,
+                state: Some(Nl80211DfsState::Usable),
+                state_time: Some(1000),
+                cac_time: Some(60000),
+            }
+        );
+        assert!(channels[3].disabled);
+    }
+
+    #[test]
+    fn dfs_channel_available_or_usable() {
+        let channels = Nl80211DfsChannel::from_band(&band_5ghz());
+        let available: Vec<bool> =
+            channels.iter().map(|c| c.is_available()).collect();
+        let usable: Vec<bool> =
+            channels.iter().map(|c| c.is_usable()).collect();
+        assert_eq!(available, vec![false, true, false, false]);
+        assert_eq!(usable, vec![true, false, false, false]);
+    }
+}
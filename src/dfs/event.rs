@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Nl80211Attr, Nl80211ChannelWidth};
+
+const NL80211_RADAR_DETECTED: u32 = 0;
+const NL80211_RADAR_CAC_FINISHED: u32 = 1;
+const NL80211_RADAR_CAC_ABORTED: u32 = 2;
+const NL80211_RADAR_NOP_FINISHED: u32 = 3;
+const NL80211_RADAR_PRE_CAC_EXPIRED: u32 = 4;
+const NL80211_RADAR_CAC_STARTED: u32 = 5;
+
+/// Type of the radar detection event
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Nl80211RadarEventType {
+    /// A radar pattern has been detected, the channel is unavailable for
+    /// the non-occupancy period (NOP)
+    Detected,
+    /// Channel availability check (CAC) finished without radar detected,
+    /// the channel is available
+    CacFinished,
+    /// Channel availability check (CAC) was aborted, e.g. the interface
+    /// went down
+    CacAborted,
+    /// The non-occupancy period (NOP) of the channel expired, the channel
+    /// is usable again
+    NopFinished,
+    /// The result of the channel availability check done on another
+    /// channel expired, the channel is usable again
+    PreCacExpired,
+    /// Channel availability check (CAC) started, possibly offloaded to
+    /// the driver
+    CacStarted,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211RadarEventType {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_RADAR_DETECTED => Self::Detected,
+            NL80211_RADAR_CAC_FINISHED => Self::CacFinished,
+            NL80211_RADAR_CAC_ABORTED => Self::CacAborted,
+            NL80211_RADAR_NOP_FINISHED => Self::NopFinished,
+            NL80211_RADAR_PRE_CAC_EXPIRED => Self::PreCacExpired,
+            NL80211_RADAR_CAC_STARTED => Self::CacStarted,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211RadarEventType> for u32 {
+    fn from(v: Nl80211RadarEventType) -> u32 {
+        match v {
+            Nl80211RadarEventType::Detected => NL80211_RADAR_DETECTED,
+            Nl80211RadarEventType::CacFinished => NL80211_RADAR_CAC_FINISHED,
+            Nl80211RadarEventType::CacAborted => NL80211_RADAR_CAC_ABORTED,
+            Nl80211RadarEventType::NopFinished => NL80211_RADAR_NOP_FINISHED,
+            Nl80211RadarEventType::PreCacExpired => {
+                NL80211_RADAR_PRE_CAC_EXPIRED
+            }
+            Nl80211RadarEventType::CacStarted => NL80211_RADAR_CAC_STARTED,
+            Nl80211RadarEventType::Other(d) => d,
+        }
+    }
+}
+
+/// Notification of `NL80211_CMD_RADAR_DETECT` or `NL80211_CMD_NOTIFY_RADAR`
+/// (CAC started, finished or aborted, radar detected, NOP finished), sent
+/// by kernel to the "mlme" multicast group.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211RadarEvent {
+    pub wiphy: Option<u32>,
+    /// Not included for the events not bound to an interface, e.g. NOP
+    /// finished
+    pub if_index: Option<u32>,
+    pub wdev: Option<u64>,
+    pub event: Option<Nl80211RadarEventType>,
+    pub freq: Option<u32>,
+    pub channel_width: Option<Nl80211ChannelWidth>,
+    pub center_freq1: Option<u32>,
+    pub center_freq2: Option<u32>,
+    /// The event is from the background (offchannel) radar chain
+    pub background: bool,
+    /// All the attributes of the event
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl From<Vec<Nl80211Attr>> for Nl80211RadarEvent {
+    fn from(attributes: Vec<Nl80211Attr>) -> Self {
+        let mut ret = Self::default();
+        for attr in attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(*d),
+                Nl80211Attr::RadarEvent(d) => ret.event = Some(*d),
+                Nl80211Attr::WiphyFreq(d) => ret.freq = Some(*d),
+                Nl80211Attr::ChannelWidth(d) => ret.channel_width = Some(*d),
+                Nl80211Attr::CenterFreq1(d) => ret.center_freq1 = Some(*d),
+                Nl80211Attr::CenterFreq2(d) => ret.center_freq2 = Some(*d),
+                Nl80211Attr::RadarBackground => ret.background = true,
+                _ => (),
+            }
+        }
+        ret.attributes = attributes;
+        ret
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod channel;
+mod event;
+mod request;
+
+pub use self::channel::Nl80211DfsChannel;
+pub use self::event::{Nl80211RadarEvent, Nl80211RadarEventType};
+pub use self::request::Nl80211RadarDetectRequest;
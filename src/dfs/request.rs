@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    chandef::impl_chandef_helpers, nl80211_execute, Nl80211Attr,
+    Nl80211AttrsBuilder, Nl80211Chandef, Nl80211Command, Nl80211Error,
+    Nl80211Handle, Nl80211Message,
+};
+
+/// Start the channel availability check (CAC) on a DFS channel
+/// (`NL80211_CMD_RADAR_DETECT`) before operating AP, IBSS or mesh on it.
+///
+/// The channel is set by the same helpers as
+/// [crate::Nl80211InterfaceSetChannelRequest], the CAC time is decided by
+/// the regulatory domain. The progress is reported by
+/// [crate::Nl80211RadarEvent] on the "mlme" multicast group.
+pub struct Nl80211RadarDetectRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211RadarDetectRequest>,
+    chandef: Option<Nl80211Chandef>,
+}
+
+impl Nl80211RadarDetectRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211RadarDetectRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new().if_index(if_index),
+            chandef: None,
+        }
+    }
+
+    /// Check the channel definition, see [Nl80211Chandef]
+    pub fn as_chandef(mut self, chandef: &Nl80211Chandef) -> Self {
+        self.chandef = Some(*chandef);
+        self
+    }
+
+    /// Run the CAC on the background (offchannel) radar chain while the
+    /// interface keeps operating on its current channel, requires
+    /// [crate::Nl80211ExtFeature::RadarBackground]
+    pub fn with_background(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::RadarBackground);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211RadarDetectRequest {
+            mut handle,
+            mut attrs,
+            chandef,
+        } = self;
+
+        for attr in chandef.iter().flat_map(|c| c.to_attrs()) {
+            attrs = attrs.replace(attr);
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::RadarDetect,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+impl_chandef_helpers!(Nl80211RadarDetectRequest, "Check");
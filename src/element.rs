@@ -52,6 +52,12 @@ impl From<&Vec<Nl80211Element>> for Nl80211Elements {
     }
 }
 
+impl From<&[Nl80211Element]> for Nl80211Elements {
+    fn from(d: &[Nl80211Element]) -> Self {
+        Self(d.to_vec())
+    }
+}
+
 impl From<Nl80211Elements> for Vec<Nl80211Element> {
     fn from(v: Nl80211Elements) -> Vec<Nl80211Element> {
         v.0
@@ -22,6 +22,12 @@ pub enum Nl80211Error {
     #[error("Failed to decode netlink package: {0}")]
     DecodeFailed(DecodeError),
 
+    #[error("The scan was aborted by kernel")]
+    ScanAborted,
+
+    #[error("Timed out waiting for {0}")]
+    Timeout(String),
+
     #[error("A bug in this crate")]
     Bug(String),
 }
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStreamExt;
+use netlink_packet_core::{NetlinkMessage, NetlinkPayload, NLM_F_REQUEST};
+use netlink_packet_generic::{
+    ctrl::{
+        nlas::{GenlCtrlAttrs, McastGrpAttrs},
+        GenlCtrl, GenlCtrlCmd,
+    },
+    GenlMessage,
+};
+
+use crate::{Nl80211Error, Nl80211Handle};
+
+const NL80211_GENL_NAME: &str = "nl80211";
+
+const NL80211_MULTICAST_GROUP_CONFIG: &str = "config";
+const NL80211_MULTICAST_GROUP_SCAN: &str = "scan";
+const NL80211_MULTICAST_GROUP_REG: &str = "regulatory";
+const NL80211_MULTICAST_GROUP_MLME: &str = "mlme";
+const NL80211_MULTICAST_GROUP_VENDOR: &str = "vendor";
+const NL80211_MULTICAST_GROUP_NAN: &str = "nan";
+const NL80211_MULTICAST_GROUP_TESTMODE: &str = "testmode";
+
+/// Multicast groups registered by the nl80211 generic netlink family
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Nl80211MulticastGroup {
+    /// Wiphy and interface creation, deletion and renaming
+    Config,
+    /// Scan started, finished and aborted
+    Scan,
+    /// Regulatory domain changes and beacon hints
+    Regulatory,
+    /// MLME events: authentication, association, connection, CQM, channel
+    /// switch, radar and station changes
+    Mlme,
+    /// Vendor specific events
+    Vendor,
+    /// Neighbor Awareness Networking events
+    Nan,
+    /// Test mode events
+    Testmode,
+    /// Group name not known by this crate
+    Other(String),
+}
+
+impl Nl80211MulticastGroup {
+    /// All the multicast groups defined by kernel
+    pub const ALL: [Self; 7] = [
+        Self::Config,
+        Self::Scan,
+        Self::Regulatory,
+        Self::Mlme,
+        Self::Vendor,
+        Self::Nan,
+        Self::Testmode,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Config => NL80211_MULTICAST_GROUP_CONFIG,
+            Self::Scan => NL80211_MULTICAST_GROUP_SCAN,
+            Self::Regulatory => NL80211_MULTICAST_GROUP_REG,
+            Self::Mlme => NL80211_MULTICAST_GROUP_MLME,
+            Self::Vendor => NL80211_MULTICAST_GROUP_VENDOR,
+            Self::Nan => NL80211_MULTICAST_GROUP_NAN,
+            Self::Testmode => NL80211_MULTICAST_GROUP_TESTMODE,
+            Self::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl From<&str> for Nl80211MulticastGroup {
+    fn from(d: &str) -> Self {
+        match d {
+            NL80211_MULTICAST_GROUP_CONFIG => Self::Config,
+            NL80211_MULTICAST_GROUP_SCAN => Self::Scan,
+            NL80211_MULTICAST_GROUP_REG => Self::Regulatory,
+            NL80211_MULTICAST_GROUP_MLME => Self::Mlme,
+            NL80211_MULTICAST_GROUP_VENDOR => Self::Vendor,
+            NL80211_MULTICAST_GROUP_NAN => Self::Nan,
+            NL80211_MULTICAST_GROUP_TESTMODE => Self::Testmode,
+            _ => Self::Other(d.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Nl80211MulticastGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Query the generic netlink controller for the multicast groups of nl80211
+/// family and return the `(group, group_id)` pairs.
+pub(crate) async fn nl80211_resolve_mcast_groups(
+    handle: &mut Nl80211Handle,
+) -> Result<Vec<(Nl80211MulticastGroup, u32)>, Nl80211Error> {
+    let mut nl_msg = NetlinkMessage::from(GenlMessage::from_payload(GenlCtrl {
+        cmd: GenlCtrlCmd::GetFamily,
+        nlas: vec![GenlCtrlAttrs::FamilyName(NL80211_GENL_NAME.to_string())],
+    }));
+    nl_msg.header.flags = NLM_F_REQUEST;
+
+    let mut response = handle.handle.request(nl_msg).await.map_err(|e| {
+        Nl80211Error::RequestFailed(format!(
+            "Failed to query nl80211 family from genetlink controller: {e}"
+        ))
+    })?;
+
+    let mut groups = Vec::new();
+    while let Some(msg) = response.try_next().await.map_err(|e| {
+        Nl80211Error::Bug(format!("BUG: decode error {:?}", e))
+    })? {
+        match msg.payload {
+            NetlinkPayload::InnerMessage(genl_msg) => {
+                for nla in genl_msg.payload.nlas {
+                    if let GenlCtrlAttrs::McastGroups(grps) = nla {
+                        for grp in grps {
+                            let mut name = None;
+                            let mut id = None;
+                            for grp_nla in grp {
+                                match grp_nla {
+                                    McastGrpAttrs::Name(s) => name = Some(s),
+                                    McastGrpAttrs::Id(i) => id = Some(i),
+                                }
+                            }
+                            if let (Some(name), Some(id)) = (name, id) {
+                                groups.push((
+                                    Nl80211MulticastGroup::from(name.as_str()),
+                                    id,
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+            NetlinkPayload::Error(err) => {
+                return Err(Nl80211Error::NetlinkError(err))
+            }
+            _ => (),
+        }
+    }
+    Ok(groups)
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod group;
+mod notify;
+mod stream;
+
+pub use self::group::Nl80211MulticastGroup;
+pub use self::notify::Nl80211Event;
+pub use self::stream::Nl80211EventStream;
+
+pub(crate) use self::group::nl80211_resolve_mcast_groups;
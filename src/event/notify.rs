@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211ChannelSwitchEvent, Nl80211Command,
+    Nl80211ConnectEvent, Nl80211CqmEvent, Nl80211DisconnectEvent,
+    Nl80211FrameTxStatus, Nl80211Message, Nl80211MlmeEvent,
+    Nl80211RadarEvent, Nl80211RemainOnChannelEvent, Nl80211RxFrame,
+};
+
+/// Notification sent by kernel to the nl80211 multicast groups (or unicast to
+/// the socket which registered for it).
+/// The variants hold the netlink attributes of the notification, or the
+/// typed decoding of them when available.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Nl80211Event {
+    // "config" group
+    NewWiphy(Vec<Nl80211Attr>),
+    DelWiphy(Vec<Nl80211Attr>),
+    NewInterface(Vec<Nl80211Attr>),
+    DelInterface(Vec<Nl80211Attr>),
+    // "scan" group
+    TriggerScan(Vec<Nl80211Attr>),
+    NewScanResults(Vec<Nl80211Attr>),
+    ScanAborted(Vec<Nl80211Attr>),
+    StartSchedScan(Vec<Nl80211Attr>),
+    SchedScanResults(Vec<Nl80211Attr>),
+    SchedScanStopped(Vec<Nl80211Attr>),
+    // "regulatory" group
+    RegChange(Vec<Nl80211Attr>),
+    RegBeaconHint(Vec<Nl80211Attr>),
+    WiphyRegChange(Vec<Nl80211Attr>),
+    // "mlme" group
+    Authenticate(Nl80211MlmeEvent),
+    Associate(Nl80211MlmeEvent),
+    Deauthenticate(Nl80211MlmeEvent),
+    Disassociate(Nl80211MlmeEvent),
+    UnprotDeauthenticate(Nl80211MlmeEvent),
+    UnprotDisassociate(Nl80211MlmeEvent),
+    MichaelMicFailure(Vec<Nl80211Attr>),
+    Connect(Nl80211ConnectEvent),
+    Roam(Nl80211ConnectEvent),
+    Disconnect(Nl80211DisconnectEvent),
+    PortAuthorized(Vec<Nl80211Attr>),
+    JoinIbss(Vec<Nl80211Attr>),
+    NotifyCqm(Nl80211CqmEvent),
+    ChSwitchNotify(Nl80211ChannelSwitchEvent),
+    ChSwitchStartedNotify(Nl80211ChannelSwitchEvent),
+    RadarDetect(Nl80211RadarEvent),
+    NotifyRadar(Nl80211RadarEvent),
+    RemainOnChannel(Nl80211RemainOnChannelEvent),
+    CancelRemainOnChannel(Nl80211RemainOnChannelEvent),
+    Frame(Nl80211RxFrame),
+    FrameTxStatus(Nl80211FrameTxStatus),
+    NewStation(Vec<Nl80211Attr>),
+    DelStation(Vec<Nl80211Attr>),
+    ConnFailed(Vec<Nl80211Attr>),
+    // "vendor" group
+    Vendor(Vec<Nl80211Attr>),
+    // "testmode" group
+    Testmode(Vec<Nl80211Attr>),
+    /// Notifications not decoded by this crate yet
+    Other(Nl80211Message),
+}
+
+impl From<Nl80211Message> for Nl80211Event {
+    fn from(msg: Nl80211Message) -> Self {
+        let attrs = msg.attributes;
+        match msg.cmd {
+            Nl80211Command::NewWiphy => Self::NewWiphy(attrs),
+            Nl80211Command::DelWiphy => Self::DelWiphy(attrs),
+            Nl80211Command::NewInterface => Self::NewInterface(attrs),
+            Nl80211Command::DelInterface => Self::DelInterface(attrs),
+            Nl80211Command::TriggerScan => Self::TriggerScan(attrs),
+            Nl80211Command::NewScanResults => Self::NewScanResults(attrs),
+            Nl80211Command::ScanAborted => Self::ScanAborted(attrs),
+            Nl80211Command::StartSchedScan => Self::StartSchedScan(attrs),
+            Nl80211Command::SchedScanResults => Self::SchedScanResults(attrs),
+            Nl80211Command::SchedScanStopped => Self::SchedScanStopped(attrs),
+            Nl80211Command::RegChange => Self::RegChange(attrs),
+            Nl80211Command::RegBeaconHint => Self::RegBeaconHint(attrs),
+            Nl80211Command::WiphyRegChange => Self::WiphyRegChange(attrs),
+            Nl80211Command::Authenticate => Self::Authenticate(attrs.into()),
+            Nl80211Command::Associate => Self::Associate(attrs.into()),
+            Nl80211Command::Deauthenticate => {
+                Self::Deauthenticate(attrs.into())
+            }
+            Nl80211Command::Disassociate => Self::Disassociate(attrs.into()),
+            Nl80211Command::UnprotDeauthenticate => {
+                Self::UnprotDeauthenticate(attrs.into())
+            }
+            Nl80211Command::UnprotDisassociate => {
+                Self::UnprotDisassociate(attrs.into())
+            }
+            Nl80211Command::MichaelMicFailure => Self::MichaelMicFailure(attrs),
+            Nl80211Command::Connect => Self::Connect(attrs.into()),
+            Nl80211Command::Roam => Self::Roam(attrs.into()),
+            Nl80211Command::Disconnect => Self::Disconnect(attrs.into()),
+            Nl80211Command::PortAuthorized => Self::PortAuthorized(attrs),
+            Nl80211Command::JoinIbss => Self::JoinIbss(attrs),
+            Nl80211Command::NotifyCqm => Self::NotifyCqm(attrs.into()),
+            Nl80211Command::ChSwitchNotify => {
+                Self::ChSwitchNotify(attrs.into())
+            }
+            Nl80211Command::ChSwitchStartedNotify => {
+                Self::ChSwitchStartedNotify(attrs.into())
+            }
+            Nl80211Command::RadarDetect => Self::RadarDetect(attrs.into()),
+            Nl80211Command::NotifyRadar => Self::NotifyRadar(attrs.into()),
+            Nl80211Command::RemainOnChannel => {
+                Self::RemainOnChannel(attrs.into())
+            }
+            Nl80211Command::CancelRemainOnChannel => {
+                Self::CancelRemainOnChannel(attrs.into())
+            }
+            Nl80211Command::Frame => Self::Frame(attrs.into()),
+            Nl80211Command::FrameTxStatus => {
+                Self::FrameTxStatus(attrs.into())
+            }
+            Nl80211Command::NewStation => Self::NewStation(attrs),
+            Nl80211Command::DelStation => Self::DelStation(attrs),
+            Nl80211Command::ConnFailed => Self::ConnFailed(attrs),
+            Nl80211Command::Vendor => Self::Vendor(attrs),
+            Nl80211Command::Testmode => Self::Testmode(attrs),
+            cmd => Self::Other(Nl80211Message {
+                cmd,
+                attributes: attrs,
+            }),
+        }
+    }
+}
+
+impl Nl80211Event {
+    /// The netlink attributes carried by this notification
+    pub fn attributes(&self) -> &[Nl80211Attr] {
+        match self {
+            Self::NewWiphy(a)
+            | Self::DelWiphy(a)
+            | Self::NewInterface(a)
+            | Self::DelInterface(a)
+            | Self::TriggerScan(a)
+            | Self::NewScanResults(a)
+            | Self::ScanAborted(a)
+            | Self::StartSchedScan(a)
+            | Self::SchedScanResults(a)
+            | Self::SchedScanStopped(a)
+            | Self::RegChange(a)
+            | Self::RegBeaconHint(a)
+            | Self::WiphyRegChange(a)
+            | Self::MichaelMicFailure(a)
+            | Self::PortAuthorized(a)
+            | Self::JoinIbss(a)
+            | Self::NewStation(a)
+            | Self::DelStation(a)
+            | Self::ConnFailed(a)
+            | Self::Vendor(a)
+            | Self::Testmode(a) => a.as_slice(),
+            Self::Connect(e) | Self::Roam(e) => e.attributes.as_slice(),
+            Self::Disconnect(e) => e.attributes.as_slice(),
+            Self::Authenticate(e)
+            | Self::Associate(e)
+            | Self::Deauthenticate(e)
+            | Self::Disassociate(e)
+            | Self::UnprotDeauthenticate(e)
+            | Self::UnprotDisassociate(e) => e.attributes.as_slice(),
+            Self::Frame(e) => e.attributes.as_slice(),
+            Self::FrameTxStatus(e) => e.attributes.as_slice(),
+            Self::ChSwitchNotify(e) | Self::ChSwitchStartedNotify(e) => {
+                e.attributes.as_slice()
+            }
+            Self::RemainOnChannel(e) | Self::CancelRemainOnChannel(e) => {
+                e.attributes.as_slice()
+            }
+            Self::NotifyCqm(e) => e.attributes.as_slice(),
+            Self::RadarDetect(e) | Self::NotifyRadar(e) => {
+                e.attributes.as_slice()
+            }
+            Self::Other(msg) => msg.attributes.as_slice(),
+        }
+    }
+
+    /// Wiphy index this notification is related to
+    pub fn wiphy(&self) -> Option<u32> {
+        self.attributes().iter().find_map(|attr| {
+            if let Nl80211Attr::Wiphy(d) = attr {
+                Some(*d)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Interface index this notification is related to
+    pub fn if_index(&self) -> Option<u32> {
+        self.attributes().iter().find_map(|attr| {
+            if let Nl80211Attr::IfIndex(d) = attr {
+                Some(*d)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Wireless device identifier this notification is related to
+    pub fn wdev(&self) -> Option<u64> {
+        self.attributes().iter().find_map(|attr| {
+            if let Nl80211Attr::Wdev(d) = attr {
+                Some(*d)
+            } else {
+                None
+            }
+        })
+    }
+}
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{channel::mpsc::UnboundedReceiver, Stream};
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::{NetlinkMessage, NetlinkPayload};
+use netlink_proto::Connection;
+use netlink_sys::{AsyncSocket, SocketAddr};
+
+use crate::{
+    event::nl80211_resolve_mcast_groups, Nl80211Error, Nl80211Event,
+    Nl80211Handle, Nl80211Message, Nl80211MulticastGroup,
+};
+
+/// Stream of [Nl80211Event] received from the nl80211 multicast groups
+/// joined by [Nl80211Handle::events()].
+///
+/// The stream owns a dedicated netlink socket and drives its connection
+/// internally, hence no need to spawn anything. Dropping the stream closes
+/// the socket and leaves the multicast groups.
+///
+/// As the connection only runs while the stream is polled, requests sent
+/// through [Nl80211EventStream::handle()] must be awaited with
+/// [Nl80211EventStream::drive()].
+pub struct Nl80211EventStream<S> {
+    connection: Option<Connection<RawGenlMessage, S>>,
+    handle: Nl80211Handle,
+    messages: UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+}
+
+impl<S> std::fmt::Debug for Nl80211EventStream<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Nl80211EventStream")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<S> Nl80211EventStream<S>
+where
+    S: AsyncSocket,
+{
+    pub(crate) async fn new(
+        handle: &mut Nl80211Handle,
+        groups: &[Nl80211MulticastGroup],
+    ) -> Result<Self, Nl80211Error> {
+        let group_ids = nl80211_resolve_mcast_groups(handle).await?;
+
+        let (mut connection, event_handle, messages) =
+            crate::new_connection_with_socket::<S>().map_err(|e| {
+                Nl80211Error::RequestFailed(format!(
+                    "Failed to create netlink socket for nl80211 events: {e}"
+                ))
+            })?;
+
+        for group in groups {
+            let group_id = match group_ids.iter().find(|(g, _)| g == group) {
+                Some((_, id)) => *id,
+                None => {
+                    return Err(Nl80211Error::RequestFailed(format!(
+                        "Kernel does not provide nl80211 multicast group \
+                        {group}"
+                    )))
+                }
+            };
+            connection
+                .socket_mut()
+                .socket_mut()
+                .add_membership(group_id)
+                .map_err(|e| {
+                    Nl80211Error::RequestFailed(format!(
+                        "Failed to join nl80211 multicast group {group}: {e}"
+                    ))
+                })?;
+        }
+
+        Ok(Self {
+            connection: Some(connection),
+            handle: event_handle,
+            messages,
+        })
+    }
+
+    /// Handle sending requests through the socket of this stream.
+    /// Notifications kernel unicasts to the requesting socket (e.g. frames
+    /// matching a frame registration) are delivered to this stream.
+    ///
+    /// The connection of this socket is only driven while the stream is
+    /// polled, a request sent through this handle never completes unless
+    /// awaited by [Nl80211EventStream::drive()] or concurrently with
+    /// polling the stream.
+    pub fn handle(&self) -> Nl80211Handle {
+        self.handle.clone()
+    }
+
+    /// Await the future while driving the connection of this stream, e.g.
+    /// for the requests sent through [Nl80211EventStream::handle()].
+    /// Notifications received meanwhile are kept for the stream.
+    pub async fn drive<F>(&mut self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        let mut future = Box::pin(future);
+        futures::future::poll_fn(|cx| {
+            if let Some(connection) = self.connection.as_mut() {
+                if Pin::new(connection).poll(cx).is_ready() {
+                    self.connection = None;
+                }
+            }
+            future.as_mut().poll(cx)
+        })
+        .await
+    }
+}
+
+impl<S> Stream for Nl80211EventStream<S>
+where
+    S: AsyncSocket,
+{
+    type Item = Result<Nl80211Event, Nl80211Error>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        // Keep the socket connection running, it will forward every
+        // multicast notification to `messages`.
+        if let Some(connection) = this.connection.as_mut() {
+            if Pin::new(connection).poll(cx).is_ready() {
+                this.connection = None;
+            }
+        }
+
+        loop {
+            let (msg, _) = match Pin::new(&mut this.messages).poll_next(cx) {
+                Poll::Ready(Some(msg)) => msg,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            match msg.payload {
+                NetlinkPayload::InnerMessage(raw) => {
+                    return Poll::Ready(Some(
+                        raw.parse_into_genlmsg::<Nl80211Message>()
+                            .map(|genl_msg| {
+                                Nl80211Event::from(genl_msg.payload)
+                            })
+                            .map_err(Nl80211Error::DecodeFailed),
+                    ));
+                }
+                NetlinkPayload::Error(err) => {
+                    return Poll::Ready(Some(Err(
+                        Nl80211Error::NetlinkError(err),
+                    )));
+                }
+                _ => continue,
+            }
+        }
+    }
+}
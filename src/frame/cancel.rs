@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+/// Stop waiting on the off-channel frequency for response of a frame
+/// transmitted with [crate::Nl80211FrameTxRequest::with_wait()].
+pub struct Nl80211FrameWaitCancelRequest {
+    handle: Nl80211Handle,
+    attrs: Vec<Nl80211Attr>,
+}
+
+impl Nl80211FrameWaitCancelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        cookie: u64,
+    ) -> Self {
+        Nl80211FrameWaitCancelRequest {
+            handle,
+            attrs: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::Cookie(cookie),
+            ],
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211FrameWaitCancelRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::FrameWaitCancel,
+            attributes: attrs,
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{DecodeError, Emitable, Parseable};
+
+use crate::{
+    bytes::{parse_u16_le, write_u16_le, write_u32_le},
+    Nl80211FrameType, Nl80211FrameTypeMgmt,
+};
+
+const ETH_ALEN: usize = 6;
+
+const IEEE80211_FCTL_FTYPE_STYPE: u16 = 0x00fc;
+const IEEE80211_FCTL_VERS: u16 = 0x0003;
+const IEEE80211_STYPE_QOS_DATA: u16 = 0x0080;
+
+const IEEE80211_HDR_3ADDR_LEN: usize = 24;
+const IEEE80211_QOS_CTL_LEN: usize = 2;
+const IEEE80211_HT_CTL_LEN: usize = 4;
+
+bitflags::bitflags! {
+    /// Flags of the frame control field (the second octet)
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[non_exhaustive]
+    pub struct Nl80211FrameControlFlags: u8 {
+        const ToDs = 1 << 0;
+        const FromDs = 1 << 1;
+        const MoreFragments = 1 << 2;
+        const Retry = 1 << 3;
+        const PowerManagement = 1 << 4;
+        const MoreData = 1 << 5;
+        /// Frame body is encrypted
+        const Protected = 1 << 6;
+        /// HT Control field is present for QoS data and management frames
+        const Order = 1 << 7;
+        const _ = !0;
+    }
+}
+
+/// IEEE 802.11-2020 `9.3.1 MAC header` of management and data frames.
+/// Control frames use shorter headers and are not supported.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct Nl80211MacHeader {
+    pub frame_type: Nl80211FrameType,
+    pub flags: Nl80211FrameControlFlags,
+    /// Duration/ID field in microseconds
+    pub duration: u16,
+    /// Receiver address, the destination address of management frame
+    pub addr1: [u8; ETH_ALEN],
+    /// Transmitter address, the source address of management frame
+    pub addr2: [u8; ETH_ALEN],
+    /// BSSID of management frame
+    pub addr3: [u8; ETH_ALEN],
+    /// Sequence number (upper 12 bits) and fragment number (lower 4 bits)
+    pub seq_ctrl: u16,
+    /// Only present when both `ToDs` and `FromDs` are set on data frame
+    pub addr4: Option<[u8; ETH_ALEN]>,
+    /// Only present for QoS data frames
+    pub qos_control: Option<u16>,
+    /// Only present when `Order` is set on QoS data or management frame
+    pub ht_control: Option<u32>,
+}
+
+impl Nl80211MacHeader {
+    /// Header of management frame sent from `sa` to `da` in `bssid`
+    pub fn new_mgmt(
+        subtype: Nl80211FrameTypeMgmt,
+        da: [u8; ETH_ALEN],
+        sa: [u8; ETH_ALEN],
+        bssid: [u8; ETH_ALEN],
+    ) -> Self {
+        Self {
+            frame_type: Nl80211FrameType::Management(subtype),
+            flags: Nl80211FrameControlFlags::empty(),
+            duration: 0,
+            addr1: da,
+            addr2: sa,
+            addr3: bssid,
+            seq_ctrl: 0,
+            addr4: None,
+            qos_control: None,
+            ht_control: None,
+        }
+    }
+
+    pub fn sequence_number(&self) -> u16 {
+        self.seq_ctrl >> 4
+    }
+
+    pub fn fragment_number(&self) -> u8 {
+        (self.seq_ctrl & 0xf) as u8
+    }
+
+    fn has_addr4(
+        frame_type: Nl80211FrameType,
+        flags: Nl80211FrameControlFlags,
+    ) -> bool {
+        matches!(frame_type, Nl80211FrameType::Data(_))
+            && flags.contains(
+                Nl80211FrameControlFlags::ToDs
+                    | Nl80211FrameControlFlags::FromDs,
+            )
+    }
+
+    fn has_qos_control(frame_type: Nl80211FrameType) -> bool {
+        match frame_type {
+            Nl80211FrameType::Data(s) => {
+                u16::from(s) & IEEE80211_STYPE_QOS_DATA > 0
+            }
+            _ => false,
+        }
+    }
+
+    fn has_ht_control(
+        frame_type: Nl80211FrameType,
+        flags: Nl80211FrameControlFlags,
+    ) -> bool {
+        flags.contains(Nl80211FrameControlFlags::Order)
+            && (matches!(frame_type, Nl80211FrameType::Management(_))
+                || Self::has_qos_control(frame_type))
+    }
+}
+
+fn parse_mac(payload: &[u8]) -> [u8; ETH_ALEN] {
+    let mut ret = [0u8; ETH_ALEN];
+    ret.copy_from_slice(&payload[..ETH_ALEN]);
+    ret
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211MacHeader {
+    fn parse(buf: &T) -> Result<Self, DecodeError> {
+        let buf = buf.as_ref();
+        if buf.len() < IEEE80211_HDR_3ADDR_LEN {
+            return Err(format!(
+                "Invalid IEEE 802.11 MAC header, expected length at least \
+                {} got {:?}",
+                IEEE80211_HDR_3ADDR_LEN, buf
+            )
+            .into());
+        }
+        let fc = parse_u16_le(buf)?;
+        if fc & IEEE80211_FCTL_VERS != 0 {
+            return Err(format!(
+                "Unsupported IEEE 802.11 protocol version {}",
+                fc & IEEE80211_FCTL_VERS
+            )
+            .into());
+        }
+        let frame_type =
+            Nl80211FrameType::from(fc & IEEE80211_FCTL_FTYPE_STYPE);
+        if let Nl80211FrameType::Control(_) = frame_type {
+            return Err(format!(
+                "Unsupported IEEE 802.11 control frame header {:?}",
+                buf
+            )
+            .into());
+        }
+        let flags = Nl80211FrameControlFlags::from_bits_retain(buf[1]);
+
+        let mut ret = Self {
+            frame_type,
+            flags,
+            duration: parse_u16_le(&buf[2..])?,
+            addr1: parse_mac(&buf[4..]),
+            addr2: parse_mac(&buf[10..]),
+            addr3: parse_mac(&buf[16..]),
+            seq_ctrl: parse_u16_le(&buf[22..])?,
+            addr4: None,
+            qos_control: None,
+            ht_control: None,
+        };
+        let mut offset = IEEE80211_HDR_3ADDR_LEN;
+        if Self::has_addr4(frame_type, flags) {
+            if buf.len() < offset + ETH_ALEN {
+                return Err(format!(
+                    "Invalid IEEE 802.11 MAC header, missing address 4 {:?}",
+                    buf
+                )
+                .into());
+            }
+            ret.addr4 = Some(parse_mac(&buf[offset..]));
+            offset += ETH_ALEN;
+        }
+        if Self::has_qos_control(frame_type) {
+            ret.qos_control = Some(parse_u16_le(&buf[offset..])?);
+            offset += IEEE80211_QOS_CTL_LEN;
+        }
+        if Self::has_ht_control(frame_type, flags) {
+            if buf.len() < offset + IEEE80211_HT_CTL_LEN {
+                return Err(format!(
+                    "Invalid IEEE 802.11 MAC header, missing HT control {:?}",
+                    buf
+                )
+                .into());
+            }
+            ret.ht_control = Some(u32::from_le_bytes([
+                buf[offset],
+                buf[offset + 1],
+                buf[offset + 2],
+                buf[offset + 3],
+            ]));
+        }
+        Ok(ret)
+    }
+}
+
+impl Emitable for Nl80211MacHeader {
+    fn buffer_len(&self) -> usize {
+        IEEE80211_HDR_3ADDR_LEN
+            + self.addr4.map(|_| ETH_ALEN).unwrap_or_default()
+            + self
+                .qos_control
+                .map(|_| IEEE80211_QOS_CTL_LEN)
+                .unwrap_or_default()
+            + self
+                .ht_control
+                .map(|_| IEEE80211_HT_CTL_LEN)
+                .unwrap_or_default()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut flags = self.flags;
+        flags.set(Nl80211FrameControlFlags::Order, self.ht_control.is_some());
+        buffer[0] =
+            (u16::from(self.frame_type) & IEEE80211_FCTL_FTYPE_STYPE) as u8;
+        buffer[1] = flags.bits();
+        write_u16_le(&mut buffer[2..], self.duration);
+        buffer[4..10].copy_from_slice(&self.addr1);
+        buffer[10..16].copy_from_slice(&self.addr2);
+        buffer[16..22].copy_from_slice(&self.addr3);
+        write_u16_le(&mut buffer[22..], self.seq_ctrl);
+        let mut offset = IEEE80211_HDR_3ADDR_LEN;
+        if let Some(addr4) = self.addr4 {
+            buffer[offset..offset + ETH_ALEN].copy_from_slice(&addr4);
+            offset += ETH_ALEN;
+        }
+        if let Some(qos_control) = self.qos_control {
+            write_u16_le(&mut buffer[offset..], qos_control);
+            offset += IEEE80211_QOS_CTL_LEN;
+        }
+        if let Some(ht_control) = self.ht_control {
+            write_u32_le(&mut buffer[offset..], ht_control);
+        }
+    }
+}
+
+impl Default for Nl80211MacHeader {
+    fn default() -> Self {
+        Self::new_mgmt(
+            Nl80211FrameTypeMgmt::Action,
+            [0u8; ETH_ALEN],
+            [0u8; ETH_ALEN],
+            [0u8; ETH_ALEN],
+        )
+    }
+}
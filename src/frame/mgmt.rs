@@ -0,0 +1,843 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    DecodeError, Emitable, Parseable, ParseableParametrized,
+};
+
+use crate::{
+    bytes::{parse_u16_le, write_u16_le},
+    Nl80211BssCapabilities, Nl80211Element, Nl80211Elements,
+    Nl80211FrameControlFlags, Nl80211FrameType, Nl80211FrameTypeMgmt,
+    Nl80211MacHeader, Nl80211ReasonCode, Nl80211StatusCode,
+};
+
+const ETH_ALEN: usize = 6;
+
+// Authentication algorithm numbers defined in IEEE 802.11-2020 9.4.1.1
+const WLAN_AUTH_OPEN: u16 = 0;
+const WLAN_AUTH_SHARED_KEY: u16 = 1;
+const WLAN_AUTH_FT: u16 = 2;
+const WLAN_AUTH_SAE: u16 = 3;
+const WLAN_AUTH_FILS_SK: u16 = 4;
+const WLAN_AUTH_FILS_SK_PFS: u16 = 5;
+const WLAN_AUTH_FILS_PK: u16 = 6;
+const WLAN_AUTH_PASN: u16 = 7;
+
+const WLAN_SAE_COMMIT: u16 = 1;
+const WLAN_SAE_CONFIRM: u16 = 2;
+
+const TIMESTAMP_LEN: usize = 8;
+
+/// Authentication algorithm number of authentication frame
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Nl80211AuthAlgorithm {
+    Open,
+    SharedKey,
+    FastBssTransition,
+    Sae,
+    FilsSk,
+    FilsSkPfs,
+    FilsPk,
+    Pasn,
+    Other(u16),
+}
+
+impl From<u16> for Nl80211AuthAlgorithm {
+    fn from(d: u16) -> Self {
+        match d {
+            WLAN_AUTH_OPEN => Self::Open,
+            WLAN_AUTH_SHARED_KEY => Self::SharedKey,
+            WLAN_AUTH_FT => Self::FastBssTransition,
+            WLAN_AUTH_SAE => Self::Sae,
+            WLAN_AUTH_FILS_SK => Self::FilsSk,
+            WLAN_AUTH_FILS_SK_PFS => Self::FilsSkPfs,
+            WLAN_AUTH_FILS_PK => Self::FilsPk,
+            WLAN_AUTH_PASN => Self::Pasn,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211AuthAlgorithm> for u16 {
+    fn from(v: Nl80211AuthAlgorithm) -> u16 {
+        match v {
+            Nl80211AuthAlgorithm::Open => WLAN_AUTH_OPEN,
+            Nl80211AuthAlgorithm::SharedKey => WLAN_AUTH_SHARED_KEY,
+            Nl80211AuthAlgorithm::FastBssTransition => WLAN_AUTH_FT,
+            Nl80211AuthAlgorithm::Sae => WLAN_AUTH_SAE,
+            Nl80211AuthAlgorithm::FilsSk => WLAN_AUTH_FILS_SK,
+            Nl80211AuthAlgorithm::FilsSkPfs => WLAN_AUTH_FILS_SK_PFS,
+            Nl80211AuthAlgorithm::FilsPk => WLAN_AUTH_FILS_PK,
+            Nl80211AuthAlgorithm::Pasn => WLAN_AUTH_PASN,
+            Nl80211AuthAlgorithm::Other(d) => d,
+        }
+    }
+}
+
+/// SAE fields of authentication frame, IEEE 802.11-2020 9.3.3.12
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Nl80211SaeAuth {
+    Commit {
+        /// Finite cyclic group (IANA group number)
+        group: u16,
+        /// Empty if the group is unknown to this crate
+        scalar: Vec<u8>,
+        /// Empty if the group is unknown to this crate
+        element: Vec<u8>,
+        /// Anything following the element, e.g. password identifier,
+        /// rejected groups or anti-clogging token container
+        remaining: Vec<u8>,
+    },
+    Confirm {
+        send_confirm: u16,
+        confirm: Vec<u8>,
+    },
+    /// Commit rejected with [Nl80211StatusCode::AntiCloggingTokenRequired]
+    AntiCloggingToken {
+        /// Finite cyclic group (IANA group number)
+        group: u16,
+        /// Token (or anti-clogging token container element when using
+        /// hash-to-element) to include in the next commit
+        token: Vec<u8>,
+    },
+    /// Raw body of SAE authentication frame with other unsuccessful status,
+    /// e.g. the rejected group of
+    /// [Nl80211StatusCode::FiniteCyclicGroupNotSupported]
+    Other(Vec<u8>),
+}
+
+impl Nl80211SaeAuth {
+    /// Length of scalar (the prime length) of supported ECC groups
+    fn prime_len(group: u16) -> Option<usize> {
+        match group {
+            19 | 28 => Some(32),
+            20 | 29 => Some(48),
+            21 => Some(66),
+            30 => Some(64),
+            _ => None,
+        }
+    }
+
+    fn parse(transaction: u16, buf: &[u8]) -> Result<Self, DecodeError> {
+        match transaction {
+            WLAN_SAE_COMMIT => {
+                let group = parse_u16_le(buf)?;
+                let buf = &buf[2..];
+                match Self::prime_len(group) {
+                    Some(len) if buf.len() >= len * 3 => Ok(Self::Commit {
+                        group,
+                        scalar: buf[..len].to_vec(),
+                        element: buf[len..len * 3].to_vec(),
+                        remaining: buf[len * 3..].to_vec(),
+                    }),
+                    Some(_) => Err(format!(
+                        "Invalid SAE commit of group {group}, too short {:?}",
+                        buf
+                    )
+                    .into()),
+                    None => Ok(Self::Commit {
+                        group,
+                        scalar: Vec::new(),
+                        element: Vec::new(),
+                        remaining: buf.to_vec(),
+                    }),
+                }
+            }
+            WLAN_SAE_CONFIRM => Ok(Self::Confirm {
+                send_confirm: parse_u16_le(buf)?,
+                confirm: buf[2..].to_vec(),
+            }),
+            _ => Err(format!(
+                "Invalid SAE authentication transaction {transaction}"
+            )
+            .into()),
+        }
+    }
+
+    fn parse_with_status(
+        transaction: u16,
+        status: Nl80211StatusCode,
+        buf: &[u8],
+    ) -> Result<Self, DecodeError> {
+        match status {
+            Nl80211StatusCode::Success
+            | Nl80211StatusCode::SaeHashToElement
+            | Nl80211StatusCode::SaePk => Self::parse(transaction, buf),
+            Nl80211StatusCode::AntiCloggingTokenRequired => {
+                Ok(Self::AntiCloggingToken {
+                    group: parse_u16_le(buf)?,
+                    token: buf[2..].to_vec(),
+                })
+            }
+            _ => Ok(Self::Other(buf.to_vec())),
+        }
+    }
+
+    fn transaction(&self) -> Option<u16> {
+        match self {
+            Self::Commit { .. } | Self::AntiCloggingToken { .. } => {
+                Some(WLAN_SAE_COMMIT)
+            }
+            Self::Confirm { .. } => Some(WLAN_SAE_CONFIRM),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+impl Emitable for Nl80211SaeAuth {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Commit {
+                scalar,
+                element,
+                remaining,
+                ..
+            } => 2 + scalar.len() + element.len() + remaining.len(),
+            Self::Confirm { confirm, .. } => 2 + confirm.len(),
+            Self::AntiCloggingToken { token, .. } => 2 + token.len(),
+            Self::Other(d) => d.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Commit {
+                group,
+                scalar,
+                element,
+                remaining,
+            } => {
+                write_u16_le(buffer, *group);
+                let mut offset = 2;
+                for d in [scalar, element, remaining] {
+                    buffer[offset..offset + d.len()].copy_from_slice(d);
+                    offset += d.len();
+                }
+            }
+            Self::Confirm {
+                send_confirm,
+                confirm,
+            } => {
+                write_u16_le(buffer, *send_confirm);
+                buffer[2..2 + confirm.len()].copy_from_slice(confirm);
+            }
+            Self::AntiCloggingToken { group, token } => {
+                write_u16_le(buffer, *group);
+                buffer[2..2 + token.len()].copy_from_slice(token);
+            }
+            Self::Other(d) => buffer[..d.len()].copy_from_slice(d),
+        }
+    }
+}
+
+/// Body of IEEE 802.11 management frame, IEEE 802.11-2020 9.3.3
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Nl80211MgmtBody {
+    Beacon {
+        /// TSF timer in microseconds
+        timestamp: u64,
+        /// In time units (1024 microseconds)
+        beacon_interval: u16,
+        capabilities: Nl80211BssCapabilities,
+        elements: Vec<Nl80211Element>,
+    },
+    ProbeReq {
+        elements: Vec<Nl80211Element>,
+    },
+    ProbeResp {
+        /// TSF timer in microseconds
+        timestamp: u64,
+        /// In time units (1024 microseconds)
+        beacon_interval: u16,
+        capabilities: Nl80211BssCapabilities,
+        elements: Vec<Nl80211Element>,
+    },
+    Auth {
+        algorithm: Nl80211AuthAlgorithm,
+        transaction: u16,
+        status: Nl80211StatusCode,
+        /// Only for SAE, the `elements` will be empty in that case
+        sae: Option<Nl80211SaeAuth>,
+        elements: Vec<Nl80211Element>,
+    },
+    AssocReq {
+        capabilities: Nl80211BssCapabilities,
+        /// In beacon intervals
+        listen_interval: u16,
+        elements: Vec<Nl80211Element>,
+    },
+    AssocResp {
+        capabilities: Nl80211BssCapabilities,
+        status: Nl80211StatusCode,
+        /// Association ID, the upper two bits are reserved and set to 1
+        aid: u16,
+        elements: Vec<Nl80211Element>,
+    },
+    ReassocReq {
+        capabilities: Nl80211BssCapabilities,
+        /// In beacon intervals
+        listen_interval: u16,
+        current_ap: [u8; ETH_ALEN],
+        elements: Vec<Nl80211Element>,
+    },
+    ReassocResp {
+        capabilities: Nl80211BssCapabilities,
+        status: Nl80211StatusCode,
+        /// Association ID, the upper two bits are reserved and set to 1
+        aid: u16,
+        elements: Vec<Nl80211Element>,
+    },
+    Deauth {
+        reason: Nl80211ReasonCode,
+        /// Vendor specific elements and MME
+        elements: Vec<Nl80211Element>,
+    },
+    Disassoc {
+        reason: Nl80211ReasonCode,
+        /// Vendor specific elements and MME
+        elements: Vec<Nl80211Element>,
+    },
+    Action {
+        category: u8,
+        /// Action details following the category, starting with the
+        /// action code for most categories
+        data: Vec<u8>,
+    },
+    /// Protected (encrypted) frame body or unsupported subtype
+    Other(Vec<u8>),
+}
+
+fn parse_capabilities(
+    buf: &[u8],
+) -> Result<Nl80211BssCapabilities, DecodeError> {
+    // Unlike netlink attributes, fields of frames are little endian
+    Ok(Nl80211BssCapabilities::from_bits_retain(parse_u16_le(buf)?))
+}
+
+fn parse_elements(buf: &[u8]) -> Result<Vec<Nl80211Element>, DecodeError> {
+    Ok(Nl80211Elements::parse(buf)?.into())
+}
+
+fn check_len(
+    buf: &[u8],
+    len: usize,
+    subtype: Nl80211FrameTypeMgmt,
+) -> Result<(), DecodeError> {
+    if buf.len() < len {
+        Err(format!(
+            "Invalid {subtype:?} frame body, expected length at least \
+            {len} got {buf:?}"
+        )
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+impl ParseableParametrized<[u8], Nl80211FrameTypeMgmt> for Nl80211MgmtBody {
+    fn parse_with_param(
+        buf: &[u8],
+        subtype: Nl80211FrameTypeMgmt,
+    ) -> Result<Self, DecodeError> {
+        Ok(match subtype {
+            Nl80211FrameTypeMgmt::Beacon | Nl80211FrameTypeMgmt::ProbeResp => {
+                check_len(buf, TIMESTAMP_LEN + 4, subtype)?;
+                let mut timestamp = [0u8; TIMESTAMP_LEN];
+                timestamp.copy_from_slice(&buf[..TIMESTAMP_LEN]);
+                let timestamp = u64::from_le_bytes(timestamp);
+                let beacon_interval = parse_u16_le(&buf[8..])?;
+                let capabilities = parse_capabilities(&buf[10..])?;
+                let elements = parse_elements(&buf[12..])?;
+                if subtype == Nl80211FrameTypeMgmt::Beacon {
+                    Self::Beacon {
+                        timestamp,
+                        beacon_interval,
+                        capabilities,
+                        elements,
+                    }
+                } else {
+                    Self::ProbeResp {
+                        timestamp,
+                        beacon_interval,
+                        capabilities,
+                        elements,
+                    }
+                }
+            }
+            Nl80211FrameTypeMgmt::ProbeReq => Self::ProbeReq {
+                elements: parse_elements(buf)?,
+            },
+            Nl80211FrameTypeMgmt::Auth => {
+                check_len(buf, 6, subtype)?;
+                let algorithm = Nl80211AuthAlgorithm::from(parse_u16_le(buf)?);
+                let transaction = parse_u16_le(&buf[2..])?;
+                let status = Nl80211StatusCode::from(parse_u16_le(&buf[4..])?);
+                let (sae, elements) = if algorithm == Nl80211AuthAlgorithm::Sae
+                {
+                    (
+                        Some(Nl80211SaeAuth::parse_with_status(
+                            transaction,
+                            status,
+                            &buf[6..],
+                        )?),
+                        vec![],
+                    )
+                } else {
+                    (None, parse_elements(&buf[6..])?)
+                };
+                Self::Auth {
+                    algorithm,
+                    transaction,
+                    status,
+                    sae,
+                    elements,
+                }
+            }
+            Nl80211FrameTypeMgmt::AssocReq => {
+                check_len(buf, 4, subtype)?;
+                Self::AssocReq {
+                    capabilities: parse_capabilities(buf)?,
+                    listen_interval: parse_u16_le(&buf[2..])?,
+                    elements: parse_elements(&buf[4..])?,
+                }
+            }
+            Nl80211FrameTypeMgmt::ReassocReq => {
+                check_len(buf, 4 + ETH_ALEN, subtype)?;
+                let mut current_ap = [0u8; ETH_ALEN];
+                current_ap.copy_from_slice(&buf[4..4 + ETH_ALEN]);
+                Self::ReassocReq {
+                    capabilities: parse_capabilities(buf)?,
+                    listen_interval: parse_u16_le(&buf[2..])?,
+                    current_ap,
+                    elements: parse_elements(&buf[4 + ETH_ALEN..])?,
+                }
+            }
+            Nl80211FrameTypeMgmt::AssocResp
+            | Nl80211FrameTypeMgmt::ReassocResp => {
+                check_len(buf, 6, subtype)?;
+                let capabilities = parse_capabilities(buf)?;
+                let status = Nl80211StatusCode::from(parse_u16_le(&buf[2..])?);
+                let aid = parse_u16_le(&buf[4..])?;
+                let elements = parse_elements(&buf[6..])?;
+                if subtype == Nl80211FrameTypeMgmt::AssocResp {
+                    Self::AssocResp {
+                        capabilities,
+                        status,
+                        aid,
+                        elements,
+                    }
+                } else {
+                    Self::ReassocResp {
+                        capabilities,
+                        status,
+                        aid,
+                        elements,
+                    }
+                }
+            }
+            Nl80211FrameTypeMgmt::Deauth | Nl80211FrameTypeMgmt::Disassoc => {
+                let reason = Nl80211ReasonCode::from(parse_u16_le(buf)?);
+                let elements = parse_elements(&buf[2..])?;
+                if subtype == Nl80211FrameTypeMgmt::Deauth {
+                    Self::Deauth { reason, elements }
+                } else {
+                    Self::Disassoc { reason, elements }
+                }
+            }
+            Nl80211FrameTypeMgmt::Action => {
+                check_len(buf, 1, subtype)?;
+                Self::Action {
+                    category: buf[0],
+                    data: buf[1..].to_vec(),
+                }
+            }
+            _ => Self::Other(buf.to_vec()),
+        })
+    }
+}
+
+impl Nl80211MgmtBody {
+    /// The management frame subtype of this body, `None` for `Other`
+    pub fn subtype(&self) -> Option<Nl80211FrameTypeMgmt> {
+        match self {
+            Self::Beacon { .. } => Some(Nl80211FrameTypeMgmt::Beacon),
+            Self::ProbeReq { .. } => Some(Nl80211FrameTypeMgmt::ProbeReq),
+            Self::ProbeResp { .. } => Some(Nl80211FrameTypeMgmt::ProbeResp),
+            Self::Auth { .. } => Some(Nl80211FrameTypeMgmt::Auth),
+            Self::AssocReq { .. } => Some(Nl80211FrameTypeMgmt::AssocReq),
+            Self::AssocResp { .. } => Some(Nl80211FrameTypeMgmt::AssocResp),
+            Self::ReassocReq { .. } => Some(Nl80211FrameTypeMgmt::ReassocReq),
+            Self::ReassocResp { .. } => Some(Nl80211FrameTypeMgmt::ReassocResp),
+            Self::Deauth { .. } => Some(Nl80211FrameTypeMgmt::Deauth),
+            Self::Disassoc { .. } => Some(Nl80211FrameTypeMgmt::Disassoc),
+            Self::Action { .. } => Some(Nl80211FrameTypeMgmt::Action),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// Information elements of the body, empty for action frames
+    pub fn elements(&self) -> &[Nl80211Element] {
+        match self {
+            Self::Beacon { elements, .. }
+            | Self::ProbeReq { elements }
+            | Self::ProbeResp { elements, .. }
+            | Self::Auth { elements, .. }
+            | Self::AssocReq { elements, .. }
+            | Self::AssocResp { elements, .. }
+            | Self::ReassocReq { elements, .. }
+            | Self::ReassocResp { elements, .. }
+            | Self::Deauth { elements, .. }
+            | Self::Disassoc { elements, .. } => elements.as_slice(),
+            Self::Action { .. } | Self::Other(_) => &[],
+        }
+    }
+
+    fn fixed_len(&self) -> usize {
+        match self {
+            Self::Beacon { .. } | Self::ProbeResp { .. } => TIMESTAMP_LEN + 4,
+            Self::ProbeReq { .. } => 0,
+            Self::Auth { sae, .. } => {
+                6 + sae.as_ref().map(|s| s.buffer_len()).unwrap_or_default()
+            }
+            Self::AssocReq { .. } => 4,
+            Self::ReassocReq { .. } => 4 + ETH_ALEN,
+            Self::AssocResp { .. } | Self::ReassocResp { .. } => 6,
+            Self::Deauth { .. } | Self::Disassoc { .. } => 2,
+            Self::Action { data, .. } => 1 + data.len(),
+            Self::Other(d) => d.len(),
+        }
+    }
+}
+
+impl Emitable for Nl80211MgmtBody {
+    fn buffer_len(&self) -> usize {
+        self.fixed_len()
+            + Nl80211Elements::from(&self.elements().to_vec()).buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Beacon {
+                timestamp,
+                beacon_interval,
+                capabilities,
+                ..
+            }
+            | Self::ProbeResp {
+                timestamp,
+                beacon_interval,
+                capabilities,
+                ..
+            } => {
+                buffer[..TIMESTAMP_LEN]
+                    .copy_from_slice(&timestamp.to_le_bytes());
+                write_u16_le(&mut buffer[8..], *beacon_interval);
+                write_u16_le(&mut buffer[10..], capabilities.bits());
+            }
+            Self::ProbeReq { .. } => (),
+            Self::Auth {
+                algorithm,
+                transaction,
+                status,
+                sae,
+                ..
+            } => {
+                write_u16_le(buffer, (*algorithm).into());
+                write_u16_le(
+                    &mut buffer[2..],
+                    sae.as_ref()
+                        .and_then(|s| s.transaction())
+                        .unwrap_or(*transaction),
+                );
+                write_u16_le(&mut buffer[4..], (*status).into());
+                if let Some(sae) = sae {
+                    sae.emit(&mut buffer[6..]);
+                }
+            }
+            Self::AssocReq {
+                capabilities,
+                listen_interval,
+                ..
+            } => {
+                write_u16_le(buffer, capabilities.bits());
+                write_u16_le(&mut buffer[2..], *listen_interval);
+            }
+            Self::ReassocReq {
+                capabilities,
+                listen_interval,
+                current_ap,
+                ..
+            } => {
+                write_u16_le(buffer, capabilities.bits());
+                write_u16_le(&mut buffer[2..], *listen_interval);
+                buffer[4..4 + ETH_ALEN].copy_from_slice(current_ap);
+            }
+            Self::AssocResp {
+                capabilities,
+                status,
+                aid,
+                ..
+            }
+            | Self::ReassocResp {
+                capabilities,
+                status,
+                aid,
+                ..
+            } => {
+                write_u16_le(buffer, capabilities.bits());
+                write_u16_le(&mut buffer[2..], (*status).into());
+                write_u16_le(&mut buffer[4..], *aid);
+            }
+            Self::Deauth { reason, .. } | Self::Disassoc { reason, .. } => {
+                write_u16_le(buffer, (*reason).into());
+            }
+            Self::Action { category, data } => {
+                buffer[0] = *category;
+                buffer[1..1 + data.len()].copy_from_slice(data);
+            }
+            Self::Other(d) => buffer[..d.len()].copy_from_slice(d),
+        }
+        let offset = self.fixed_len();
+        Nl80211Elements::from(&self.elements().to_vec())
+            .emit(&mut buffer[offset..]);
+    }
+}
+
+/// IEEE 802.11 management frame, could be used to decode
+/// `NL80211_ATTR_FRAME` or build frame for `Nl80211FrameTxRequest`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct Nl80211MgmtFrame {
+    pub header: Nl80211MacHeader,
+    pub body: Nl80211MgmtBody,
+}
+
+impl Nl80211MgmtFrame {
+    /// Create management frame of `subtype` sent from `sa` to `da` in
+    /// `bssid`. The body should be of the same subtype, or
+    /// [Nl80211MgmtBody::Other] holding the raw body, e.g. of a subtype not
+    /// supported by this crate.
+    pub fn new(
+        subtype: Nl80211FrameTypeMgmt,
+        da: [u8; ETH_ALEN],
+        sa: [u8; ETH_ALEN],
+        bssid: [u8; ETH_ALEN],
+        body: Nl80211MgmtBody,
+    ) -> Self {
+        Self {
+            header: Nl80211MacHeader::new_mgmt(subtype, da, sa, bssid),
+            body,
+        }
+    }
+
+    /// Emit the frame into a new buffer
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = vec![0u8; self.buffer_len()];
+        self.emit(&mut buffer);
+        buffer
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211MgmtFrame {
+    fn parse(buf: &T) -> Result<Self, DecodeError> {
+        let buf = buf.as_ref();
+        let header = Nl80211MacHeader::parse(buf)?;
+        let subtype = match header.frame_type {
+            Nl80211FrameType::Management(s) => s,
+            _ => {
+                return Err(format!(
+                    "Not a IEEE 802.11 management frame {:?}",
+                    header.frame_type
+                )
+                .into())
+            }
+        };
+        let payload = &buf[header.buffer_len()..];
+        let body = if header.flags.contains(Nl80211FrameControlFlags::Protected)
+        {
+            Nl80211MgmtBody::Other(payload.to_vec())
+        } else {
+            Nl80211MgmtBody::parse_with_param(payload, subtype)?
+        };
+        Ok(Self { header, body })
+    }
+}
+
+impl Emitable for Nl80211MgmtFrame {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.body.buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        let offset = self.header.buffer_len();
+        self.body.emit(&mut buffer[offset..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AP: [u8; ETH_ALEN] = [0x02, 0, 0, 0, 0, 0x01];
+    const STA: [u8; ETH_ALEN] = [0x02, 0, 0, 0, 0, 0x02];
+    const BROADCAST: [u8; ETH_ALEN] = [0xff; ETH_ALEN];
+
+    fn header(fc: [u8; 2], da: [u8; ETH_ALEN], sa: [u8; ETH_ALEN]) -> Vec<u8> {
+        let mut ret = fc.to_vec();
+        ret.extend_from_slice(&[0, 0]);
+        ret.extend_from_slice(&da);
+        ret.extend_from_slice(&sa);
+        ret.extend_from_slice(&AP);
+        ret.extend_from_slice(&[0x10, 0]);
+        ret
+    }
+
+    #[test]
+    fn beacon_round_trip() {
+        let mut raw = header([0x80, 0], BROADCAST, AP);
+        raw.extend_from_slice(&0x0102030405060708u64.to_le_bytes());
+        raw.extend_from_slice(&[0x64, 0x00, 0x31, 0x04]);
+        raw.extend_from_slice(&[0, 3, b'l', b'a', b'b', 3, 1, 6]);
+
+        let frame = Nl80211MgmtFrame::parse(&raw).unwrap();
+        assert_eq!(
+            frame.header.frame_type,
+            Nl80211FrameType::Management(Nl80211FrameTypeMgmt::Beacon)
+        );
+        assert_eq!(frame.header.sequence_number(), 1);
+        assert_eq!(
+            frame.body,
+            Nl80211MgmtBody::Beacon {
+                timestamp: 0x0102030405060708,
+                beacon_interval: 100,
+                capabilities: Nl80211BssCapabilities::from_bits_retain(0x0431),
+                elements: vec![
+                    Nl80211Element::Ssid("lab".to_string()),
+                    Nl80211Element::Channel(6),
+                ],
+            }
+        );
+        assert_eq!(frame.to_bytes(), raw);
+    }
+
+    #[test]
+    fn sae_commit_auth_round_trip() {
+        let scalar = vec![0x11; 32];
+        let element = vec![0x22; 64];
+        let mut raw = header([0xb0, 0], AP, STA);
+        // SAE algorithm, commit transaction, success status, group 19
+        raw.extend_from_slice(&[3, 0, 1, 0, 0, 0, 19, 0]);
+        raw.extend_from_slice(&scalar);
+        raw.extend_from_slice(&element);
+
+        let frame = Nl80211MgmtFrame::parse(&raw).unwrap();
+        assert_eq!(
+            frame.body,
+            Nl80211MgmtBody::Auth {
+                algorithm: Nl80211AuthAlgorithm::Sae,
+                transaction: 1,
+                status: Nl80211StatusCode::Success,
+                sae: Some(Nl80211SaeAuth::Commit {
+                    group: 19,
+                    scalar,
+                    element,
+                    remaining: vec![],
+                }),
+                elements: vec![],
+            }
+        );
+        assert_eq!(frame.to_bytes(), raw);
+    }
+
+    #[test]
+    fn sae_anti_clogging_auth_round_trip() {
+        let token = vec![0x33; 32];
+        let mut raw = header([0xb0, 0], STA, AP);
+        // SAE algorithm, commit transaction, anti-clogging token required
+        // status, group 19
+        raw.extend_from_slice(&[3, 0, 1, 0, 76, 0, 19, 0]);
+        raw.extend_from_slice(&token);
+
+        let frame = Nl80211MgmtFrame::parse(&raw).unwrap();
+        assert_eq!(
+            frame.body,
+            Nl80211MgmtBody::Auth {
+                algorithm: Nl80211AuthAlgorithm::Sae,
+                transaction: 1,
+                status: Nl80211StatusCode::AntiCloggingTokenRequired,
+                sae: Some(Nl80211SaeAuth::AntiCloggingToken {
+                    group: 19,
+                    token,
+                }),
+                elements: vec![],
+            }
+        );
+        assert_eq!(frame.to_bytes(), raw);
+    }
+
+    #[test]
+    fn sae_rejected_group_auth_round_trip() {
+        let mut raw = header([0xb0, 0], STA, AP);
+        // SAE algorithm, commit transaction, finite cyclic group not
+        // supported status, the rejected group 21
+        raw.extend_from_slice(&[3, 0, 1, 0, 77, 0, 21, 0]);
+
+        let frame = Nl80211MgmtFrame::parse(&raw).unwrap();
+        assert_eq!(
+            frame.body,
+            Nl80211MgmtBody::Auth {
+                algorithm: Nl80211AuthAlgorithm::Sae,
+                transaction: 1,
+                status: Nl80211StatusCode::FiniteCyclicGroupNotSupported,
+                sae: Some(Nl80211SaeAuth::Other(vec![21, 0])),
+                elements: vec![],
+            }
+        );
+        assert_eq!(frame.to_bytes(), raw);
+    }
+
+    #[test]
+    fn action_with_ht_control_round_trip() {
+        // Order flag set, the HT control field follows the header
+        let mut raw = header([0xd0, 0x80], AP, STA);
+        raw.extend_from_slice(&[0x78, 0x56, 0x34, 0x12]);
+        // Public action, GAS initial request
+        raw.extend_from_slice(&[4, 10, 0x01, 0x6c, 0x02]);
+
+        let frame = Nl80211MgmtFrame::parse(&raw).unwrap();
+        assert!(frame.header.flags.contains(Nl80211FrameControlFlags::Order));
+        assert_eq!(frame.header.ht_control, Some(0x12345678));
+        assert_eq!(
+            frame.body,
+            Nl80211MgmtBody::Action {
+                category: 4,
+                data: vec![10, 0x01, 0x6c, 0x02],
+            }
+        );
+        assert_eq!(frame.to_bytes(), raw);
+
+        let mut frame = frame;
+        frame.header.ht_control = None;
+        let bytes = frame.to_bytes();
+        assert_eq!(bytes[1], 0);
+        assert_eq!(&bytes[24..], &[4, 10, 0x01, 0x6c, 0x02]);
+    }
+
+    #[test]
+    fn new_keeps_subtype_of_other_body() {
+        let frame = Nl80211MgmtFrame::new(
+            Nl80211FrameTypeMgmt::Auth,
+            AP,
+            STA,
+            AP,
+            Nl80211MgmtBody::Other(vec![0xaa, 0xbb]),
+        );
+        let bytes = frame.to_bytes();
+        assert_eq!(&bytes[..2], &[0xb0, 0]);
+        assert_eq!(&bytes[24..], &[0xaa, 0xbb]);
+    }
+}
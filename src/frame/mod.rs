@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+
+mod cancel;
+mod header;
+mod mgmt;
+mod register;
+mod rx;
+mod status;
+mod tx;
+
+pub use self::cancel::Nl80211FrameWaitCancelRequest;
+pub use self::header::{Nl80211FrameControlFlags, Nl80211MacHeader};
+pub use self::mgmt::{
+    Nl80211AuthAlgorithm, Nl80211MgmtBody, Nl80211MgmtFrame, Nl80211SaeAuth,
+};
+pub use self::register::{
+    Nl80211RegisterFramesRequest, Nl80211RxFrameStream,
+};
+pub use self::rx::{Nl80211RxFrame, Nl80211RxFrameFlags};
+pub use self::status::Nl80211FrameTxStatus;
+pub use self::tx::{Nl80211FrameTx, Nl80211FrameTxRequest};
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, TryStreamExt};
+use netlink_sys::AsyncSocket;
+
+use crate::{
+    Nl80211Error, Nl80211Event, Nl80211EventStream, Nl80211FrameType,
+    Nl80211Handle, Nl80211RegisterFrame, Nl80211RxFrame,
+};
+
+/// Register for several management frame types on a dedicated socket and
+/// receive the matching frames from it.
+/// Created by [crate::Nl80211InterfaceHandle::register_frames()].
+pub struct Nl80211RegisterFramesRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    frames: Vec<(Nl80211FrameType, Vec<u8>)>,
+}
+
+impl Nl80211RegisterFramesRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        frames: &[(Nl80211FrameType, Vec<u8>)],
+    ) -> Self {
+        Nl80211RegisterFramesRequest {
+            handle,
+            if_index,
+            frames: frames.to_vec(),
+        }
+    }
+
+    /// Register the frames and return the stream of received frames.
+    #[cfg(feature = "tokio_socket")]
+    pub async fn stream(
+        self,
+    ) -> Result<Nl80211RxFrameStream<netlink_sys::TokioSocket>, Nl80211Error>
+    {
+        self.stream_with_socket().await
+    }
+
+    /// Same as [Nl80211RegisterFramesRequest::stream()] with specified
+    /// socket type.
+    pub async fn stream_with_socket<S>(
+        self,
+    ) -> Result<Nl80211RxFrameStream<S>, Nl80211Error>
+    where
+        S: AsyncSocket,
+    {
+        let Nl80211RegisterFramesRequest {
+            mut handle,
+            if_index,
+            frames,
+        } = self;
+
+        // Kernel delivers the frames to the socket which registered them
+        // and drops the registrations once that socket is closed, hence
+        // register through the socket of the stream. Nothing polls the
+        // stream yet, so drive its connection until each ACK arrives.
+        let mut events = handle.events_with_socket::<S>(&[]).await?;
+        for (frame_type, frame_match) in frames {
+            let request = Nl80211RegisterFrame::new(
+                events.handle(),
+                if_index,
+                frame_type,
+                frame_match,
+            );
+            events
+                .drive(async move {
+                    let mut stream = Box::pin(request.execute().await);
+                    while stream.try_next().await?.is_some() {}
+                    Ok::<(), Nl80211Error>(())
+                })
+                .await?;
+        }
+        Ok(Nl80211RxFrameStream { events })
+    }
+}
+
+/// Stream of [Nl80211RxFrame] matching the registrations made by
+/// [Nl80211RegisterFramesRequest]. Dropping the stream closes the socket
+/// and removes the registrations.
+#[derive(Debug)]
+pub struct Nl80211RxFrameStream<S> {
+    events: Nl80211EventStream<S>,
+}
+
+impl<S> Nl80211RxFrameStream<S>
+where
+    S: AsyncSocket,
+{
+    /// Handle sending requests through the socket of this stream, e.g.
+    /// for additional frame registrations.
+    pub fn handle(&self) -> Nl80211Handle {
+        self.events.handle()
+    }
+}
+
+impl<S> Stream for Nl80211RxFrameStream<S>
+where
+    S: AsyncSocket,
+{
+    type Item = Result<Nl80211RxFrame, Nl80211Error>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.events).poll_next(cx) {
+                Poll::Ready(Some(Ok(Nl80211Event::Frame(frame)))) => {
+                    return Poll::Ready(Some(Ok(frame)))
+                }
+                Poll::Ready(Some(Ok(_))) => continue,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
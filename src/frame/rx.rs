@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use crate::Nl80211Attr;
+
+bitflags::bitflags! {
+    /// Flags of received management frame (`NL80211_RXMGMT_FLAG_*`)
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[non_exhaustive]
+    pub struct Nl80211RxFrameFlags: u32 {
+        /// The frame was already answered by driver or device
+        const Answered = 1 << 0;
+        /// The frame is for external authentication and should be handled
+        /// by userspace
+        const ExternalAuth = 1 << 1;
+        const _ = !0;
+    }
+}
+
+/// Management frame matching a frame registration, sent by kernel to the
+/// socket which registered for it.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211RxFrame {
+    pub wiphy: Option<u32>,
+    pub if_index: Option<u32>,
+    pub wdev: Option<u64>,
+    /// Frequency in MHz the frame was received on
+    pub freq: Option<u32>,
+    /// Signal strength in dBm
+    pub signal_dbm: Option<i32>,
+    pub rx_flags: Nl80211RxFrameFlags,
+    /// The received frame, including the IEEE 802.11 header
+    pub frame: Vec<u8>,
+    /// MLO link the frame was received on
+    pub link_id: Option<u8>,
+    /// All the attributes of the event
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl From<Vec<Nl80211Attr>> for Nl80211RxFrame {
+    fn from(attributes: Vec<Nl80211Attr>) -> Self {
+        let mut ret = Self::default();
+        for attr in attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(*d),
+                Nl80211Attr::WiphyFreq(d) => ret.freq = Some(*d),
+                Nl80211Attr::RxSignalDbm(d) => ret.signal_dbm = Some(*d),
+                Nl80211Attr::RxmgmtFlags(d) => ret.rx_flags = *d,
+                Nl80211Attr::Frame(d) => ret.frame = d.clone(),
+                Nl80211Attr::MloLinkId(d) => ret.link_id = Some(*d),
+                _ => (),
+            }
+        }
+        ret.attributes = attributes;
+        ret
+    }
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use crate::Nl80211Attr;
+
+/// TX status of a management frame transmitted by
+/// [crate::Nl80211FrameTxRequest], sent by kernel to the "mlme" multicast
+/// group.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211FrameTxStatus {
+    pub wiphy: Option<u32>,
+    pub if_index: Option<u32>,
+    pub wdev: Option<u64>,
+    /// Cookie returned when the frame was transmitted
+    pub cookie: Option<u64>,
+    /// The frame was acknowledged by the recipient
+    pub ack: bool,
+    /// Signal strength of the ACK frame in dBm
+    pub ack_signal: Option<i32>,
+    /// The transmitted frame
+    pub frame: Vec<u8>,
+    /// All the attributes of the event
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl From<Vec<Nl80211Attr>> for Nl80211FrameTxStatus {
+    fn from(attributes: Vec<Nl80211Attr>) -> Self {
+        let mut ret = Self::default();
+        for attr in attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(*d),
+                Nl80211Attr::Cookie(d) => ret.cookie = Some(*d),
+                Nl80211Attr::Ack => ret.ack = true,
+                Nl80211Attr::AckSignal(d) => ret.ack_signal = Some(*d),
+                Nl80211Attr::Frame(d) => ret.frame = d.clone(),
+                _ => (),
+            }
+        }
+        ret.attributes = attributes;
+        ret
+    }
+}
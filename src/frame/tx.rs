@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use futures::{TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+use netlink_sys::AsyncSocket;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211AttrsBuilder, Nl80211Command,
+    Nl80211Error, Nl80211Event, Nl80211EventStream, Nl80211FrameTxStatus,
+    Nl80211Handle, Nl80211Message, Nl80211MulticastGroup,
+};
+
+/// Transmit a management frame (`NL80211_CMD_FRAME`), e.g. to answer
+/// authentication or association requests from userspace.
+pub struct Nl80211FrameTxRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    attrs: Nl80211AttrsBuilder<Nl80211FrameTxRequest>,
+    dont_wait_for_ack: bool,
+}
+
+impl Nl80211FrameTxRequest {
+    /// The frame should include the IEEE 802.11 header
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        frame: Vec<u8>,
+    ) -> Self {
+        Nl80211FrameTxRequest {
+            handle,
+            if_index,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::Frame(frame)),
+            dont_wait_for_ack: false,
+        }
+    }
+
+    /// Transmit on specified frequency instead of the operating channel
+    pub fn with_frequency(mut self, freq: u32) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::WiphyFreq(freq));
+        self
+    }
+
+    /// Stay on the off-channel frequency waiting for a response, cancel it
+    /// by [crate::Nl80211FrameWaitCancelRequest]
+    pub fn with_wait(mut self, wait: Duration) -> Self {
+        let wait = u32::try_from(wait.as_millis()).unwrap_or(u32::MAX);
+        self.attrs = self.attrs.replace(Nl80211Attr::Duration(wait));
+        self
+    }
+
+    /// Allow transmitting on a channel other than the operating one
+    pub fn with_offchannel_tx_ok(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::OffchannelTxOk);
+        self
+    }
+
+    /// Do not use CCK rates, used for P2P
+    pub fn with_no_cck_rate(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::TxNoCckRate);
+        self
+    }
+
+    /// Do not report TX status, kernel will not return a cookie either
+    pub fn with_dont_wait_for_ack(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::DontWaitForAck);
+        self.dont_wait_for_ack = true;
+        self
+    }
+
+    /// Transmit on specified MLO link
+    pub fn with_link_id(mut self, link_id: u8) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MloLinkId(link_id));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211FrameTxRequest {
+            mut handle, attrs, ..
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::Frame,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+
+    /// Transmit the frame and return the kernel cookie along with the
+    /// subscription waiting for its TX status
+    #[cfg(feature = "tokio_socket")]
+    pub async fn send(
+        self,
+    ) -> Result<Nl80211FrameTx<netlink_sys::TokioSocket>, Nl80211Error> {
+        self.send_with_socket().await
+    }
+
+    /// Same as [Nl80211FrameTxRequest::send()] with specified socket type.
+    pub async fn send_with_socket<S>(
+        self,
+    ) -> Result<Nl80211FrameTx<S>, Nl80211Error>
+    where
+        S: AsyncSocket,
+    {
+        let mut handle = self.handle.clone();
+        let if_index = self.if_index;
+
+        // TX status is sent to the "mlme" multicast group, subscribe before
+        // transmitting so it cannot be missed.
+        let events = if self.dont_wait_for_ack {
+            None
+        } else {
+            Some(
+                handle
+                    .events_with_socket(&[Nl80211MulticastGroup::Mlme])
+                    .await?,
+            )
+        };
+
+        let mut cookie = None;
+        let mut stream = Box::pin(self.execute().await);
+        while let Some(msg) = stream.try_next().await? {
+            for attr in msg.payload.attributes {
+                if let Nl80211Attr::Cookie(d) = attr {
+                    cookie = Some(d);
+                }
+            }
+        }
+
+        Ok(Nl80211FrameTx {
+            cookie,
+            if_index,
+            events,
+        })
+    }
+}
+
+/// Management frame handed to kernel by
+/// [Nl80211FrameTxRequest::send()].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Nl80211FrameTx<S> {
+    /// Cookie identifying the transmission, used by TX status and
+    /// [crate::Nl80211FrameWaitCancelRequest]. `None` when sent with
+    /// `DontWaitForAck`.
+    pub cookie: Option<u64>,
+    if_index: u32,
+    events: Option<Nl80211EventStream<S>>,
+}
+
+impl<S> Nl80211FrameTx<S>
+where
+    S: AsyncSocket,
+{
+    /// Wait for the `NL80211_CMD_FRAME_TX_STATUS` event of this frame,
+    /// check [Nl80211FrameTxStatus::ack] for whether the recipient
+    /// acknowledged it.
+    pub async fn status(self) -> Result<Nl80211FrameTxStatus, Nl80211Error> {
+        let Nl80211FrameTx {
+            cookie,
+            if_index,
+            events,
+        } = self;
+        let (cookie, events) = match (cookie, events) {
+            (Some(cookie), Some(events)) => (cookie, events),
+            _ => {
+                return Err(Nl80211Error::RequestFailed(
+                    "No TX status for frame sent without cookie".to_string(),
+                ))
+            }
+        };
+        let mut events = Box::pin(events);
+        while let Some(event) = events.try_next().await? {
+            if let Nl80211Event::FrameTxStatus(status) = event {
+                if status.cookie == Some(cookie)
+                    && status.if_index == Some(if_index)
+                {
+                    return Ok(status);
+                }
+            }
+        }
+        Err(Nl80211Error::RequestFailed(
+            "nl80211 event stream closed before TX status of frame".to_string(),
+        ))
+    }
+}
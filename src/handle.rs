@@ -6,9 +6,13 @@ use netlink_packet_core::NetlinkMessage;
 use netlink_packet_generic::GenlMessage;
 use netlink_packet_utils::DecodeError;
 
+use netlink_sys::AsyncSocket;
+
 use crate::{
-    try_nl80211, Nl80211Error, Nl80211InterfaceHandle, Nl80211Message,
-    Nl80211ScanHandle, Nl80211StationHandle, Nl80211WiphyHandle,
+    try_nl80211, Nl80211Error, Nl80211EventStream, Nl80211InterfaceHandle,
+    Nl80211KeyHandle, Nl80211Message, Nl80211MulticastGroup,
+    Nl80211RegulatoryHandle, Nl80211ScanHandle, Nl80211StationHandle,
+    Nl80211SurveyHandle, Nl80211WiphyHandle,
 };
 
 #[derive(Clone, Debug)]
@@ -36,16 +40,55 @@ impl Nl80211Handle {
         Nl80211StationHandle::new(self.clone())
     }
 
+    // equivalent to `NL80211_CMD_{NEW,SET,DEL,GET}_KEY` commands
+    pub fn key(&self) -> Nl80211KeyHandle {
+        Nl80211KeyHandle::new(self.clone())
+    }
+
     // equivalent to `iw phy` command
     pub fn wireless_physic(&self) -> Nl80211WiphyHandle {
         Nl80211WiphyHandle::new(self.clone())
     }
 
+    // equivalent to `iw reg` command
+    pub fn regulatory(&self) -> Nl80211RegulatoryHandle {
+        Nl80211RegulatoryHandle::new(self.clone())
+    }
+
     // equivalent to `iw dev DEVICE scan` command
     pub fn scan(&self) -> Nl80211ScanHandle {
         Nl80211ScanHandle::new(self.clone())
     }
 
+    // equivalent to `iw dev DEVICE survey` command
+    pub fn survey(&self) -> Nl80211SurveyHandle {
+        Nl80211SurveyHandle::new(self.clone())
+    }
+
+    /// Subscribe to the specified nl80211 multicast groups.
+    /// The group IDs are resolved through the generic netlink controller and
+    /// joined on a dedicated socket, the returned stream yields the decoded
+    /// notifications. Use [Nl80211MulticastGroup::ALL] to receive everything.
+    #[cfg(feature = "tokio_socket")]
+    pub async fn events(
+        &mut self,
+        groups: &[Nl80211MulticastGroup],
+    ) -> Result<Nl80211EventStream<netlink_sys::TokioSocket>, Nl80211Error>
+    {
+        self.events_with_socket(groups).await
+    }
+
+    /// Same as [Nl80211Handle::events()] with specified socket type.
+    pub async fn events_with_socket<S>(
+        &mut self,
+        groups: &[Nl80211MulticastGroup],
+    ) -> Result<Nl80211EventStream<S>, Nl80211Error>
+    where
+        S: AsyncSocket,
+    {
+        Nl80211EventStream::new(self, groups).await
+    }
+
     pub async fn request(
         &mut self,
         message: NetlinkMessage<GenlMessage<Nl80211Message>>,
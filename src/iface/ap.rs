@@ -1,14 +1,19 @@
 // SPDX-License-Identifier: MIT
 
 use std::any::Any;
+use std::convert::TryFrom;
 
-use futures::TryStream;
+use futures::{TryStream, TryStreamExt};
 use log::debug;
 use netlink_packet_core::{NLM_F_ACK, NLM_F_DUMP, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
-use netlink_packet_utils::Emitable;
+use netlink_packet_utils::{DecodeError, Emitable, Parseable};
 
-use crate::{element::{Nl80211AkmSuite, Nl80211AuthType}, nl80211_execute, reg::{Nl80211RegDomType, Nl80211RegdomInitiator}, Nl80211Attr, Nl80211BssCapabilities, Nl80211ChannelWidth, Nl80211CipherSuite, Nl80211Command, Nl80211Element, Nl80211Elements, Nl80211Error, Nl80211FrameType, Nl80211Handle, Nl80211Message};
+use crate::{element::{Nl80211AkmSuite, Nl80211AuthType}, nl80211_execute, Nl80211Attr, Nl80211AttrsBuilder, Nl80211BssCapabilities, Nl80211Chandef, Nl80211ChannelWidth, Nl80211CipherSuite, Nl80211Command, Nl80211Element, Nl80211Elements, Nl80211Error, Nl80211FrameType, Nl80211FrameTypeMgmt, Nl80211Handle, Nl80211Message, Nl80211MgmtBody, Nl80211MgmtFrame};
+
+const ELEMENT_ID_SUPPORTED_RATES: u8 = 1;
+// In time units (1024 microseconds)
+const DEFAULT_BEACON_INTERVAL: u16 = 100;
 
 // The command types in this file are specific to AP-mode operation (there is probably other use-cases, but the goal here is eventually parity with hostapd)
 
@@ -109,62 +114,6 @@ impl Nl80211RegisterFrame {
 }
 
 
-/// Prepare and send a CMD_GET_REG - used to request the regulatory domain
-pub struct Nl80211GetRegulatory {
-    handle: Nl80211Handle,
-    message: Nl80211Message,
-}
-
-impl Nl80211GetRegulatory {
-    pub(crate) fn new(handle: Nl80211Handle) -> Self {
-        Nl80211GetRegulatory { 
-            handle,
-            message: Nl80211Message {
-                cmd: Nl80211Command::GetReg,
-                attributes: vec![],
-            },
-        }
-    }
-
-    pub async fn execute(
-        self,
-    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
-    {
-        let Nl80211GetRegulatory { mut handle, message } = self;
-        
-        nl80211_execute(&mut handle, message, NLM_F_REQUEST).await
-    }
-
-}
-
-/// Prepare and send a CMD_REQ_SET_REG - used to request a change to regulatory domain
-pub struct Nl80211ReqSetRegulatory {
-    handle: Nl80211Handle,
-    message: Nl80211Message,
-}
-
-impl Nl80211ReqSetRegulatory {
-    pub(crate) fn new(handle: Nl80211Handle, alpha2: String) -> Self {
-        Nl80211ReqSetRegulatory { 
-            handle,
-            message: Nl80211Message {
-                cmd: Nl80211Command::ReqSetReg,
-                attributes: vec![Nl80211Attr::RegAlpha2(alpha2)],
-            },
-        }
-    }
-
-    pub async fn execute(
-        self,
-    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
-    {
-        let Nl80211ReqSetRegulatory { mut handle, message } = self;
-
-        nl80211_execute(&mut handle, message, NLM_F_REQUEST).await
-    }
-
-}
-
 // To set as an AP:
 // Set AP Mode
 // Set Regulatory Domain
@@ -298,51 +247,9 @@ impl Nl80211StartAp {
         supported_rates: &[u8],
         channel: u8,
     ) -> Self {
-        let mut beacon_head = Vec::new();
-    
-        // Frame Control
-        beacon_head.extend_from_slice(&[0x80, 0x00]);
-    
-        // Duration
-        beacon_head.extend_from_slice(&[0x00, 0x00]);
-    
-        // Destination Address (Broadcast)
-        beacon_head.extend_from_slice(&[0xFF; 6]);
-    
-        // Source Address
-        beacon_head.extend_from_slice(&src_mac);
-    
-        // BSSID
-        beacon_head.extend_from_slice(&src_mac);
-    
-        // Sequence Control
-        beacon_head.extend_from_slice(&[0x00, 0x00]);
-    
-        // Timestamp
-        beacon_head.extend_from_slice(&[0x00; 8]);
-    
-        // Beacon Interval
-        beacon_head.extend_from_slice(&100u16.to_le_bytes());
-    
-        // Capability Information
-        beacon_head.extend_from_slice(&capabilities.bits().to_le_bytes());
-    
-        // **Include SSID IE**
-        let ssid_bytes = ssid.as_bytes();
-        beacon_head.push(0x00); // SSID Element ID
-        beacon_head.push(ssid_bytes.len() as u8); // SSID Length
-        beacon_head.extend_from_slice(ssid_bytes); // SSID
-    
-        // **Include Supported Rates IE**
-        beacon_head.push(0x01); // Supported Rates Element ID
-        beacon_head.push(supported_rates.len() as u8); // Supported Rates Length
-        beacon_head.extend_from_slice(supported_rates); // Supported Rates
-    
-        // **Include DS Parameter Set IE**
-        beacon_head.push(0x03); // DS Parameter Set Element ID
-        beacon_head.push(0x01); // Length
-        beacon_head.push(channel); // Current Channel
-    
+        let beacon_head =
+            beacon_head(src_mac, capabilities, ssid, supported_rates, channel);
+
         // Remove any existing BeaconHead attribute
         self.message.attributes.retain(|attr| !matches!(attr, Nl80211Attr::BeaconHead(_)));
     
@@ -390,10 +297,7 @@ impl Nl80211StartAp {
     /// Set the beacon tail using high-level Information Elements
     pub fn with_beacon_tail(mut self, elements: &Vec<Nl80211Element>) -> Self {
         // Convert elements to raw bytes
-        let beacon_elements = Nl80211Elements::from(elements);
-        let buffer_len = beacon_elements.buffer_len();
-        let mut beacon_tail_buffer = vec![0u8; buffer_len];
-        beacon_elements.emit(&mut beacon_tail_buffer);
+        let beacon_tail_buffer = elements_to_bytes(elements);
 
         debug!("Custom: {:?}", beacon_tail_buffer);
 
@@ -423,6 +327,22 @@ impl Nl80211StartAp {
         self
     }
 
+    /// Replace the frequency and channel width given to [Self::new()] by
+    /// the channel definition, e.g. for 80 MHz and wider channels
+    pub fn with_chandef(mut self, chandef: &Nl80211Chandef) -> Self {
+        self.message.attributes.retain(|attr| {
+            !matches!(
+                attr,
+                Nl80211Attr::WiphyFreq(_)
+                    | Nl80211Attr::ChannelWidth(_)
+                    | Nl80211Attr::CenterFreq1(_)
+                    | Nl80211Attr::CenterFreq2(_)
+                    | Nl80211Attr::WiphyChannelType(_)
+            )
+        });
+        self.message.attributes.extend(chandef.to_attrs());
+        self
+    }
     
     pub async fn execute(
         self,
@@ -435,3 +355,356 @@ impl Nl80211StartAp {
         nl80211_execute(&mut handle, message, NLM_F_REQUEST | NLM_F_ACK).await
     }
 }
+
+/// Struct to handle building and sending NL80211_CMD_STOP_AP
+pub struct Nl80211StopAp {
+    handle: Nl80211Handle,
+    message: Nl80211Message,
+}
+
+impl Nl80211StopAp {
+    pub fn new(handle: Nl80211Handle, ifindex: u32) -> Self {
+        Nl80211StopAp {
+            handle,
+            message: Nl80211Message {
+                cmd: Nl80211Command::StopAp,
+                attributes: vec![Nl80211Attr::IfIndex(ifindex)],
+            },
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211StopAp { mut handle, message } = self;
+
+        nl80211_execute(&mut handle, message, NLM_F_REQUEST | NLM_F_ACK).await
+    }
+}
+
+/// Struct to handle building and sending NL80211_CMD_SET_BEACON, which
+/// updates the beacon and probe response data of a running AP without
+/// restarting it. Only the beacon data included is changed.
+pub struct Nl80211SetBeacon {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211SetBeacon>,
+}
+
+impl Nl80211SetBeacon {
+    pub fn new(handle: Nl80211Handle, ifindex: u32) -> Self {
+        Nl80211SetBeacon {
+            handle,
+            attrs: Nl80211AttrsBuilder::new().if_index(ifindex),
+        }
+    }
+
+    /// Replace the IEs of beacon, probe response and association response
+    pub fn with_ies(mut self, ies: Nl80211Elements) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Ie(ies));
+        self
+    }
+
+    /// Replace the IEs of probe response
+    pub fn with_probe_resp_ies(mut self, ies: Nl80211Elements) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::IeProbeResp(ies));
+        self
+    }
+
+    /// Replace the IEs of association response
+    pub fn with_assoc_resp_ies(mut self, ies: Nl80211Elements) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::IeAssocResp(ies));
+        self
+    }
+
+    /// Replace the beacon head, same as [Nl80211StartAp::with_beacon_head()].
+    /// Pass an empty `ssid` to hide the SSID from beacons.
+    pub fn with_beacon_head(
+        mut self,
+        src_mac: [u8; 6],
+        capabilities: Nl80211BssCapabilities,
+        ssid: &str,
+        supported_rates: &[u8],
+        channel: u8,
+    ) -> Self {
+        let head =
+            beacon_head(src_mac, capabilities, ssid, supported_rates, channel);
+        self.attrs = self.attrs.replace(Nl80211Attr::BeaconHead(head));
+        self
+    }
+
+    /// Replace the beacon head using raw bytes
+    pub fn with_beacon_head_raw(mut self, beacon_head: &[u8]) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::BeaconHead(beacon_head.to_vec()));
+        self
+    }
+
+    /// Replace the beacon tail using high-level Information Elements
+    pub fn with_beacon_tail(mut self, elements: &Vec<Nl80211Element>) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::BeaconTail(elements_to_bytes(elements)));
+        self
+    }
+
+    /// Replace the beacon tail using raw bytes
+    pub fn with_beacon_tail_raw(mut self, elements: &[u8]) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::BeaconTail(elements.to_vec()));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211SetBeacon { mut handle, attrs } = self;
+
+        let message = Nl80211Message {
+            cmd: Nl80211Command::SetBeacon,
+            attributes: attrs.build(),
+        };
+
+        debug!("CMD_SET_BEACON message: {:#?}", message);
+
+        nl80211_execute(&mut handle, message, NLM_F_REQUEST | NLM_F_ACK).await
+    }
+}
+
+/// Struct to handle building and sending NL80211_CMD_GET_BEACON.
+/// Mainline kernels do not implement this command yet and reply
+/// `EOPNOTSUPP`, it is provided for drivers and kernels which do.
+pub struct Nl80211GetBeacon {
+    handle: Nl80211Handle,
+    message: Nl80211Message,
+}
+
+impl Nl80211GetBeacon {
+    pub fn new(handle: Nl80211Handle, ifindex: u32) -> Self {
+        Nl80211GetBeacon {
+            handle,
+            message: Nl80211Message {
+                cmd: Nl80211Command::GetBeacon,
+                attributes: vec![Nl80211Attr::IfIndex(ifindex)],
+            },
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211GetBeacon { mut handle, message } = self;
+
+        nl80211_execute(&mut handle, message, NLM_F_REQUEST | NLM_F_ACK).await
+    }
+
+    /// Execute the request and decode the reply
+    pub async fn reply(self) -> Result<Nl80211Beacon, Nl80211Error> {
+        let mut stream = Box::pin(self.execute().await);
+        match stream.try_next().await? {
+            Some(msg) => Nl80211Beacon::try_from(msg.payload),
+            None => Err(Nl80211Error::RequestFailed(
+                "No reply for NL80211_CMD_GET_BEACON".to_string(),
+            )),
+        }
+    }
+}
+
+/// Beacon data of a running AP, decoded from the reply of
+/// [Nl80211GetBeacon]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211Beacon {
+    pub if_index: Option<u32>,
+    /// Beacon head including the IEEE 802.11 header
+    pub head: Vec<u8>,
+    /// Information elements following the TIM element
+    pub tail: Vec<u8>,
+    pub ies: Option<Nl80211Elements>,
+    pub probe_resp_ies: Option<Nl80211Elements>,
+    pub assoc_resp_ies: Option<Nl80211Elements>,
+    /// All the attributes of the reply
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl Nl80211Beacon {
+    /// Beacon data built from raw beacon head and tail, e.g. for
+    /// [crate::Nl80211ChannelSwitchRequest]
+    pub fn new(head: Vec<u8>, tail: Vec<u8>) -> Self {
+        Self {
+            head,
+            tail,
+            ..Default::default()
+        }
+    }
+
+    /// Beacon data built from the same helpers as
+    /// [Nl80211StartAp::with_beacon_head()] and
+    /// [Nl80211StartAp::with_beacon_tail()]
+    pub fn from_elements(
+        src_mac: [u8; 6],
+        capabilities: Nl80211BssCapabilities,
+        ssid: &str,
+        supported_rates: &[u8],
+        channel: u8,
+        tail: &Vec<Nl80211Element>,
+    ) -> Self {
+        Self::new(
+            beacon_head(src_mac, capabilities, ssid, supported_rates, channel),
+            elements_to_bytes(tail),
+        )
+    }
+
+    /// IEs added to beacon, probe response and association response
+    pub fn with_ies(mut self, ies: Nl80211Elements) -> Self {
+        self.ies = Some(ies);
+        self
+    }
+
+    /// IEs added to probe response
+    pub fn with_probe_resp_ies(mut self, ies: Nl80211Elements) -> Self {
+        self.probe_resp_ies = Some(ies);
+        self
+    }
+
+    /// IEs added to association response
+    pub fn with_assoc_resp_ies(mut self, ies: Nl80211Elements) -> Self {
+        self.assoc_resp_ies = Some(ies);
+        self
+    }
+
+    /// The beacon data attributes used by kernel
+    pub(crate) fn to_attrs(&self) -> Vec<Nl80211Attr> {
+        let mut attrs = Vec::new();
+        if !self.head.is_empty() {
+            attrs.push(Nl80211Attr::BeaconHead(self.head.clone()));
+        }
+        if !self.tail.is_empty() {
+            attrs.push(Nl80211Attr::BeaconTail(self.tail.clone()));
+        }
+        if let Some(ies) = self.ies.as_ref() {
+            attrs.push(Nl80211Attr::Ie(ies.clone()));
+        }
+        if let Some(ies) = self.probe_resp_ies.as_ref() {
+            attrs.push(Nl80211Attr::IeProbeResp(ies.clone()));
+        }
+        if let Some(ies) = self.assoc_resp_ies.as_ref() {
+            attrs.push(Nl80211Attr::IeAssocResp(ies.clone()));
+        }
+        attrs
+    }
+
+    /// Decode the beacon head and tail into a management frame
+    pub fn frame(&self) -> Result<Nl80211MgmtFrame, DecodeError> {
+        let mut frame = self.head.clone();
+        frame.extend_from_slice(&self.tail);
+        Nl80211MgmtFrame::parse(&frame)
+    }
+}
+
+impl TryFrom<Nl80211Message> for Nl80211Beacon {
+    type Error = Nl80211Error;
+
+    fn try_from(msg: Nl80211Message) -> Result<Self, Self::Error> {
+        if msg.cmd != Nl80211Command::GetBeacon {
+            return Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                format!(
+                    "Expecting NL80211_CMD_GET_BEACON for beacon, got {:?}",
+                    msg.cmd
+                ),
+            )));
+        }
+        let mut ret = Self::default();
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::BeaconHead(d) => ret.head = d.clone(),
+                Nl80211Attr::BeaconTail(d) => ret.tail = d.clone(),
+                Nl80211Attr::Ie(d) => ret.ies = Some(d.clone()),
+                Nl80211Attr::IeProbeResp(d) => {
+                    ret.probe_resp_ies = Some(d.clone())
+                }
+                Nl80211Attr::IeAssocResp(d) => {
+                    ret.assoc_resp_ies = Some(d.clone())
+                }
+                _ => (),
+            }
+        }
+        ret.attributes = msg.attributes;
+        Ok(ret)
+    }
+}
+
+/// Build the beacon head: IEEE 802.11 header, fixed fields and the SSID,
+/// Supported Rates and DS Parameter Set elements. An empty `ssid` hides
+/// the SSID from beacons.
+fn beacon_head(
+    src_mac: [u8; 6],
+    capabilities: Nl80211BssCapabilities,
+    ssid: &str,
+    supported_rates: &[u8],
+    channel: u8,
+) -> Vec<u8> {
+    Nl80211MgmtFrame::new(
+        Nl80211FrameTypeMgmt::Beacon,
+        [0xff; 6],
+        src_mac,
+        src_mac,
+        Nl80211MgmtBody::Beacon {
+            // Filled by driver
+            timestamp: 0,
+            beacon_interval: DEFAULT_BEACON_INTERVAL,
+            capabilities,
+            elements: vec![
+                Nl80211Element::Ssid(ssid.to_string()),
+                // Kept raw to preserve the basic rate bit of each rate
+                Nl80211Element::Other(
+                    ELEMENT_ID_SUPPORTED_RATES,
+                    supported_rates.to_vec(),
+                ),
+                Nl80211Element::Channel(channel),
+            ],
+        },
+    )
+    .to_bytes()
+}
+
+fn elements_to_bytes(elements: &Vec<Nl80211Element>) -> Vec<u8> {
+    let elements = Nl80211Elements::from(elements);
+    let mut buffer = vec![0u8; elements.buffer_len()];
+    elements.emit(&mut buffer);
+    buffer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beacon_head_layout() {
+        let mac = [0x02, 0, 0, 0, 0, 0x01];
+        let head = beacon_head(
+            mac,
+            Nl80211BssCapabilities::Ess,
+            "ap",
+            &[0x82, 0x84, 0x8b, 0x96],
+            6,
+        );
+
+        let mut expected = vec![0x80, 0, 0, 0];
+        expected.extend_from_slice(&[0xff; 6]);
+        expected.extend_from_slice(&mac);
+        expected.extend_from_slice(&mac);
+        // Sequence control, timestamp, beacon interval, capabilities
+        expected.extend_from_slice(&[0; 10]);
+        expected.extend_from_slice(&[100, 0, 0x01, 0]);
+        expected.extend_from_slice(&[0, 2, b'a', b'p']);
+        expected.extend_from_slice(&[1, 4, 0x82, 0x84, 0x8b, 0x96]);
+        expected.extend_from_slice(&[3, 1, 6]);
+        assert_eq!(head, expected);
+    }
+}
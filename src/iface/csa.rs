@@ -0,0 +1,287 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{future::Either, stream, TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    chandef::impl_chandef_helpers, nl80211_execute, Nl80211Attr,
+    Nl80211AttrsBuilder, Nl80211Beacon, Nl80211Chandef, Nl80211ChannelWidth,
+    Nl80211Command, Nl80211Error, Nl80211Handle, Nl80211HtWiphyChannelType,
+    Nl80211Message,
+};
+
+const ELEMENT_ID_CHANNEL_SWITCH: u8 = 37;
+const ELEMENT_ID_EXT_CHANNEL_SWITCH: u8 = 60;
+
+// Offset of the switch count field in the element body
+const CHANNEL_SWITCH_COUNT_OFFSET: usize = 2;
+const EXT_CHANNEL_SWITCH_COUNT_OFFSET: usize = 3;
+
+/// Announce and perform a channel switch (`NL80211_CMD_CHANNEL_SWITCH`) on
+/// AP, IBSS or mesh interface, e.g. to vacate a DFS channel after radar
+/// detected without dropping the clients.
+///
+/// The target channel is set by the same helpers as
+/// [crate::Nl80211InterfaceSetChannelRequest]. The beacon used during the
+/// switch should contain the Channel Switch Announcement element (ID 37)
+/// and optionally the Extended Channel Switch Announcement element
+/// (ID 60), their counter offsets are computed from it.
+pub struct Nl80211ChannelSwitchRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211ChannelSwitchRequest>,
+    count: u32,
+    chandef: Option<Nl80211Chandef>,
+    beacon_after: Option<Nl80211Beacon>,
+    beacon_csa: Option<Nl80211Beacon>,
+}
+
+impl Nl80211ChannelSwitchRequest {
+    /// Switch after `count` beacon intervals (TBTTs), `0` means switch
+    /// immediately
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        count: u32,
+    ) -> Self {
+        Nl80211ChannelSwitchRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new().if_index(if_index),
+            count,
+            chandef: None,
+            beacon_after: None,
+            beacon_csa: None,
+        }
+    }
+
+    /// Switch to the channel definition, see [Nl80211Chandef]
+    pub fn as_chandef(mut self, chandef: &Nl80211Chandef) -> Self {
+        self.chandef = Some(*chandef);
+        self
+    }
+
+    /// Block transmission until the switch completed, i.e. the channel
+    /// switch mode field of the announcement is 1
+    pub fn with_block_tx(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::ChSwitchBlockTx);
+        self
+    }
+
+    /// Beacon data used once switched to the new channel
+    pub fn with_beacon_after(mut self, beacon: &Nl80211Beacon) -> Self {
+        self.beacon_after = Some(beacon.clone());
+        self
+    }
+
+    /// Beacon data used during the switch, the counter offsets are
+    /// computed from the channel switch elements in the beacon tail, whose
+    /// switch count fields must hold the `count` of the request.
+    pub fn with_csa_beacon(mut self, beacon: &Nl80211Beacon) -> Self {
+        self.beacon_csa = Some(beacon.clone());
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211ChannelSwitchRequest {
+            mut handle,
+            mut attrs,
+            count,
+            chandef,
+            beacon_after,
+            beacon_csa,
+        } = self;
+
+        attrs = attrs.replace(Nl80211Attr::ChSwitchCount(count));
+        if let Some(beacon) = beacon_csa.as_ref() {
+            if let Err(e) = check_countdown(beacon, count) {
+                return Either::Right(stream::once(futures::future::err(e)));
+            }
+            attrs = attrs.replace(Nl80211Attr::CsaIes(csa_ies(beacon)));
+        }
+        for attr in chandef.iter().flat_map(|c| c.to_attrs()) {
+            attrs = attrs.replace(attr);
+        }
+        for attr in beacon_after.iter().flat_map(|b| b.to_attrs()) {
+            attrs = attrs.replace(attr);
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::ChannelSwitch,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        Either::Left(
+            nl80211_execute(&mut handle, nl80211_msg, flags)
+                .await
+                .into_stream(),
+        )
+    }
+}
+
+impl_chandef_helpers!(Nl80211ChannelSwitchRequest, "Switch to");
+
+/// Check the switch count fields of the channel switch elements in the
+/// beacon tail hold the `count` of the request, as kernel does not
+/// overwrite them before the first beacon
+fn check_countdown(
+    beacon: &Nl80211Beacon,
+    count: u32,
+) -> Result<(), Nl80211Error> {
+    let offsets = countdown_offsets(&beacon.tail);
+    if offsets.is_empty() {
+        return Err(Nl80211Error::RequestFailed(
+            "No (Extended) Channel Switch Announcement element in the tail \
+            of the CSA beacon"
+                .to_string(),
+        ));
+    }
+    for offset in offsets {
+        let found = beacon.tail[offset as usize];
+        if u32::from(found) != count {
+            return Err(Nl80211Error::RequestFailed(format!(
+                "Switch count {found} at offset {offset} of the CSA beacon \
+                tail does not match the requested count {count}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Nested attributes of `NL80211_ATTR_CSA_IES`, the beacon data along with
+/// the counter offsets in its tail
+fn csa_ies(beacon: &Nl80211Beacon) -> Vec<Nl80211Attr> {
+    let mut ret = beacon.to_attrs();
+    let offsets = countdown_offsets(&beacon.tail);
+    if !offsets.is_empty() {
+        ret.push(Nl80211Attr::CntdwnOffsBeacon(offsets));
+    }
+    ret
+}
+
+/// Offsets of the switch count fields of the (Extended) Channel Switch
+/// Announcement elements in the IEs
+fn countdown_offsets(ies: &[u8]) -> Vec<u16> {
+    let mut ret = Vec::new();
+    let mut offset = 0;
+    while offset + 2 <= ies.len() {
+        let id = ies[offset];
+        let len = ies[offset + 1] as usize;
+        let count_offset = match id {
+            ELEMENT_ID_CHANNEL_SWITCH => Some(CHANNEL_SWITCH_COUNT_OFFSET),
+            ELEMENT_ID_EXT_CHANNEL_SWITCH => {
+                Some(EXT_CHANNEL_SWITCH_COUNT_OFFSET)
+            }
+            _ => None,
+        };
+        if let Some(count_offset) = count_offset {
+            if count_offset < len {
+                ret.push((offset + 2 + count_offset) as u16);
+            }
+        }
+        offset += 2 + len;
+    }
+    ret
+}
+
+/// Notification of `NL80211_CMD_CH_SWITCH_STARTED_NOTIFY` (channel switch
+/// announced) or `NL80211_CMD_CH_SWITCH_NOTIFY` (switched to the new
+/// channel), sent by kernel to the "mlme" multicast group.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211ChannelSwitchEvent {
+    pub wiphy: Option<u32>,
+    pub if_index: Option<u32>,
+    pub freq: Option<u32>,
+    pub channel_width: Option<Nl80211ChannelWidth>,
+    pub channel_type: Option<Nl80211HtWiphyChannelType>,
+    pub center_freq1: Option<u32>,
+    pub center_freq2: Option<u32>,
+    /// Number of TBTTs until the switch, only included when started
+    pub count: Option<u32>,
+    /// Transmission is blocked until the switch, only included when
+    /// started
+    pub block_tx: bool,
+    pub link_id: Option<u8>,
+    /// All the attributes of the event
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl From<Vec<Nl80211Attr>> for Nl80211ChannelSwitchEvent {
+    fn from(attributes: Vec<Nl80211Attr>) -> Self {
+        let mut ret = Self::default();
+        for attr in attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::WiphyFreq(d) => ret.freq = Some(*d),
+                Nl80211Attr::ChannelWidth(d) => ret.channel_width = Some(*d),
+                Nl80211Attr::WiphyChannelType(d) => ret.channel_type = Some(*d),
+                Nl80211Attr::CenterFreq1(d) => ret.center_freq1 = Some(*d),
+                Nl80211Attr::CenterFreq2(d) => ret.center_freq2 = Some(*d),
+                Nl80211Attr::ChSwitchCount(d) => ret.count = Some(*d),
+                Nl80211Attr::ChSwitchBlockTx => ret.block_tx = true,
+                Nl80211Attr::MloLinkId(d) => ret.link_id = Some(*d),
+                _ => (),
+            }
+        }
+        ret.attributes = attributes;
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn countdown_offsets_in_beacon_tail() {
+        // The head is not searched, even if it looks like a CSA element
+        let head = vec![ELEMENT_ID_CHANNEL_SWITCH, 3, 1, 36, 9];
+        // Extended supported rates
+        let mut tail = vec![50, 2, 0x0c, 0x12];
+        // Channel switch announcement: mode, new channel, count
+        tail.extend_from_slice(&[ELEMENT_ID_CHANNEL_SWITCH, 3, 1, 36, 5]);
+        // Extended channel switch announcement: mode, operating class, new
+        // channel, count
+        let ecsa = [ELEMENT_ID_EXT_CHANNEL_SWITCH, 4, 1, 115, 36, 5];
+        tail.extend_from_slice(&ecsa);
+        let beacon = Nl80211Beacon::new(head, tail.clone());
+
+        let csa_ies = csa_ies(&beacon);
+        assert!(csa_ies.contains(&Nl80211Attr::BeaconTail(tail.clone())));
+        assert!(csa_ies.contains(&Nl80211Attr::CntdwnOffsBeacon(vec![8, 14])));
+        assert_eq!(tail[8], 5);
+        assert_eq!(tail[14], 5);
+    }
+
+    #[test]
+    fn check_countdown_against_count() {
+        let mut tail = vec![ELEMENT_ID_CHANNEL_SWITCH, 3, 1, 36, 5];
+        tail.extend_from_slice(&[ELEMENT_ID_EXT_CHANNEL_SWITCH, 4, 1, 115]);
+        tail.extend_from_slice(&[36, 5]);
+        let beacon = Nl80211Beacon::new(vec![], tail.clone());
+        assert!(check_countdown(&beacon, 5).is_ok());
+        assert!(check_countdown(&beacon, 4).is_err());
+        assert!(check_countdown(&beacon, 0x105).is_err());
+
+        // Extended announcement disagreeing with the first one
+        tail[10] = 4;
+        let beacon = Nl80211Beacon::new(vec![], tail);
+        assert!(check_countdown(&beacon, 5).is_err());
+
+        // Without any announcement
+        let beacon = Nl80211Beacon::new(vec![], vec![50, 2, 0x0c, 0x12]);
+        assert!(check_countdown(&beacon, 5).is_err());
+    }
+
+    #[test]
+    fn countdown_offsets_skip_short_element() {
+        let tail = vec![ELEMENT_ID_EXT_CHANNEL_SWITCH, 3, 1, 115, 36];
+        assert!(countdown_offsets(&tail).is_empty());
+    }
+}
@@ -1,12 +1,14 @@
 // SPDX-License-Identifier: MIT
 
-use futures::TryStream;
-use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+use std::convert::TryFrom;
+
+use futures::{future::ready, TryStream, TryStreamExt};
 use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
 use crate::{
-    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle, Nl80211Message
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Interface, Nl80211Message,
 };
 
 pub struct Nl80211InterfaceGetRequest {
@@ -31,11 +33,20 @@ impl Nl80211InterfaceGetRequest {
     {
         let Nl80211InterfaceGetRequest { mut handle, message } = self;
 
-        let flags = NLM_F_REQUEST;
+        let flags = request_flags(&message.attributes);
 
         nl80211_execute(&mut handle, message, flags).await
     }
 
+    /// Execute the request and decode the replies into [Nl80211Interface]
+    pub async fn collect(self) -> Result<Vec<Nl80211Interface>, Nl80211Error> {
+        self.execute()
+            .await
+            .and_then(|msg| ready(Nl80211Interface::try_from(msg.payload)))
+            .try_collect()
+            .await
+    }
+
     /// Lookup a interface by index
     pub fn match_index(mut self, index: u32) -> Self {
         self.message.attributes.push(Nl80211Attr::IfIndex(index));
@@ -49,3 +60,28 @@ impl Nl80211InterfaceGetRequest {
     }
     
 }
+
+// Kernel rejects `NL80211_CMD_GET_INTERFACE` without interface specified
+// unless dumping all interfaces
+fn request_flags(attrs: &[Nl80211Attr]) -> u16 {
+    if attrs.is_empty() {
+        NLM_F_REQUEST | NLM_F_DUMP
+    } else {
+        NLM_F_REQUEST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump_without_interface() {
+        assert_eq!(request_flags(&[]), NLM_F_REQUEST | NLM_F_DUMP);
+        assert_eq!(request_flags(&[Nl80211Attr::IfIndex(3)]), NLM_F_REQUEST);
+        assert_eq!(
+            request_flags(&[Nl80211Attr::IfName("wlan0".to_string())]),
+            NLM_F_REQUEST
+        );
+    }
+}
@@ -1,8 +1,32 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{Nl80211FrameType, Nl80211Handle, Nl80211InterfaceGetRequest};
+use std::time::Duration;
 
-use super::{ap::Nl80211RegisterFrame, set::{Nl80211InterfaceSetChannelRequest, Nl80211InterfaceSetRequest}};
+use crate::{
+    Nl80211AssociateRequest, Nl80211AuthType, Nl80211AuthenticateRequest,
+    Nl80211CancelRemainOnChannelRequest, Nl80211ChannelSwitchRequest,
+    Nl80211ConnectRequest, Nl80211DeauthenticateRequest,
+    Nl80211DisassociateRequest, Nl80211DisconnectRequest,
+    Nl80211FrameTxRequest, Nl80211FrameType, Nl80211FrameWaitCancelRequest,
+    Nl80211Handle, Nl80211InterfaceDelRequest, Nl80211InterfaceGetRequest,
+    Nl80211InterfaceNewRequest, Nl80211InterfaceType,
+    Nl80211RadarDetectRequest, Nl80211ReasonCode,
+    Nl80211RegisterFramesRequest, Nl80211RemainOnChannelRequest,
+    Nl80211SetCqmRequest,
+};
+#[cfg(feature = "tokio_socket")]
+use crate::Nl80211ChannelHopper;
+
+const ETH_ALEN: usize = 6;
+
+use super::{
+    ap::{
+        Nl80211GetBeacon, Nl80211RegisterFrame, Nl80211SetBeacon,
+        Nl80211StopAp,
+    },
+    power_save::Nl80211PowerSaveHandle,
+    set::{Nl80211InterfaceSetChannelRequest, Nl80211InterfaceSetRequest},
+};
 
 pub struct Nl80211InterfaceHandle(Nl80211Handle);
 
@@ -17,6 +41,127 @@ impl Nl80211InterfaceHandle {
         Nl80211InterfaceGetRequest::new(self.0.clone())
     }
 
+    /// Create a new virtual interface on the same wiphy of specified
+    /// interface index
+    /// (equivalent to `iw dev DEVICE interface add NAME type TYPE`)
+    pub fn add(
+        &mut self,
+        if_index: u32,
+        name: String,
+        iftype: Nl80211InterfaceType,
+    ) -> Nl80211InterfaceNewRequest {
+        Nl80211InterfaceNewRequest::new(self.0.clone(), if_index, name, iftype)
+    }
+
+    /// Create a new virtual interface on specified wiphy index
+    /// (equivalent to `iw phy PHY interface add NAME type TYPE`)
+    pub fn add_to_wiphy(
+        &mut self,
+        wiphy: u32,
+        name: String,
+        iftype: Nl80211InterfaceType,
+    ) -> Nl80211InterfaceNewRequest {
+        Nl80211InterfaceNewRequest::new_on_wiphy(
+            self.0.clone(),
+            wiphy,
+            name,
+            iftype,
+        )
+    }
+
+    /// Delete the virtual interface
+    /// (equivalent to `iw dev DEVICE del`)
+    pub fn del(&mut self, if_index: u32) -> Nl80211InterfaceDelRequest {
+        Nl80211InterfaceDelRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Connect to specified SSID with SME handled by kernel or driver
+    /// (equivalent to `iw dev DEVICE connect SSID`)
+    pub fn connect(
+        &mut self,
+        if_index: u32,
+        ssid: String,
+    ) -> Nl80211ConnectRequest {
+        Nl80211ConnectRequest::new(self.0.clone(), if_index, ssid)
+    }
+
+    /// Disconnect from current network
+    /// (equivalent to `iw dev DEVICE disconnect`)
+    pub fn disconnect(
+        &mut self,
+        if_index: u32,
+        reason: Nl80211ReasonCode,
+    ) -> Nl80211DisconnectRequest {
+        Nl80211DisconnectRequest::new(self.0.clone(), if_index, reason)
+    }
+
+    /// Authenticate with specified BSS, SME handled by userspace
+    /// (equivalent to `iw dev DEVICE auth SSID BSSID TYPE FREQ`)
+    pub fn authenticate(
+        &mut self,
+        if_index: u32,
+        bssid: [u8; ETH_ALEN],
+        freq: u32,
+        ssid: String,
+        auth_type: Nl80211AuthType,
+    ) -> Nl80211AuthenticateRequest {
+        Nl80211AuthenticateRequest::new(
+            self.0.clone(),
+            if_index,
+            bssid,
+            freq,
+            ssid,
+            auth_type,
+        )
+    }
+
+    /// (Re)associate with specified BSS, SME handled by userspace
+    pub fn associate(
+        &mut self,
+        if_index: u32,
+        bssid: [u8; ETH_ALEN],
+        freq: u32,
+        ssid: String,
+    ) -> Nl80211AssociateRequest {
+        Nl80211AssociateRequest::new(
+            self.0.clone(),
+            if_index,
+            bssid,
+            freq,
+            ssid,
+        )
+    }
+
+    /// Deauthenticate from specified BSS, SME handled by userspace
+    pub fn deauthenticate(
+        &mut self,
+        if_index: u32,
+        bssid: [u8; ETH_ALEN],
+        reason: Nl80211ReasonCode,
+    ) -> Nl80211DeauthenticateRequest {
+        Nl80211DeauthenticateRequest::new(
+            self.0.clone(),
+            if_index,
+            bssid,
+            reason,
+        )
+    }
+
+    /// Disassociate from specified BSS, SME handled by userspace
+    pub fn disassociate(
+        &mut self,
+        if_index: u32,
+        bssid: [u8; ETH_ALEN],
+        reason: Nl80211ReasonCode,
+    ) -> Nl80211DisassociateRequest {
+        Nl80211DisassociateRequest::new(
+            self.0.clone(),
+            if_index,
+            bssid,
+            reason,
+        )
+    }
+
     /// Set wireless interface info
     /// capable of setting monitor, station, other type, or mac:
     /// .with_mon()
@@ -27,6 +172,18 @@ impl Nl80211InterfaceHandle {
         Nl80211InterfaceSetRequest::new(self.0.clone(), index)
     }
 
+    /// Enable, disable or retrieve 802.11 power save of the interface
+    /// (equivalent to `iw dev DEVICE set/get power_save`)
+    pub fn power_save(&mut self, if_index: u32) -> Nl80211PowerSaveHandle {
+        Nl80211PowerSaveHandle::new(self.0.clone(), if_index)
+    }
+
+    /// Configure the connection quality monitor of connected station
+    /// (equivalent to `iw dev DEVICE cqm rssi THRESHOLD HYSTERESIS`)
+    pub fn cqm(&mut self, if_index: u32) -> Nl80211SetCqmRequest {
+        Nl80211SetCqmRequest::new(self.0.clone(), if_index)
+    }
+
     /// Set wireless interface info
     /// (equivalent to `iw dev set channel`)
     /// as_channel(freq: u32) // 20Mhz Channel
@@ -40,10 +197,124 @@ impl Nl80211InterfaceHandle {
         Nl80211InterfaceSetChannelRequest::new(self.0.clone(), index)
     }
 
+    /// Periodically switch the channel of the monitor interface through
+    /// the frequencies given by [Nl80211ChannelHopper::with_freqs()] or
+    /// [Nl80211ChannelHopper::with_wiphy()]
+    #[cfg(feature = "tokio_socket")]
+    pub fn channel_hopper(&mut self, index: u32) -> Nl80211ChannelHopper {
+        Nl80211ChannelHopper::new(self.0.clone(), index)
+    }
+
     /// Register a Frame Type to recieve over the unsolicited socket
-    /// an example of this would be if establishing an access point
-    pub fn register_frame(&mut self, index: u32, frame_type: Nl80211FrameType) -> Nl80211RegisterFrame {
-        Nl80211RegisterFrame::new(self.0.clone(), index, frame_type, vec![0x00])
+    /// an example of this would be if establishing an access point.
+    /// The frame match is the leading bytes of the frame body to match,
+    /// e.g. the category (and action code) of action frames, empty for
+    /// all frames of the type.
+    pub fn register_frame(
+        &mut self,
+        index: u32,
+        frame_type: Nl80211FrameType,
+        frame_match: Vec<u8>,
+    ) -> Nl80211RegisterFrame {
+        Nl80211RegisterFrame::new(
+            self.0.clone(),
+            index,
+            frame_type,
+            frame_match,
+        )
+    }
+
+    /// Register several frame types with their frame matches on a
+    /// dedicated socket, the matching frames are received from the
+    /// returned stream
+    pub fn register_frames(
+        &mut self,
+        index: u32,
+        frames: &[(Nl80211FrameType, Vec<u8>)],
+    ) -> Nl80211RegisterFramesRequest {
+        Nl80211RegisterFramesRequest::new(self.0.clone(), index, frames)
+    }
+
+    /// Transmit a management frame, the frame should include the IEEE
+    /// 802.11 header
+    pub fn frame_tx(
+        &mut self,
+        if_index: u32,
+        frame: Vec<u8>,
+    ) -> Nl80211FrameTxRequest {
+        Nl80211FrameTxRequest::new(self.0.clone(), if_index, frame)
+    }
+
+    /// Stop waiting for response of frame transmitted with a wait duration
+    pub fn frame_wait_cancel(
+        &mut self,
+        if_index: u32,
+        cookie: u64,
+    ) -> Nl80211FrameWaitCancelRequest {
+        Nl80211FrameWaitCancelRequest::new(self.0.clone(), if_index, cookie)
+    }
+
+    /// Stop the AP started by [crate::Nl80211StartAp]
+    /// (equivalent to `iw dev DEVICE ap stop`)
+    pub fn stop_ap(&mut self, if_index: u32) -> Nl80211StopAp {
+        Nl80211StopAp::new(self.0.clone(), if_index)
+    }
+
+    /// Update the beacon and probe response data of a running AP
+    pub fn set_beacon(&mut self, if_index: u32) -> Nl80211SetBeacon {
+        Nl80211SetBeacon::new(self.0.clone(), if_index)
+    }
+
+    /// Query the beacon data of a running AP
+    pub fn get_beacon(&mut self, if_index: u32) -> Nl80211GetBeacon {
+        Nl80211GetBeacon::new(self.0.clone(), if_index)
+    }
+
+    /// Announce switching to another channel after `count` beacon
+    /// intervals, set the target channel by the `as_*_channel()` helpers
+    /// (equivalent to `iw dev DEVICE switch freq FREQ [beacons COUNT]`)
+    pub fn channel_switch(
+        &mut self,
+        if_index: u32,
+        count: u32,
+    ) -> Nl80211ChannelSwitchRequest {
+        Nl80211ChannelSwitchRequest::new(self.0.clone(), if_index, count)
+    }
+
+    /// Start the channel availability check (CAC) on a DFS channel, set
+    /// the channel by the `as_*_channel()` helpers
+    /// (equivalent to `iw dev DEVICE cac trigger freq FREQ`)
+    pub fn radar_detect(&mut self, if_index: u32) -> Nl80211RadarDetectRequest {
+        Nl80211RadarDetectRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Stay on specified frequency for the duration, the duration is
+    /// capped by the wiphy when started
+    /// (equivalent to `iw dev DEVICE offchannel FREQ DURATION`)
+    pub fn remain_on_channel(
+        &mut self,
+        if_index: u32,
+        freq: u32,
+        duration: Duration,
+    ) -> Nl80211RemainOnChannelRequest {
+        Nl80211RemainOnChannelRequest::new(
+            self.0.clone(),
+            if_index,
+            freq,
+            duration,
+        )
+    }
+
+    /// Cancel remain-on-channel identified by the cookie
+    pub fn cancel_remain_on_channel(
+        &mut self,
+        if_index: u32,
+        cookie: u64,
+    ) -> Nl80211CancelRemainOnChannelRequest {
+        Nl80211CancelRemainOnChannelRequest::new(
+            self.0.clone(),
+            if_index,
+            cookie,
+        )
     }
-    
 }
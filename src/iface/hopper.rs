@@ -0,0 +1,501 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use futures::TryStreamExt;
+use tokio::{
+    sync::{mpsc, watch},
+    task::JoinHandle,
+};
+
+use crate::{
+    Nl80211Band, Nl80211BandInfo, Nl80211Chandef, Nl80211ChannelWidth,
+    Nl80211Error, Nl80211FrequencyInfo, Nl80211Handle,
+    Nl80211HtWiphyChannelType, Nl80211Wiphy,
+};
+
+use super::set::Nl80211InterfaceSetChannelRequest;
+
+const DEFAULT_DWELL: Duration = Duration::from_millis(250);
+// Failed hops not yet received are dropped (with a warning) once this many
+// are queued
+const MAX_PENDING_FAILURES: usize = 64;
+
+/// Channel width used by [Nl80211ChannelHopper] on each hop
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum Nl80211ChannelHopWidth {
+    /// 20MHz without HT
+    #[default]
+    NoHt,
+    /// HT 20MHz
+    Ht20,
+    /// HT40 with the secondary channel above the control channel
+    Ht40Plus,
+    /// HT40 with the secondary channel below the control channel
+    Ht40Minus,
+    /// HT40 with the secondary channel allowed by the channel plan and the
+    /// wiphy frequency flags, HT40+ preferred, HT 20MHz used when neither
+    /// is allowed. Hops given by [Nl80211ChannelHopper::with_freqs] have
+    /// no flags, hence only the channel plan applies to them.
+    Ht40,
+    /// VHT 80MHz channel of the 5 or 6 GHz channel plan containing the
+    /// control channel, [Nl80211ChannelHopWidth::Ht40] used when there is
+    /// none or the frequency is flagged [Nl80211FrequencyInfo::No80Mhz]
+    Vht80,
+    /// VHT 160MHz channel of the 5 or 6 GHz channel plan containing the
+    /// control channel, [Nl80211ChannelHopWidth::Vht80] used when there is
+    /// none or the frequency is flagged [Nl80211FrequencyInfo::No160Mhz]
+    Vht160,
+    /// EHT 320MHz channel of the 6 GHz channel plan containing the control
+    /// channel, [Nl80211ChannelHopWidth::Vht160] used when there is none
+    /// or the frequency is flagged [Nl80211FrequencyInfo::No320Mhz]
+    Eht320,
+}
+
+/// Channel the monitor interface has been set to
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub struct Nl80211ChannelHop {
+    /// Control frequency in MHz
+    pub freq: u32,
+    /// Width and center frequencies of the channel, the width is
+    /// [Nl80211ChannelWidth::NoHt20] for [Nl80211ChannelHopWidth::NoHt]
+    pub chandef: Nl80211Chandef,
+}
+
+/// Hop the kernel failed to switch to
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Nl80211ChannelHopFailure {
+    pub hop: Nl80211ChannelHop,
+    pub error: Nl80211Error,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct HopFreq {
+    freq: u32,
+    restricted: bool,
+    no_ht40_plus: bool,
+    no_ht40_minus: bool,
+    no_80mhz: bool,
+    no_160mhz: bool,
+    no_320mhz: bool,
+}
+
+impl HopFreq {
+    fn hop(&self, width: Nl80211ChannelHopWidth) -> Nl80211ChannelHop {
+        Nl80211ChannelHop {
+            freq: self.freq,
+            chandef: self.chandef(width),
+        }
+    }
+
+    fn chandef(&self, width: Nl80211ChannelHopWidth) -> Nl80211Chandef {
+        let freq = self.freq;
+        match width {
+            Nl80211ChannelHopWidth::NoHt => Nl80211Chandef::from_freqs(
+                freq,
+                Nl80211ChannelWidth::NoHt20,
+                freq,
+                None,
+            ),
+            Nl80211ChannelHopWidth::Ht20 => Nl80211Chandef::from_freqs(
+                freq,
+                Nl80211ChannelWidth::Mhz(20),
+                freq,
+                None,
+            ),
+            Nl80211ChannelHopWidth::Ht40Plus => Nl80211Chandef::from_freqs(
+                freq,
+                Nl80211ChannelWidth::Mhz(40),
+                freq + 10,
+                None,
+            ),
+            Nl80211ChannelHopWidth::Ht40Minus => Nl80211Chandef::from_freqs(
+                freq,
+                Nl80211ChannelWidth::Mhz(40),
+                freq - 10,
+                None,
+            ),
+            Nl80211ChannelHopWidth::Ht40 => [
+                (self.no_ht40_plus, Nl80211HtWiphyChannelType::Ht40Plus),
+                (self.no_ht40_minus, Nl80211HtWiphyChannelType::Ht40Minus),
+            ]
+            .into_iter()
+            .filter(|(prohibited, _)| !prohibited)
+            .find_map(|(_, t)| Nl80211Chandef::new_ht40(freq, t).ok())
+            .unwrap_or_else(|| self.chandef(Nl80211ChannelHopWidth::Ht20)),
+            Nl80211ChannelHopWidth::Vht80 => self
+                .wide(80, self.no_80mhz)
+                .unwrap_or_else(|| self.chandef(Nl80211ChannelHopWidth::Ht40)),
+            Nl80211ChannelHopWidth::Vht160 => self
+                .wide(160, self.no_160mhz)
+                .unwrap_or_else(|| self.chandef(Nl80211ChannelHopWidth::Vht80)),
+            Nl80211ChannelHopWidth::Eht320 => {
+                self.wide(320, self.no_320mhz).unwrap_or_else(|| {
+                    self.chandef(Nl80211ChannelHopWidth::Vht160)
+                })
+            }
+        }
+    }
+
+    fn wide(&self, mhz: u32, prohibited: bool) -> Option<Nl80211Chandef> {
+        if prohibited {
+            None
+        } else {
+            Nl80211Chandef::new(self.freq, Nl80211ChannelWidth::Mhz(mhz)).ok()
+        }
+    }
+}
+
+/// Periodically switch the channel (`NL80211_CMD_SET_CHANNEL`) of a
+/// monitor interface through a list of frequencies.
+/// Created by [crate::Nl80211InterfaceHandle::channel_hopper()].
+pub struct Nl80211ChannelHopper {
+    handle: Nl80211Handle,
+    if_index: u32,
+    freqs: Vec<HopFreq>,
+    dwell: Duration,
+    width: Nl80211ChannelHopWidth,
+    skip_restricted: bool,
+}
+
+impl Nl80211ChannelHopper {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211ChannelHopper {
+            handle,
+            if_index,
+            freqs: Vec::new(),
+            dwell: DEFAULT_DWELL,
+            width: Nl80211ChannelHopWidth::default(),
+            skip_restricted: false,
+        }
+    }
+
+    /// Append the frequencies (MHz) to the hop list
+    pub fn with_freqs(mut self, freqs: &[u32]) -> Self {
+        self.freqs.extend(freqs.iter().map(|freq| HopFreq {
+            freq: *freq,
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Append the supported frequencies of the bands to the hop list
+    pub fn with_bands(mut self, bands: &[Nl80211Band]) -> Self {
+        for band in bands {
+            for info in band.info.as_slice() {
+                if let Nl80211BandInfo::Freqs(freqs) = info {
+                    self.freqs
+                        .extend(freqs.iter().filter_map(|f| hop_freq(&f.info)));
+                }
+            }
+        }
+        self
+    }
+
+    /// Append the supported frequencies of all bands of the wiphy to the
+    /// hop list
+    pub fn with_wiphy(self, wiphy: &Nl80211Wiphy) -> Self {
+        self.with_bands(&wiphy.bands)
+    }
+
+    /// Skip the frequencies flagged as [Nl80211FrequencyInfo::Disabled],
+    /// [Nl80211FrequencyInfo::NoIr] or [Nl80211FrequencyInfo::NoIbss] by
+    /// the current regulatory domain
+    pub fn skip_restricted(mut self) -> Self {
+        self.skip_restricted = true;
+        self
+    }
+
+    /// Time to stay on each channel, default is 250 milliseconds
+    pub fn with_dwell(mut self, dwell: Duration) -> Self {
+        self.dwell = dwell;
+        self
+    }
+
+    /// Channel width of each hop, default is
+    /// [Nl80211ChannelHopWidth::NoHt]
+    pub fn with_width(mut self, width: Nl80211ChannelHopWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// The hops in order, after skipping the restricted frequencies if
+    /// requested
+    pub fn hops(&self) -> Vec<Nl80211ChannelHop> {
+        hops(&self.freqs, self.width, self.skip_restricted)
+    }
+
+    /// Spawn the hopping task on the tokio runtime, it runs until the
+    /// returned [Nl80211ChannelHopperTask] is stopped or dropped.
+    pub fn start(self) -> Result<Nl80211ChannelHopperTask, Nl80211Error> {
+        let hops = self.hops();
+        if hops.is_empty() {
+            return Err(Nl80211Error::RequestFailed(format!(
+                "No channel to hop on interface {}",
+                self.if_index
+            )));
+        }
+        let (current_tx, current) = watch::channel(None);
+        let (failure_tx, failures) = mpsc::channel(MAX_PENDING_FAILURES);
+        let task = tokio::spawn(hop(
+            self.handle,
+            self.if_index,
+            hops,
+            self.dwell,
+            current_tx,
+            failure_tx,
+        ));
+        Ok(Nl80211ChannelHopperTask {
+            task,
+            current,
+            failures,
+        })
+    }
+}
+
+fn hops(
+    freqs: &[HopFreq],
+    width: Nl80211ChannelHopWidth,
+    skip_restricted: bool,
+) -> Vec<Nl80211ChannelHop> {
+    freqs
+        .iter()
+        .filter(|f| !(skip_restricted && f.restricted))
+        .map(|f| f.hop(width))
+        .collect()
+}
+
+fn hop_freq(infos: &[Nl80211FrequencyInfo]) -> Option<HopFreq> {
+    let mut ret = HopFreq::default();
+    for info in infos {
+        match info {
+            Nl80211FrequencyInfo::Freq(d) => ret.freq = *d,
+            Nl80211FrequencyInfo::Disabled
+            | Nl80211FrequencyInfo::NoIr
+            | Nl80211FrequencyInfo::NoIbss => ret.restricted = true,
+            Nl80211FrequencyInfo::NoHt40Plus => ret.no_ht40_plus = true,
+            Nl80211FrequencyInfo::NoHt40Minus => ret.no_ht40_minus = true,
+            Nl80211FrequencyInfo::No80Mhz => ret.no_80mhz = true,
+            Nl80211FrequencyInfo::No160Mhz => ret.no_160mhz = true,
+            Nl80211FrequencyInfo::No320Mhz => ret.no_320mhz = true,
+            _ => (),
+        }
+    }
+    if ret.freq == 0 {
+        None
+    } else {
+        Some(ret)
+    }
+}
+
+async fn hop(
+    handle: Nl80211Handle,
+    if_index: u32,
+    hops: Vec<Nl80211ChannelHop>,
+    dwell: Duration,
+    current: watch::Sender<Option<Nl80211ChannelHop>>,
+    failures: mpsc::Sender<Nl80211ChannelHopFailure>,
+) {
+    for hop in hops.iter().cycle() {
+        match set_channel(&handle, if_index, hop).await {
+            Ok(()) => {
+                current.send(Some(*hop)).ok();
+            }
+            Err(error) => {
+                log::warn!(
+                    "Failed to hop interface {if_index} to {hop:?}: {error}"
+                );
+                current.send(None).ok();
+                if let Err(mpsc::error::TrySendError::Full(_)) = failures
+                    .try_send(Nl80211ChannelHopFailure { hop: *hop, error })
+                {
+                    log::warn!(
+                        "Too many channel hop failures pending, dropping"
+                    );
+                }
+            }
+        }
+        tokio::time::sleep(dwell).await;
+    }
+}
+
+async fn set_channel(
+    handle: &Nl80211Handle,
+    if_index: u32,
+    hop: &Nl80211ChannelHop,
+) -> Result<(), Nl80211Error> {
+    let request =
+        Nl80211InterfaceSetChannelRequest::new(handle.clone(), if_index)
+            .as_chandef(&hop.chandef);
+    let mut stream = Box::pin(request.execute().await);
+    while stream.try_next().await?.is_some() {}
+    Ok(())
+}
+
+/// The running channel hopping task of [Nl80211ChannelHopper], hopping is
+/// stopped once this is dropped.
+pub struct Nl80211ChannelHopperTask {
+    task: JoinHandle<()>,
+    current: watch::Receiver<Option<Nl80211ChannelHop>>,
+    failures: mpsc::Receiver<Nl80211ChannelHopFailure>,
+}
+
+impl Nl80211ChannelHopperTask {
+    /// Subscribe to the current channel of the interface, `None` before
+    /// the first hop or after a failed hop
+    pub fn current(&self) -> watch::Receiver<Option<Nl80211ChannelHop>> {
+        self.current.clone()
+    }
+
+    /// Wait for the next hop the kernel failed to switch to
+    pub async fn next_failure(&mut self) -> Option<Nl80211ChannelHopFailure> {
+        self.failures.recv().await
+    }
+
+    /// Stop hopping, the interface stays on the current channel
+    pub fn stop(self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for Nl80211ChannelHopperTask {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hop_freqs(freqs: &[(u32, &[Nl80211FrequencyInfo])]) -> Vec<HopFreq> {
+        freqs
+            .iter()
+            .filter_map(|(freq, flags)| {
+                let mut infos = vec![Nl80211FrequencyInfo::Freq(*freq)];
+                infos.extend_from_slice(flags);
+                hop_freq(&infos)
+            })
+            .collect()
+    }
+
+    fn explicit(freq: u32) -> HopFreq {
+        HopFreq {
+            freq,
+            ..Default::default()
+        }
+    }
+
+    // Width and center frequency of the hop
+    fn channel(
+        freq: &HopFreq,
+        width: Nl80211ChannelHopWidth,
+    ) -> (Nl80211ChannelWidth, u32) {
+        let hop = freq.hop(width);
+        assert_eq!(hop.freq, freq.freq);
+        assert_eq!(hop.chandef.center_freq2, None);
+        (hop.chandef.width, hop.chandef.center_freq1)
+    }
+
+    #[test]
+    fn hops_skip_restricted() {
+        let freqs = hop_freqs(&[
+            (2412, &[]),
+            (2417, &[Nl80211FrequencyInfo::Disabled]),
+            (2422, &[Nl80211FrequencyInfo::NoIr]),
+            (2427, &[Nl80211FrequencyInfo::NoIbss]),
+            (2432, &[Nl80211FrequencyInfo::NoHt40Minus]),
+        ]);
+        assert!(hop_freq(&[Nl80211FrequencyInfo::NoIr]).is_none());
+
+        let all: Vec<u32> = hops(&freqs, Nl80211ChannelHopWidth::NoHt, false)
+            .iter()
+            .map(|h| h.freq)
+            .collect();
+        assert_eq!(all, vec![2412, 2417, 2422, 2427, 2432]);
+
+        let hops = hops(&freqs, Nl80211ChannelHopWidth::NoHt, true);
+        let allowed: Vec<u32> = hops.iter().map(|h| h.freq).collect();
+        assert_eq!(allowed, vec![2412, 2432]);
+        assert_eq!(hops[0].chandef.width, Nl80211ChannelWidth::NoHt20);
+    }
+
+    #[test]
+    fn ht40_follows_channel_plan() {
+        let ht40 = Nl80211ChannelHopWidth::Ht40;
+        let mhz40 = Nl80211ChannelWidth::Mhz(40);
+        assert_eq!(channel(&explicit(2412), ht40), (mhz40, 2422));
+        // 2.4 GHz channel 10 to 13 have no channel 4 above
+        assert_eq!(channel(&explicit(2457), ht40), (mhz40, 2447));
+        assert_eq!(channel(&explicit(2472), ht40), (mhz40, 2462));
+        // Channel 14 has no HT40 at all
+        assert_eq!(
+            channel(&explicit(2484), ht40),
+            (Nl80211ChannelWidth::Mhz(20), 2484)
+        );
+        // 5 GHz channel 40 is the upper half of the 36/40 channel
+        assert_eq!(channel(&explicit(5180), ht40), (mhz40, 5190));
+        assert_eq!(channel(&explicit(5200), ht40), (mhz40, 5190));
+    }
+
+    #[test]
+    fn ht40_follows_frequency_flags() {
+        let ht40 = Nl80211ChannelHopWidth::Ht40;
+        let freqs = hop_freqs(&[
+            (2437, &[Nl80211FrequencyInfo::NoHt40Plus]),
+            (
+                2442,
+                &[
+                    Nl80211FrequencyInfo::NoHt40Plus,
+                    Nl80211FrequencyInfo::NoHt40Minus,
+                ],
+            ),
+            // HT40- is not possible on channel 1 regardless of the flags
+            (2412, &[Nl80211FrequencyInfo::NoHt40Plus]),
+        ]);
+        let mhz40 = Nl80211ChannelWidth::Mhz(40);
+        let mhz20 = Nl80211ChannelWidth::Mhz(20);
+        assert_eq!(channel(&freqs[0], ht40), (mhz40, 2427));
+        assert_eq!(channel(&freqs[1], ht40), (mhz20, 2442));
+        assert_eq!(channel(&freqs[2], ht40), (mhz20, 2412));
+
+        // The fixed directions ignore both
+        assert_eq!(
+            channel(&freqs[1], Nl80211ChannelHopWidth::Ht40Plus),
+            (mhz40, 2452)
+        );
+        assert_eq!(
+            channel(&freqs[1], Nl80211ChannelHopWidth::Ht40Minus),
+            (mhz40, 2432)
+        );
+    }
+
+    #[test]
+    fn wide_channels_fall_back() {
+        let vht80 = Nl80211ChannelHopWidth::Vht80;
+        let vht160 = Nl80211ChannelHopWidth::Vht160;
+        let eht320 = Nl80211ChannelHopWidth::Eht320;
+        let mhz40 = Nl80211ChannelWidth::Mhz(40);
+        let mhz80 = Nl80211ChannelWidth::Mhz(80);
+        let mhz160 = Nl80211ChannelWidth::Mhz(160);
+        let mhz320 = Nl80211ChannelWidth::Mhz(320);
+
+        assert_eq!(channel(&explicit(5180), vht80), (mhz80, 5210));
+        assert_eq!(channel(&explicit(5180), vht160), (mhz160, 5250));
+        assert_eq!(channel(&explicit(5955), eht320), (mhz320, 6105));
+        // No 80 MHz channel on 2.4 GHz
+        assert_eq!(channel(&explicit(2412), vht80), (mhz40, 2422));
+        // No 320 MHz channel on 5 GHz
+        assert_eq!(channel(&explicit(5180), eht320), (mhz160, 5250));
+
+        let freqs = hop_freqs(&[
+            (5180, &[Nl80211FrequencyInfo::No80Mhz]),
+            (5200, &[Nl80211FrequencyInfo::No160Mhz]),
+        ]);
+        assert_eq!(channel(&freqs[0], vht80), (mhz40, 5190));
+        assert_eq!(channel(&freqs[1], vht160), (mhz80, 5210));
+    }
+}
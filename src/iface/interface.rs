@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    Nl80211Attr, Nl80211ChannelWidth, Nl80211Command, Nl80211Error,
+    Nl80211HtWiphyChannelType, Nl80211InterfaceType, Nl80211Message,
+    Nl80211MloLink, Nl80211TransmitQueueStat,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Wireless interface, decoded from the reply of
+/// [crate::Nl80211InterfaceGetRequest] (equivalent to one entry of `iw dev`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211Interface {
+    /// Network interface index, `None` for non-netdev interface like P2P
+    /// device
+    pub index: Option<u32>,
+    pub name: Option<String>,
+    pub iftype: Option<Nl80211InterfaceType>,
+    /// Index of wiphy this interface belongs to
+    pub wiphy: u32,
+    /// Wireless device identifier
+    pub wdev: u64,
+    pub mac: Option<[u8; ETH_ALEN]>,
+    pub generation: Option<u32>,
+    pub ssid: Option<String>,
+    /// Frequency in MHz of the operating channel
+    pub frequency: Option<u32>,
+    /// Frequency offset in KHz
+    pub frequency_offset: Option<u32>,
+    pub channel_type: Option<Nl80211HtWiphyChannelType>,
+    pub channel_width: Option<Nl80211ChannelWidth>,
+    pub center_freq1: Option<u32>,
+    pub center_freq2: Option<u32>,
+    /// Transmit power level in mBm (dBm * 100)
+    pub tx_power: Option<u32>,
+    pub use_4addr: bool,
+    pub txq_stats: Vec<Nl80211TransmitQueueStat>,
+    pub mlo_links: Vec<Nl80211MloLink>,
+}
+
+impl TryFrom<Nl80211Message> for Nl80211Interface {
+    type Error = Nl80211Error;
+
+    fn try_from(msg: Nl80211Message) -> Result<Self, Self::Error> {
+        if msg.cmd != Nl80211Command::NewInterface {
+            return Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                format!(
+                    "Expecting NL80211_CMD_NEW_INTERFACE for interface, \
+                    got {:?}",
+                    msg.cmd
+                ),
+            )));
+        }
+        let mut ret = Self::default();
+        let mut wiphy = None;
+        let mut wdev = None;
+        for attr in msg.attributes {
+            match attr {
+                Nl80211Attr::IfIndex(d) => ret.index = Some(d),
+                Nl80211Attr::IfName(s) => ret.name = Some(s),
+                Nl80211Attr::IfType(d) => ret.iftype = Some(d),
+                Nl80211Attr::Wiphy(d) => wiphy = Some(d),
+                Nl80211Attr::Wdev(d) => wdev = Some(d),
+                Nl80211Attr::Mac(d) => ret.mac = Some(d),
+                Nl80211Attr::Generation(d) => ret.generation = Some(d),
+                Nl80211Attr::Ssid(s) => ret.ssid = Some(s),
+                Nl80211Attr::WiphyFreq(d) => ret.frequency = Some(d),
+                Nl80211Attr::WiphyFreqOffset(d) => {
+                    ret.frequency_offset = Some(d)
+                }
+                Nl80211Attr::WiphyChannelType(d) => {
+                    ret.channel_type = Some(d)
+                }
+                Nl80211Attr::ChannelWidth(d) => ret.channel_width = Some(d),
+                Nl80211Attr::CenterFreq1(d) => ret.center_freq1 = Some(d),
+                Nl80211Attr::CenterFreq2(d) => ret.center_freq2 = Some(d),
+                Nl80211Attr::WiphyTxPowerLevel(d) => ret.tx_power = Some(d),
+                Nl80211Attr::Use4Addr(d) => ret.use_4addr = d,
+                Nl80211Attr::TransmitQueueStats(d) => ret.txq_stats = d,
+                Nl80211Attr::MloLinks(d) => ret.mlo_links = d,
+                _ => (),
+            }
+        }
+        match (wiphy, wdev) {
+            (Some(wiphy), Some(wdev)) => {
+                ret.wiphy = wiphy;
+                ret.wdev = wdev;
+                Ok(ret)
+            }
+            _ => Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                "Interface reply does not contain NL80211_ATTR_WIPHY or \
+                NL80211_ATTR_WDEV",
+            ))),
+        }
+    }
+}
@@ -1,7 +1,13 @@
 // SPDX-License-Identifier: MIT
 
 mod combination;
+mod csa;
 mod get;
+#[cfg(feature = "tokio_socket")]
+mod hopper;
+mod interface;
+mod monitor;
+mod power_save;
 mod set;
 mod vif;
 mod handle;
@@ -12,10 +18,28 @@ pub use self::combination::{
     Nl80211IfaceComb, Nl80211IfaceCombAttribute, Nl80211IfaceCombLimit,
     Nl80211IfaceCombLimitAttribute,
 };
+pub use self::csa::{
+    Nl80211ChannelSwitchEvent, Nl80211ChannelSwitchRequest,
+};
 pub use self::get::Nl80211InterfaceGetRequest;
+#[cfg(feature = "tokio_socket")]
+pub use self::hopper::{
+    Nl80211ChannelHop, Nl80211ChannelHopFailure, Nl80211ChannelHopWidth,
+    Nl80211ChannelHopper, Nl80211ChannelHopperTask,
+};
 pub use self::handle::Nl80211InterfaceHandle;
+pub use self::interface::Nl80211Interface;
 pub use self::iface_type::Nl80211InterfaceType;
-pub use self::ap::Nl80211StartAp;
+pub use self::monitor::Nl80211MonitorFlags;
+pub use self::power_save::{
+    Nl80211PowerSaveGetRequest, Nl80211PowerSaveHandle,
+    Nl80211PowerSaveSetRequest, Nl80211PsState,
+};
+pub use self::ap::{
+    Nl80211Beacon, Nl80211GetBeacon, Nl80211RegisterFrame, Nl80211SetBeacon,
+    Nl80211StartAp, Nl80211StopAp,
+};
 pub use self::set::{Nl80211InterfaceSetChannelRequest, Nl80211InterfaceSetRequest};
+pub use self::vif::{Nl80211InterfaceDelRequest, Nl80211InterfaceNewRequest};
 
 pub(crate) use self::iface_type::Nl80211InterfaceTypes;
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{nla::NlasIterator, DecodeError, Emitable};
+
+use crate::{
+    bytes::write_u16, Nl80211Attr, Nl80211Error, Nl80211Handle,
+    Nl80211WiphyGetRequest,
+};
+
+const NLA_HEADER_LEN: usize = 4;
+
+const NL80211_MNTR_FLAG_FCSFAIL: u16 = 1;
+const NL80211_MNTR_FLAG_PLCPFAIL: u16 = 2;
+const NL80211_MNTR_FLAG_CONTROL: u16 = 3;
+const NL80211_MNTR_FLAG_OTHER_BSS: u16 = 4;
+const NL80211_MNTR_FLAG_COOK_FRAMES: u16 = 5;
+const NL80211_MNTR_FLAG_ACTIVE: u16 = 6;
+const NL80211_MNTR_FLAG_MAX: u16 = NL80211_MNTR_FLAG_ACTIVE;
+
+// Length of the VHT MU-MIMO group membership (8 bytes) and user position
+// (16 bytes) arrays
+pub(crate) const VHT_MUMIMO_GROUPS_DATA_LEN: usize = 24;
+
+bitflags::bitflags! {
+    /// Monitor configuration flags.
+    /// Kernel is using nested flag attributes for them, the bit position
+    /// here is the attribute number.
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[non_exhaustive]
+    pub struct Nl80211MonitorFlags: u32 {
+        /// Pass frames with bad FCS
+        const FcsFail = 1 << NL80211_MNTR_FLAG_FCSFAIL;
+        /// Pass frames with bad PLCP
+        const PlcpFail = 1 << NL80211_MNTR_FLAG_PLCPFAIL;
+        /// Pass control frames
+        const Control = 1 << NL80211_MNTR_FLAG_CONTROL;
+        /// Disable BSSID filtering
+        const OtherBss = 1 << NL80211_MNTR_FLAG_OTHER_BSS;
+        /// Report frames after processing. Overrides all other flags.
+        const CookFrames = 1 << NL80211_MNTR_FLAG_COOK_FRAMES;
+        /// Use the configured MAC address and ACK incoming unicast packets.
+        const Active = 1 << NL80211_MNTR_FLAG_ACTIVE;
+        const _ = !0;
+    }
+}
+
+impl Nl80211MonitorFlags {
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut flags = Self::empty();
+        for nla in NlasIterator::new(payload) {
+            let nla = nla.context(format!(
+                "Invalid NL80211_ATTR_MNTR_FLAGS value {payload:?}"
+            ))?;
+            if nla.kind() > 0 && nla.kind() < 32 {
+                flags |= Self::from_bits_retain(1 << nla.kind());
+            }
+        }
+        Ok(flags)
+    }
+
+    fn kinds(&self) -> Vec<u16> {
+        (1..=NL80211_MNTR_FLAG_MAX)
+            .filter(|kind| self.bits() & (1 << kind) > 0)
+            .collect()
+    }
+}
+
+impl Emitable for Nl80211MonitorFlags {
+    fn buffer_len(&self) -> usize {
+        self.kinds().len() * NLA_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        for (i, kind) in self.kinds().into_iter().enumerate() {
+            let offset = i * NLA_HEADER_LEN;
+            // Flag attribute: NLA header only, no payload
+            write_u16(&mut buffer[offset..offset + 2], NLA_HEADER_LEN as u16);
+            write_u16(&mut buffer[offset + 2..offset + 4], kind);
+        }
+    }
+}
+
+/// Check the monitor options among the attributes of set or new interface
+/// request are supported by the wiphy, so that kernel rejecting them with
+/// `EOPNOTSUPP` is reported with a clear reason.
+pub(crate) async fn check_monitor_support(
+    handle: &Nl80211Handle,
+    attrs: &[Nl80211Attr],
+) -> Result<(), Nl80211Error> {
+    let mut active = false;
+    let mut mu_mimo = false;
+    let mut request = Nl80211WiphyGetRequest::new(handle.clone());
+    for attr in attrs {
+        match attr {
+            Nl80211Attr::MntrFlags(flags) => {
+                active = flags.contains(Nl80211MonitorFlags::Active)
+            }
+            Nl80211Attr::MuMimoGroupData(_)
+            | Nl80211Attr::MuMimoFollowMacAddr(_) => mu_mimo = true,
+            Nl80211Attr::IfIndex(d) => request = request.match_iface_index(*d),
+            Nl80211Attr::Wiphy(d) => request = request.match_index(*d),
+            _ => (),
+        }
+    }
+    if !active && !mu_mimo {
+        return Ok(());
+    }
+
+    let wiphys = request.collect().await?;
+    let wiphy = wiphys.first().ok_or_else(|| {
+        Nl80211Error::RequestFailed(
+            "Wiphy of the monitor interface not found".to_string(),
+        )
+    })?;
+    if active && !wiphy.supports_active_monitor() {
+        return Err(Nl80211Error::RequestFailed(format!(
+            "Wiphy {} does not support active monitor",
+            wiphy.name
+        )));
+    }
+    if mu_mimo && !wiphy.supports_mu_mimo_air_sniffer() {
+        return Err(Nl80211Error::RequestFailed(format!(
+            "Wiphy {} does not support following VHT MU-MIMO groups in \
+            monitor mode",
+            wiphy.name
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+const NL80211_PS_DISABLED: u32 = 0;
+const NL80211_PS_ENABLED: u32 = 1;
+
+/// 802.11 power save state of the interface
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211PsState {
+    Disabled,
+    Enabled,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211PsState {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_PS_DISABLED => Self::Disabled,
+            NL80211_PS_ENABLED => Self::Enabled,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211PsState> for u32 {
+    fn from(v: Nl80211PsState) -> u32 {
+        match v {
+            Nl80211PsState::Disabled => NL80211_PS_DISABLED,
+            Nl80211PsState::Enabled => NL80211_PS_ENABLED,
+            Nl80211PsState::Other(d) => d,
+        }
+    }
+}
+
+pub struct Nl80211PowerSaveHandle {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211PowerSaveHandle {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211PowerSaveHandle { handle, if_index }
+    }
+
+    /// Enable power save
+    /// (equivalent to `iw dev DEVICE set power_save on`)
+    pub fn enable(&mut self) -> Nl80211PowerSaveSetRequest {
+        self.set(Nl80211PsState::Enabled)
+    }
+
+    /// Disable power save
+    /// (equivalent to `iw dev DEVICE set power_save off`)
+    pub fn disable(&mut self) -> Nl80211PowerSaveSetRequest {
+        self.set(Nl80211PsState::Disabled)
+    }
+
+    /// Set power save state
+    pub fn set(&mut self, state: Nl80211PsState) -> Nl80211PowerSaveSetRequest {
+        Nl80211PowerSaveSetRequest::new(
+            self.handle.clone(),
+            self.if_index,
+            state,
+        )
+    }
+
+    /// Retrieve power save state
+    /// (equivalent to `iw dev DEVICE get power_save`)
+    pub fn get(&mut self) -> Nl80211PowerSaveGetRequest {
+        Nl80211PowerSaveGetRequest::new(self.handle.clone(), self.if_index)
+    }
+}
+
+pub struct Nl80211PowerSaveSetRequest {
+    handle: Nl80211Handle,
+    message: Nl80211Message,
+}
+
+impl Nl80211PowerSaveSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        state: Nl80211PsState,
+    ) -> Self {
+        Nl80211PowerSaveSetRequest {
+            handle,
+            message: Nl80211Message {
+                cmd: Nl80211Command::SetPowerSave,
+                attributes: vec![
+                    Nl80211Attr::IfIndex(if_index),
+                    Nl80211Attr::PsState(state),
+                ],
+            },
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211PowerSaveSetRequest {
+            mut handle,
+            message,
+        } = self;
+
+        nl80211_execute(&mut handle, message, NLM_F_REQUEST | NLM_F_ACK).await
+    }
+}
+
+pub struct Nl80211PowerSaveGetRequest {
+    handle: Nl80211Handle,
+    message: Nl80211Message,
+}
+
+impl Nl80211PowerSaveGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211PowerSaveGetRequest {
+            handle,
+            message: Nl80211Message {
+                cmd: Nl80211Command::GetPowerSave,
+                attributes: vec![Nl80211Attr::IfIndex(if_index)],
+            },
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211PowerSaveGetRequest {
+            mut handle,
+            message,
+        } = self;
+
+        nl80211_execute(&mut handle, message, NLM_F_REQUEST | NLM_F_ACK).await
+    }
+
+    /// Execute the request and decode the power save state from the reply
+    pub async fn state(self) -> Result<Nl80211PsState, Nl80211Error> {
+        let mut stream = Box::pin(self.execute().await);
+        match stream.try_next().await? {
+            Some(msg) => msg
+                .payload
+                .attributes
+                .iter()
+                .find_map(|attr| {
+                    if let Nl80211Attr::PsState(d) = attr {
+                        Some(*d)
+                    } else {
+                        None
+                    }
+                })
+                .ok_or_else(|| {
+                    Nl80211Error::DecodeFailed(DecodeError::from(
+                        "Power save reply does not contain \
+                        NL80211_ATTR_PS_STATE",
+                    ))
+                }),
+            None => Err(Nl80211Error::RequestFailed(
+                "No reply for NL80211_CMD_GET_POWER_SAVE".to_string(),
+            )),
+        }
+    }
+}
@@ -1,13 +1,14 @@
 // SPDX-License-Identifier: MIT
 
-use futures::TryStream;
+use futures::{future::Either, stream, TryStream, TryStreamExt};
 use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
 use crate::{
-    nl80211_execute, Nl80211Attr, Nl80211ChannelWidth, Nl80211Command, Nl80211Error, Nl80211Handle, Nl80211HtWiphyChannelType, Nl80211Message
+    nl80211_execute, Nl80211Attr, Nl80211Chandef, Nl80211ChannelWidth, Nl80211Command, Nl80211Error, Nl80211Handle, Nl80211HtWiphyChannelType, Nl80211Message, Nl80211MonitorFlags
 };
 
+use super::monitor::{check_monitor_support, VHT_MUMIMO_GROUPS_DATA_LEN};
 use super::Nl80211InterfaceType;
 
 pub struct Nl80211InterfaceSetRequest {
@@ -35,6 +36,39 @@ impl Nl80211InterfaceSetRequest {
         self
     }
 
+    /// Set monitor flags, only valid for monitor interface. Requesting
+    /// [Nl80211MonitorFlags::Active] is checked against
+    /// [crate::Nl80211Wiphy::supports_active_monitor] before applying.
+    /// (equivalent to `iw dev DEVICE set monitor FLAGS`)
+    pub fn with_monitor_flags(mut self, flags: Nl80211MonitorFlags) -> Self {
+        self.attrs.retain(|attr| !matches!(attr, Nl80211Attr::MntrFlags(_)));
+        self.attrs.push(Nl80211Attr::MntrFlags(flags));
+        self
+    }
+
+    /// Follow the VHT MU-MIMO group membership and user position arrays in
+    /// monitor mode, requires
+    /// [crate::Nl80211ExtFeature::MuMimoAirSniffer]
+    pub fn with_mu_mimo_group_data(
+        mut self,
+        data: &[u8; VHT_MUMIMO_GROUPS_DATA_LEN],
+    ) -> Self {
+        self.attrs
+            .retain(|attr| !matches!(attr, Nl80211Attr::MuMimoGroupData(_)));
+        self.attrs.push(Nl80211Attr::MuMimoGroupData(data.to_vec()));
+        self
+    }
+
+    /// Follow the VHT MU-MIMO groups of the station in monitor mode,
+    /// requires [crate::Nl80211ExtFeature::MuMimoAirSniffer]
+    pub fn with_mu_mimo_follow_mac(mut self, mac: &[u8; 6]) -> Self {
+        self.attrs.retain(|attr| {
+            !matches!(attr, Nl80211Attr::MuMimoFollowMacAddr(_))
+        });
+        self.attrs.push(Nl80211Attr::MuMimoFollowMacAddr(*mac));
+        self
+    }
+
     // Set station mode
     pub fn with_station(mut self) -> Self {
         let attr = Nl80211Attr::IfType(Nl80211InterfaceType::Station);
@@ -60,7 +94,11 @@ impl Nl80211InterfaceSetRequest {
         self,
     ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
     {
-        let Nl80211InterfaceSetRequest { mut handle, mut attrs } = self;
+        let Nl80211InterfaceSetRequest { mut handle, attrs } = self;
+
+        if let Err(e) = check_monitor_support(&handle, &attrs).await {
+            return Either::Right(stream::once(futures::future::err(e)));
+        }
 
         let nl80211_msg = Nl80211Message {
             cmd: Nl80211Command::SetInterface,
@@ -69,8 +107,11 @@ impl Nl80211InterfaceSetRequest {
         
         let flags = NLM_F_REQUEST | NLM_F_ACK;
 
-        nl80211_execute(&mut handle, nl80211_msg, flags).await
-        
+        Either::Left(
+            nl80211_execute(&mut handle, nl80211_msg, flags)
+                .await
+                .into_stream(),
+        )
     }
 }
 
@@ -176,6 +217,13 @@ impl Nl80211InterfaceSetChannelRequest {
             .with_center_freq1(center_freq1)
     }
 
+    /// Set the channel definition, see [Nl80211Chandef]
+    pub fn as_chandef(self, chandef: &Nl80211Chandef) -> Self {
+        let mut ret = self.clear_except_ifindex();
+        ret.attrs.extend(chandef.to_attrs());
+        ret
+    }
+
     /// Clear all attributes except IfIndex
     pub fn clear_except_ifindex(mut self) -> Self {
         self.attrs.retain(|attr| matches!(attr, Nl80211Attr::IfIndex(_)));
@@ -1,41 +1,128 @@
 // SPDX-License-Identifier: MIT
 
-use futures::TryStream;
-use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+use std::convert::TryFrom;
+
+use futures::{future::Either, stream, TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
 use crate::{
-    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle, Nl80211Message
+    nl80211_execute, Nl80211Attr, Nl80211AttrsBuilder, Nl80211Command,
+    Nl80211Error, Nl80211Handle, Nl80211Interface, Nl80211Message,
+    Nl80211MonitorFlags,
 };
 
+use super::monitor::{check_monitor_support, VHT_MUMIMO_GROUPS_DATA_LEN};
 use super::Nl80211InterfaceType;
 
+const ETH_ALEN: usize = 6;
+
 pub struct Nl80211InterfaceNewRequest {
     handle: Nl80211Handle,
-    attrs: Vec<Nl80211Attr>
+    attrs: Nl80211AttrsBuilder<Nl80211InterfaceNewRequest>,
 }
 
 impl Nl80211InterfaceNewRequest {
-    // Create a new VIF for a specific interface via index
-    pub fn new(handle: Nl80211Handle, index: u32, name: String, mode: Nl80211InterfaceType) -> Self {
-        Nl80211InterfaceNewRequest { handle, attrs: vec![Nl80211Attr::IfIndex(index), Nl80211Attr::IfName(name), Nl80211Attr::IfType(mode)] }
+    /// Create a new virtual interface on the wiphy of the specified
+    /// interface index
+    pub fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        name: String,
+        iftype: Nl80211InterfaceType,
+    ) -> Self {
+        Nl80211InterfaceNewRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::IfName(name))
+                .replace(Nl80211Attr::IfType(iftype)),
+        }
+    }
+
+    /// Create a new virtual interface on the specified wiphy index
+    pub fn new_on_wiphy(
+        handle: Nl80211Handle,
+        wiphy: u32,
+        name: String,
+        iftype: Nl80211InterfaceType,
+    ) -> Self {
+        Nl80211InterfaceNewRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .replace(Nl80211Attr::Wiphy(wiphy))
+                .replace(Nl80211Attr::IfName(name))
+                .replace(Nl80211Attr::IfType(iftype)),
+        }
     }
 
-    /// Create new ViF as station
-    pub fn as_station(mut self, index: u32, name: String) -> Self {
-        self.attrs = vec![Nl80211Attr::IfIndex(index), Nl80211Attr::IfName(name), Nl80211Attr::IfType(Nl80211InterfaceType::Station)];
+    fn set_iftype(mut self, iftype: Nl80211InterfaceType) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::IfType(iftype));
         self
     }
 
-    /// Create new ViF as ap
-    pub fn as_ap(mut self, index: u32, name: String) -> Self {
-        self.attrs = vec![Nl80211Attr::IfIndex(index), Nl80211Attr::IfName(name), Nl80211Attr::IfType(Nl80211InterfaceType::Ap)];
+    /// Create new VIF as station
+    pub fn as_station(self) -> Self {
+        self.set_iftype(Nl80211InterfaceType::Station)
+    }
+
+    /// Create new VIF as AP
+    pub fn as_ap(self) -> Self {
+        self.set_iftype(Nl80211InterfaceType::Ap)
+    }
+
+    /// Create new VIF as monitor
+    pub fn as_monitor(self) -> Self {
+        self.set_iftype(Nl80211InterfaceType::Monitor)
+    }
+
+    /// Use specified MAC address instead of the one assigned by driver
+    pub fn with_mac(mut self, mac: &[u8; ETH_ALEN]) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Mac(*mac));
         self
     }
 
-    /// Create new ViF as Monitor
-    pub fn as_monitor(mut self, index: u32, name: String) -> Self {
-        self.attrs = vec![Nl80211Attr::IfIndex(index), Nl80211Attr::IfName(name), Nl80211Attr::IfType(Nl80211InterfaceType::Monitor)];
+    /// Use 4-address frames on the new interface (WDS)
+    pub fn with_4addr(mut self, enable: bool) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Use4Addr(enable));
+        self
+    }
+
+    /// Monitor flags, only valid for monitor interface. Requesting
+    /// [Nl80211MonitorFlags::Active] is checked against
+    /// [crate::Nl80211Wiphy::supports_active_monitor] before creating.
+    pub fn with_monitor_flags(mut self, flags: Nl80211MonitorFlags) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MntrFlags(flags));
+        self
+    }
+
+    /// Follow the VHT MU-MIMO group membership and user position arrays
+    /// in monitor mode, requires
+    /// [crate::Nl80211ExtFeature::MuMimoAirSniffer]
+    pub fn with_mu_mimo_group_data(
+        mut self,
+        data: &[u8; VHT_MUMIMO_GROUPS_DATA_LEN],
+    ) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::MuMimoGroupData(data.to_vec()));
+        self
+    }
+
+    /// Follow the VHT MU-MIMO groups of the station in monitor mode,
+    /// requires [crate::Nl80211ExtFeature::MuMimoAirSniffer]
+    pub fn with_mu_mimo_follow_mac(mut self, mac: &[u8; ETH_ALEN]) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MuMimoFollowMacAddr(*mac));
+        self
+    }
+
+    /// Kernel will remove the new interface once the netlink socket of
+    /// this handle is closed. The socket is the one of the
+    /// [Nl80211Handle] connection shared by all requests sent through
+    /// it, hence the interface lives until that connection (returned by
+    /// [crate::new_connection()]) is dropped, not just this request.
+    pub fn with_socket_owner(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::SocketOwner);
         self
     }
 
@@ -44,27 +131,51 @@ impl Nl80211InterfaceNewRequest {
     ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
     {
         let Nl80211InterfaceNewRequest { mut handle, attrs } = self;
+        let attrs = attrs.build();
+
+        if let Err(e) = check_monitor_support(&handle, &attrs).await {
+            return Either::Right(stream::once(futures::future::err(e)));
+        }
 
         let nl80211_msg = Nl80211Message {
-            cmd: Nl80211Command::SetInterface,
+            cmd: Nl80211Command::NewInterface,
             attributes: attrs,
-        };        
-        
-        let flags = NLM_F_REQUEST;
+        };
 
-        nl80211_execute(&mut handle, nl80211_msg, flags).await
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        Either::Left(
+            nl80211_execute(&mut handle, nl80211_msg, flags)
+                .await
+                .into_stream(),
+        )
+    }
+
+    /// Execute the request and decode the kernel reply holding the
+    /// interface index and wdev of the new interface
+    pub async fn reply(self) -> Result<Nl80211Interface, Nl80211Error> {
+        let mut stream = Box::pin(self.execute().await);
+        match stream.try_next().await? {
+            Some(msg) => Nl80211Interface::try_from(msg.payload),
+            None => Err(Nl80211Error::RequestFailed(
+                "No reply for NL80211_CMD_NEW_INTERFACE".to_string(),
+            )),
+        }
     }
 }
 
 pub struct Nl80211InterfaceDelRequest {
     handle: Nl80211Handle,
-    attrs: Vec<Nl80211Attr>
+    attrs: Vec<Nl80211Attr>,
 }
 
 impl Nl80211InterfaceDelRequest {
-    // Delete a VIF
-    pub fn new(handle: Nl80211Handle, index: u32) -> Self {
-        Nl80211InterfaceDelRequest { handle, attrs: vec![Nl80211Attr::IfIndex(index)] }
+    /// Delete the virtual interface of specified interface index
+    pub fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211InterfaceDelRequest {
+            handle,
+            attrs: vec![Nl80211Attr::IfIndex(if_index)],
+        }
     }
 
     pub async fn execute(
@@ -76,10 +187,10 @@ impl Nl80211InterfaceDelRequest {
         let nl80211_msg = Nl80211Message {
             cmd: Nl80211Command::DelInterface,
             attributes: attrs,
-        };        
-        
-        let flags = NLM_F_REQUEST;
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
 
         nl80211_execute(&mut handle, nl80211_msg, flags).await
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{bytes::write_u32, Nl80211CipherSuite};
+
+const NL80211_KEY_DATA: u16 = 1;
+const NL80211_KEY_IDX: u16 = 2;
+const NL80211_KEY_CIPHER: u16 = 3;
+const NL80211_KEY_SEQ: u16 = 4;
+const NL80211_KEY_DEFAULT: u16 = 5;
+const NL80211_KEY_DEFAULT_MGMT: u16 = 6;
+const NL80211_KEY_TYPE: u16 = 7;
+// const NL80211_KEY_DEFAULT_TYPES: u16 = 8;
+const NL80211_KEY_MODE: u16 = 9;
+const NL80211_KEY_DEFAULT_BEACON: u16 = 10;
+
+const NL80211_KEYTYPE_GROUP: u32 = 0;
+const NL80211_KEYTYPE_PAIRWISE: u32 = 1;
+const NL80211_KEYTYPE_PEERKEY: u32 = 2;
+
+const NL80211_KEY_RX_TX: u8 = 0;
+const NL80211_KEY_NO_TX: u8 = 1;
+const NL80211_KEY_SET_TX: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Nl80211KeyType {
+    /// Group (broadcast/multicast) key, also used for IGTK and BIGTK
+    Group,
+    /// Pairwise (unicast/individual) key
+    Pairwise,
+    /// Peer key (DLS)
+    PeerKey,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211KeyType {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_KEYTYPE_GROUP => Self::Group,
+            NL80211_KEYTYPE_PAIRWISE => Self::Pairwise,
+            NL80211_KEYTYPE_PEERKEY => Self::PeerKey,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211KeyType> for u32 {
+    fn from(v: Nl80211KeyType) -> u32 {
+        match v {
+            Nl80211KeyType::Group => NL80211_KEYTYPE_GROUP,
+            Nl80211KeyType::Pairwise => NL80211_KEYTYPE_PAIRWISE,
+            Nl80211KeyType::PeerKey => NL80211_KEYTYPE_PEERKEY,
+            Nl80211KeyType::Other(d) => d,
+        }
+    }
+}
+
+/// Usage of pairwise key installed with extended key ID support
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Nl80211KeyMode {
+    /// Use the key for both RX and TX
+    RxTx,
+    /// Use the key for RX only
+    NoTx,
+    /// Switch TX to this already installed key
+    SetTx,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211KeyMode {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_KEY_RX_TX => Self::RxTx,
+            NL80211_KEY_NO_TX => Self::NoTx,
+            NL80211_KEY_SET_TX => Self::SetTx,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211KeyMode> for u8 {
+    fn from(v: Nl80211KeyMode) -> u8 {
+        match v {
+            Nl80211KeyMode::RxTx => NL80211_KEY_RX_TX,
+            Nl80211KeyMode::NoTx => NL80211_KEY_NO_TX,
+            Nl80211KeyMode::SetTx => NL80211_KEY_SET_TX,
+            Nl80211KeyMode::Other(d) => d,
+        }
+    }
+}
+
+/// Key attributes nested in `NL80211_ATTR_KEY`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Nl80211KeyAttr {
+    /// Temporal key material
+    Data(Vec<u8>),
+    /// Key index, 0-3 for pairwise and group keys, 4-5 for IGTK and 6-7
+    /// for BIGTK
+    Idx(u8),
+    Cipher(Nl80211CipherSuite),
+    /// Transmit key sequence number (IV/PN) in little endian
+    Seq(Vec<u8>),
+    /// Use the key as default key for data frames
+    Default,
+    /// Use the key as default key for management frames (IGTK)
+    DefaultMgmt,
+    Type(Nl80211KeyType),
+    Mode(Nl80211KeyMode),
+    /// Use the key as default key for beacon frames (BIGTK)
+    DefaultBeacon,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211KeyAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Data(d) | Self::Seq(d) => d.len(),
+            Self::Idx(_) | Self::Mode(_) => 1,
+            Self::Cipher(_) | Self::Type(_) => 4,
+            Self::Default | Self::DefaultMgmt | Self::DefaultBeacon => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Data(d) | Self::Seq(d) => buffer.copy_from_slice(d),
+            Self::Idx(d) => buffer[0] = *d,
+            Self::Cipher(d) => write_u32(buffer, u32::from(*d)),
+            Self::Default | Self::DefaultMgmt | Self::DefaultBeacon => (),
+            Self::Type(d) => write_u32(buffer, u32::from(*d)),
+            Self::Mode(d) => buffer[0] = u8::from(*d),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Data(_) => NL80211_KEY_DATA,
+            Self::Idx(_) => NL80211_KEY_IDX,
+            Self::Cipher(_) => NL80211_KEY_CIPHER,
+            Self::Seq(_) => NL80211_KEY_SEQ,
+            Self::Default => NL80211_KEY_DEFAULT,
+            Self::DefaultMgmt => NL80211_KEY_DEFAULT_MGMT,
+            Self::Type(_) => NL80211_KEY_TYPE,
+            Self::Mode(_) => NL80211_KEY_MODE,
+            Self::DefaultBeacon => NL80211_KEY_DEFAULT_BEACON,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211KeyAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_KEY_DATA => Self::Data(payload.to_vec()),
+            NL80211_KEY_IDX => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_IDX value {:?}", payload);
+                Self::Idx(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_KEY_CIPHER => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_CIPHER value {:?}", payload);
+                Self::Cipher(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_KEY_SEQ => Self::Seq(payload.to_vec()),
+            NL80211_KEY_DEFAULT => Self::Default,
+            NL80211_KEY_DEFAULT_MGMT => Self::DefaultMgmt,
+            NL80211_KEY_TYPE => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_TYPE value {:?}", payload);
+                Self::Type(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_KEY_MODE => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_MODE value {:?}", payload);
+                Self::Mode(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_KEY_DEFAULT_BEACON => Self::DefaultBeacon,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
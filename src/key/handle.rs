@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211CipherSuite, Nl80211Handle, Nl80211KeyDelRequest,
+    Nl80211KeyGetRequest, Nl80211KeyNewRequest, Nl80211KeySetRequest,
+};
+
+pub struct Nl80211KeyHandle(Nl80211Handle);
+
+impl Nl80211KeyHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211KeyHandle(handle)
+    }
+
+    /// Install pairwise, group, IGTK or BIGTK key
+    pub fn add(
+        &mut self,
+        if_index: u32,
+        key_idx: u8,
+        cipher: Nl80211CipherSuite,
+        data: Vec<u8>,
+    ) -> Nl80211KeyNewRequest {
+        Nl80211KeyNewRequest::new(
+            self.0.clone(),
+            if_index,
+            key_idx,
+            cipher,
+            data,
+        )
+    }
+
+    /// Set default key
+    pub fn set(&mut self, if_index: u32, key_idx: u8) -> Nl80211KeySetRequest {
+        Nl80211KeySetRequest::new(self.0.clone(), if_index, key_idx)
+    }
+
+    /// Remove key
+    pub fn del(&mut self, if_index: u32, key_idx: u8) -> Nl80211KeyDelRequest {
+        Nl80211KeyDelRequest::new(self.0.clone(), if_index, key_idx)
+    }
+
+    /// Retrieve key information
+    pub fn get(&mut self, if_index: u32, key_idx: u8) -> Nl80211KeyGetRequest {
+        Nl80211KeyGetRequest::new(self.0.clone(), if_index, key_idx)
+    }
+}
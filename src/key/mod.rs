@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod handle;
+mod request;
+
+pub use self::attr::{Nl80211KeyAttr, Nl80211KeyMode, Nl80211KeyType};
+pub use self::handle::Nl80211KeyHandle;
+pub use self::request::{
+    Nl80211KeyDelRequest, Nl80211KeyGetRequest, Nl80211KeyNewRequest,
+    Nl80211KeySetRequest,
+};
@@ -0,0 +1,304 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+use netlink_packet_utils::nla::Nla;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211AttrsBuilder, Nl80211CipherSuite,
+    Nl80211Command, Nl80211Error, Nl80211Handle, Nl80211KeyAttr,
+    Nl80211KeyMode, Nl80211KeyType, Nl80211Message,
+};
+
+const ETH_ALEN: usize = 6;
+
+// Like `Nl80211AttrsBuilder::replace()`, for the attributes nested in
+// `NL80211_ATTR_KEY`
+fn replace_key(
+    mut key: Vec<Nl80211KeyAttr>,
+    attr: Nl80211KeyAttr,
+) -> Vec<Nl80211KeyAttr> {
+    key.retain(|a| a.kind() != attr.kind());
+    key.push(attr);
+    key
+}
+
+/// Install a key (equivalent to `NL80211_CMD_NEW_KEY`).
+///
+/// Group key is installed by default, use [Self::with_peer()] for pairwise
+/// key. Key index 4-5 are for IGTK and 6-7 for BIGTK.
+pub struct Nl80211KeyNewRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211KeyNewRequest>,
+    key: Vec<Nl80211KeyAttr>,
+}
+
+impl Nl80211KeyNewRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        key_idx: u8,
+        cipher: Nl80211CipherSuite,
+        data: Vec<u8>,
+    ) -> Self {
+        Nl80211KeyNewRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new().if_index(if_index),
+            key: vec![
+                Nl80211KeyAttr::Idx(key_idx),
+                Nl80211KeyAttr::Cipher(cipher),
+                Nl80211KeyAttr::Data(data),
+            ],
+        }
+    }
+
+    /// Install pairwise key for specified peer
+    pub fn with_peer(mut self, mac: [u8; ETH_ALEN]) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Mac(mac));
+        self.key = replace_key(
+            self.key,
+            Nl80211KeyAttr::Type(Nl80211KeyType::Pairwise),
+        );
+        self
+    }
+
+    /// Initial receive sequence number (IV/PN) in little endian
+    pub fn with_seq(mut self, seq: Vec<u8>) -> Self {
+        self.key = replace_key(self.key, Nl80211KeyAttr::Seq(seq));
+        self
+    }
+
+    /// Only valid for pairwise key when extended key ID is supported
+    pub fn with_mode(mut self, mode: Nl80211KeyMode) -> Self {
+        self.key = replace_key(self.key, Nl80211KeyAttr::Mode(mode));
+        self
+    }
+
+    /// Install the group key on specified MLO link
+    pub fn with_link_id(mut self, link_id: u8) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MloLinkId(link_id));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211KeyNewRequest {
+            mut handle,
+            attrs,
+            key,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::NewKey,
+            attributes: attrs.replace(Nl80211Attr::Key(key)).build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+/// Set default key or switch TX to an installed key
+/// (equivalent to `NL80211_CMD_SET_KEY`).
+pub struct Nl80211KeySetRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211KeySetRequest>,
+    key: Vec<Nl80211KeyAttr>,
+}
+
+impl Nl80211KeySetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        key_idx: u8,
+    ) -> Self {
+        Nl80211KeySetRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new().if_index(if_index),
+            key: vec![Nl80211KeyAttr::Idx(key_idx)],
+        }
+    }
+
+    /// Use the key as default key for data frames
+    pub fn as_default(mut self) -> Self {
+        self.key = replace_key(self.key, Nl80211KeyAttr::Default);
+        self
+    }
+
+    /// Use the key as default key for management frames (IGTK)
+    pub fn as_default_mgmt(mut self) -> Self {
+        self.key = replace_key(self.key, Nl80211KeyAttr::DefaultMgmt);
+        self
+    }
+
+    /// Use the key as default key for beacon frames (BIGTK)
+    pub fn as_default_beacon(mut self) -> Self {
+        self.key = replace_key(self.key, Nl80211KeyAttr::DefaultBeacon);
+        self
+    }
+
+    /// Switch TX to the pairwise key of specified peer installed with
+    /// [Nl80211KeyMode::NoTx]
+    pub fn with_set_tx(mut self, mac: [u8; ETH_ALEN]) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Mac(mac));
+        self.key = replace_key(
+            self.key,
+            Nl80211KeyAttr::Type(Nl80211KeyType::Pairwise),
+        );
+        self.key =
+            replace_key(self.key, Nl80211KeyAttr::Mode(Nl80211KeyMode::SetTx));
+        self
+    }
+
+    pub fn with_link_id(mut self, link_id: u8) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MloLinkId(link_id));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211KeySetRequest {
+            mut handle,
+            attrs,
+            key,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetKey,
+            attributes: attrs.replace(Nl80211Attr::Key(key)).build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+/// Remove a key (equivalent to `NL80211_CMD_DEL_KEY`).
+pub struct Nl80211KeyDelRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211KeyDelRequest>,
+    key: Vec<Nl80211KeyAttr>,
+}
+
+impl Nl80211KeyDelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        key_idx: u8,
+    ) -> Self {
+        Nl80211KeyDelRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new().if_index(if_index),
+            key: vec![Nl80211KeyAttr::Idx(key_idx)],
+        }
+    }
+
+    /// Remove pairwise key of specified peer
+    pub fn with_peer(mut self, mac: [u8; ETH_ALEN]) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Mac(mac));
+        self.key = replace_key(
+            self.key,
+            Nl80211KeyAttr::Type(Nl80211KeyType::Pairwise),
+        );
+        self
+    }
+
+    pub fn with_link_id(mut self, link_id: u8) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MloLinkId(link_id));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211KeyDelRequest {
+            mut handle,
+            attrs,
+            key,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::DelKey,
+            attributes: attrs.replace(Nl80211Attr::Key(key)).build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+/// Retrieve the key sequence number and cipher
+/// (equivalent to `NL80211_CMD_GET_KEY`).
+pub struct Nl80211KeyGetRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211KeyGetRequest>,
+}
+
+impl Nl80211KeyGetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        key_idx: u8,
+    ) -> Self {
+        // Kernel only accepts key index outside of NL80211_ATTR_KEY for
+        // this command
+        Nl80211KeyGetRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::KeyIdx(key_idx)),
+        }
+    }
+
+    /// Query pairwise key of specified peer
+    pub fn with_peer(mut self, mac: [u8; ETH_ALEN]) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::Mac(mac));
+        self
+    }
+
+    pub fn with_link_id(mut self, link_id: u8) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MloLinkId(link_id));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211KeyGetRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::GetKey,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+
+    /// Execute the request and return the key attributes nested in the
+    /// reply, the key material itself is never reported by kernel
+    pub async fn reply(self) -> Result<Vec<Nl80211KeyAttr>, Nl80211Error> {
+        let mut stream = Box::pin(self.execute().await);
+        while let Some(msg) = stream.try_next().await? {
+            for attr in msg.payload.attributes {
+                if let Nl80211Attr::Key(key) = attr {
+                    return Ok(key);
+                }
+            }
+        }
+        Err(Nl80211Error::RequestFailed(
+            "No NL80211_ATTR_KEY in reply of NL80211_CMD_GET_KEY".to_string(),
+        ))
+    }
+}
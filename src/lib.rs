@@ -2,23 +2,33 @@
 
 mod attr;
 mod builder;
+mod chandef;
 mod channel;
 mod command;
+mod connect;
 mod connection;
+mod cqm;
+mod dfs;
 mod element;
 mod error;
+mod event;
 mod ext_cap;
 mod feature;
+mod frame;
 mod frame_type;
 mod handle;
 mod iface;
+mod key;
 mod macros;
 mod message;
+mod mlme;
 mod mlo;
 mod scan;
 mod station;
 mod stats;
+mod survey;
 mod reg;
+mod roc;
 mod wifi4;
 mod wifi5;
 mod wifi6;
@@ -29,48 +39,112 @@ pub(crate) mod bytes;
 
 pub use self::attr::Nl80211Attr;
 pub use self::builder::Nl80211AttrsBuilder;
+pub use self::chandef::{Nl80211Chandef, Nl80211Eht320Channelization};
 pub use self::channel::Nl80211ChannelWidth;
 pub use self::command::Nl80211Command;
+pub use self::connect::{
+    Nl80211BssSelect, Nl80211ConnectEvent, Nl80211ConnectRequest,
+    Nl80211DisconnectEvent, Nl80211DisconnectRequest, Nl80211Mfp,
+    Nl80211ReasonCode, Nl80211StatusCode,
+};
 #[cfg(feature = "tokio_socket")]
 pub use self::connection::new_connection;
 pub use self::connection::new_connection_with_socket;
+pub use self::cqm::{
+    Nl80211CqmAttr, Nl80211CqmEvent, Nl80211CqmNotify,
+    Nl80211CqmRssiThresholdEvent, Nl80211SetCqmRequest,
+};
+pub use self::dfs::{
+    Nl80211DfsChannel, Nl80211RadarDetectRequest, Nl80211RadarEvent,
+    Nl80211RadarEventType,
+};
 
 pub use self::element::{Nl80211Element,Nl80211AkmSuite, Nl80211AuthType};
 
 pub use self::error::Nl80211Error;
+pub use self::event::{
+    Nl80211Event, Nl80211EventStream, Nl80211MulticastGroup,
+};
 pub use self::ext_cap::{
     Nl80211ExtendedCapability, Nl80211IfTypeExtCapa, Nl80211IfTypeExtCapas,
 };
 
 pub use self::feature::{Nl80211ExtFeature, Nl80211Features};
+pub use self::frame::{
+    Nl80211AuthAlgorithm, Nl80211FrameControlFlags, Nl80211FrameTx,
+    Nl80211FrameTxRequest, Nl80211FrameTxStatus,
+    Nl80211FrameWaitCancelRequest, Nl80211MacHeader, Nl80211MgmtBody,
+    Nl80211MgmtFrame, Nl80211RegisterFramesRequest, Nl80211RxFrame,
+    Nl80211RxFrameFlags, Nl80211RxFrameStream, Nl80211SaeAuth,
+};
 pub use self::frame_type::{Nl80211FrameType, Nl80211IfaceFrameType, Nl80211FrameTypeCtl, Nl80211FrameTypeData, Nl80211FrameTypeExt, Nl80211FrameTypeMgmt};
 pub use self::handle::Nl80211Handle;
 
 pub use self::iface::{
-    Nl80211IfaceComb, Nl80211IfaceCombAttribute, Nl80211IfaceCombLimit,
-    Nl80211IfaceCombLimitAttribute, Nl80211InterfaceGetRequest,
-    Nl80211InterfaceHandle, Nl80211InterfaceType, Nl80211StartAp, 
+    Nl80211Beacon, Nl80211ChannelSwitchEvent, Nl80211ChannelSwitchRequest,
+    Nl80211GetBeacon, Nl80211IfaceComb, Nl80211IfaceCombAttribute, Nl80211IfaceCombLimit,
+    Nl80211IfaceCombLimitAttribute, Nl80211Interface,
+    Nl80211InterfaceDelRequest, Nl80211InterfaceGetRequest,
+    Nl80211InterfaceHandle, Nl80211InterfaceNewRequest,
+    Nl80211InterfaceType, Nl80211MonitorFlags, Nl80211PowerSaveGetRequest,
+    Nl80211PowerSaveHandle, Nl80211PowerSaveSetRequest, Nl80211PsState,
+    Nl80211RegisterFrame,
+    Nl80211SetBeacon, Nl80211StartAp, Nl80211StopAp,
     Nl80211InterfaceSetChannelRequest, Nl80211InterfaceSetRequest
 };
+#[cfg(feature = "tokio_socket")]
+pub use self::iface::{
+    Nl80211ChannelHop, Nl80211ChannelHopFailure, Nl80211ChannelHopWidth,
+    Nl80211ChannelHopper, Nl80211ChannelHopperTask,
+};
 
+pub use self::key::{
+    Nl80211KeyAttr, Nl80211KeyDelRequest, Nl80211KeyGetRequest,
+    Nl80211KeyHandle, Nl80211KeyMode, Nl80211KeyNewRequest,
+    Nl80211KeySetRequest, Nl80211KeyType,
+};
 pub use self::message::Nl80211Message;
+pub use self::mlme::{
+    Nl80211AssociateRequest, Nl80211AuthenticateRequest,
+    Nl80211DeauthenticateRequest, Nl80211DisassociateRequest,
+    Nl80211MlmeEvent,
+};
 pub use self::mlo::Nl80211MloLink;
 
+pub use self::reg::{
+    Nl80211DfsRegion, Nl80211GetRegulatory, Nl80211RegDomType,
+    Nl80211RegRule, Nl80211RegRuleFlags, Nl80211Regdom,
+    Nl80211RegdomInitiator, Nl80211RegulatoryHandle, Nl80211ReloadRegdb,
+    Nl80211ReqSetRegulatory, Nl80211SetRegulatory,
+};
+pub use self::roc::{
+    Nl80211CancelRemainOnChannelRequest, Nl80211RemainOnChannel,
+    Nl80211RemainOnChannelEvent, Nl80211RemainOnChannelRequest,
+};
+
 pub use self::scan::{
-    Nl80211BssCapabilities, Nl80211BssInfo, Nl80211BssUseFor, Nl80211Scan,
+    Nl80211Bss, Nl80211BssCapabilities, Nl80211BssInfo, Nl80211BssUseFor, Nl80211Scan,
     Nl80211ScanFlags, Nl80211ScanGetRequest, Nl80211ScanHandle,
     Nl80211ScanScheduleRequest, Nl80211ScanScheduleStopRequest,
     Nl80211ScanTriggerRequest, Nl80211SchedScanMatch, Nl80211SchedScanPlan,
 };
+#[cfg(feature = "tokio_socket")]
+pub use self::scan::Nl80211ScanTriggerWaitRequest;
 pub use self::station::{
     Nl80211EhtGi, Nl80211EhtRuAllocation, Nl80211HeGi, Nl80211HeRuAllocation,
     Nl80211MeshPowerMode, Nl80211PeerLinkState, Nl80211RateInfo,
-    Nl80211StationBssParam, Nl80211StationFlag, Nl80211StationFlagUpdate,
-    Nl80211StationGetRequest, Nl80211StationHandle, Nl80211StationInfo,
+    Nl80211Station, Nl80211StationBssParam, Nl80211StationDelRequest,
+    Nl80211StationFlag, Nl80211StationFlagUpdate, Nl80211StationGetRequest,
+    Nl80211StationHandle, Nl80211StationInfo, Nl80211StationNewRequest,
+    Nl80211StationSetRequest,
 };
 pub use self::stats::{
     NestedNl80211TidStats, Nl80211TidStats, Nl80211TransmitQueueStat,
 };
+pub use self::survey::{
+    Nl80211SurveyGetRequest, Nl80211SurveyHandle, Nl80211SurveyInfo,
+    Nl80211SurveyInfoAttr,
+};
 pub use self::wifi4::{
     Nl80211ElementHtCap, Nl80211HtAMpduPara, Nl80211HtAselCaps,
     Nl80211HtCapabilityMask, Nl80211HtCaps, Nl80211HtExtendedCap,
@@ -91,9 +165,12 @@ pub use self::wifi7::{
 };
 pub use self::wiphy::{
     Nl80211Band, Nl80211BandInfo, Nl80211BandType, Nl80211BandTypes,
-    Nl80211CipherSuite, Nl80211Frequency, Nl80211FrequencyInfo, Nl80211IfMode,
-    Nl80211WiphyGetRequest, Nl80211WiphyHandle, Nl80211WowlanTcpTrigerSupport,
-    Nl80211WowlanTrigerPatternSupport, Nl80211WowlanTrigersSupport,
+    Nl80211CipherSuite, Nl80211DfsState, Nl80211Frequency,
+    Nl80211FrequencyInfo, Nl80211IfMode,
+    Nl80211TxPowerSetting, Nl80211Wiphy, Nl80211WiphyGetRequest,
+    Nl80211WiphyHandle, Nl80211WiphySetRequest,
+    Nl80211WowlanTcpTrigerSupport, Nl80211WowlanTrigerPatternSupport,
+    Nl80211WowlanTrigersSupport,
 };
 
 pub use self::element::{Nl80211Elements, Nl80211RateAndSelector, Nl80211ElementRsn, Nl80211RsnCapbilities,  Nl80211ElementCountry, Nl80211ElementCountryEnvironment, Nl80211ElementCountryTriplet, Nl80211ElementSubBand};
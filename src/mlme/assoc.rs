@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211AkmSuite, Nl80211Attr, Nl80211AttrsBuilder,
+    Nl80211CipherSuite, Nl80211Command, Nl80211Element, Nl80211Elements,
+    Nl80211Error, Nl80211Handle, Nl80211Message, Nl80211Mfp,
+};
+
+const ETH_ALEN: usize = 6;
+const ETH_P_PAE: u16 = 0x888E;
+
+/// (Re)associate with a BSS already authenticated with, for drivers
+/// without SME offload.
+///
+/// The result is reported asynchronously as
+/// [crate::Nl80211Event::Associate] to the "mlme" multicast group.
+pub struct Nl80211AssociateRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211AssociateRequest>,
+}
+
+impl Nl80211AssociateRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        bssid: [u8; ETH_ALEN],
+        freq: u32,
+        ssid: String,
+    ) -> Self {
+        Nl80211AssociateRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::Mac(bssid))
+                .replace(Nl80211Attr::WiphyFreq(freq))
+                .replace(Nl80211Attr::Ssid(ssid)),
+        }
+    }
+
+    /// Extra information elements for the (re)association request, the
+    /// RSN element should be included here for protected network
+    pub fn with_ies(mut self, ies: &[Nl80211Element]) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::Ie(Nl80211Elements::from(ies)));
+        self
+    }
+
+    /// Send reassociation request to the specified previous BSS
+    pub fn with_prev_bssid(mut self, bssid: [u8; ETH_ALEN]) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::PrevBssid(bssid));
+        self
+    }
+
+    /// Management frame protection
+    pub fn with_mfp(mut self, mfp: Nl80211Mfp) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::UseMfp(mfp));
+        self
+    }
+
+    /// Bitmap of `NL80211_WPA_VERSION_*`
+    pub fn with_wpa_versions(mut self, versions: u32) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::WpaVersions(versions));
+        self
+    }
+
+    pub fn with_pairwise_ciphers(
+        mut self,
+        ciphers: Vec<Nl80211CipherSuite>,
+    ) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::CipherSuitesPairwise(ciphers));
+        self
+    }
+
+    pub fn with_group_cipher(mut self, cipher: Nl80211CipherSuite) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::CipherSuiteGroup(cipher));
+        self
+    }
+
+    pub fn with_akm_suites(mut self, akm_suites: Vec<Nl80211AkmSuite>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::AkmSuites(akm_suites));
+        self
+    }
+
+    /// Userspace handles IEEE 802.1X/EAPOL frames, the kernel will not
+    /// forward data frames before the port is authorized
+    pub fn with_control_port(mut self) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::ControlPort)
+            .replace(Nl80211Attr::ControlPortEthertype(ETH_P_PAE));
+        self
+    }
+
+    /// Deliver the EAPOL frames over nl80211 instead of the network
+    /// interface
+    pub fn with_control_port_over_nl80211(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::ControlPortOverNl80211);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211AssociateRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::Associate,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
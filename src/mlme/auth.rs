@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211AttrsBuilder, Nl80211AuthType,
+    Nl80211Command, Nl80211Element, Nl80211Elements, Nl80211Error,
+    Nl80211Handle, Nl80211Message,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Authenticate with a BSS for drivers without SME offload
+/// (equivalent to `iw dev DEVICE auth`).
+///
+/// The result is reported asynchronously as
+/// [crate::Nl80211Event::Authenticate] to the "mlme" multicast group.
+pub struct Nl80211AuthenticateRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211AuthenticateRequest>,
+}
+
+impl Nl80211AuthenticateRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        bssid: [u8; ETH_ALEN],
+        freq: u32,
+        ssid: String,
+        auth_type: Nl80211AuthType,
+    ) -> Self {
+        Nl80211AuthenticateRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::Mac(bssid))
+                .replace(Nl80211Attr::WiphyFreq(freq))
+                .replace(Nl80211Attr::Ssid(ssid))
+                .replace(Nl80211Attr::AuthType(auth_type)),
+        }
+    }
+
+    /// Extra information elements for the authentication frame
+    pub fn with_ies(mut self, ies: &[Nl80211Element]) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::Ie(Nl80211Elements::from(ies)));
+        self
+    }
+
+    /// SAE commit or confirm message, starting with the authentication
+    /// transaction sequence number field
+    pub fn with_sae_data(mut self, data: Vec<u8>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::AuthData(data));
+        self
+    }
+
+    /// Only update local state without sending authentication frame
+    pub fn with_local_state_change(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::LocalStateChange);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211AuthenticateRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::Authenticate,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
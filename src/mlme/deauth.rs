@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211AttrsBuilder, Nl80211Command,
+    Nl80211Element, Nl80211Elements, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211ReasonCode,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Deauthenticate from a BSS, for drivers without SME offload.
+pub struct Nl80211DeauthenticateRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211DeauthenticateRequest>,
+}
+
+impl Nl80211DeauthenticateRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        bssid: [u8; ETH_ALEN],
+        reason: Nl80211ReasonCode,
+    ) -> Self {
+        Nl80211DeauthenticateRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::Mac(bssid))
+                .replace(Nl80211Attr::ReasonCode(reason)),
+        }
+    }
+
+    /// Extra information elements for the deauthentication frame
+    pub fn with_ies(mut self, ies: &[Nl80211Element]) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::Ie(Nl80211Elements::from(ies)));
+        self
+    }
+
+    /// Only update local state without sending deauthentication frame
+    pub fn with_local_state_change(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::LocalStateChange);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211DeauthenticateRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::Deauthenticate,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+/// Disassociate from a BSS, for drivers without SME offload.
+pub struct Nl80211DisassociateRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211DisassociateRequest>,
+}
+
+impl Nl80211DisassociateRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        bssid: [u8; ETH_ALEN],
+        reason: Nl80211ReasonCode,
+    ) -> Self {
+        Nl80211DisassociateRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::Mac(bssid))
+                .replace(Nl80211Attr::ReasonCode(reason)),
+        }
+    }
+
+    /// Extra information elements for the disassociation frame
+    pub fn with_ies(mut self, ies: &[Nl80211Element]) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::Ie(Nl80211Elements::from(ies)));
+        self
+    }
+
+    /// Only update local state without sending disassociation frame
+    pub fn with_local_state_change(mut self) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::LocalStateChange);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211DisassociateRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::Disassociate,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
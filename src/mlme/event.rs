@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{DecodeError, Parseable};
+
+use crate::{
+    Nl80211Attr, Nl80211FrameType, Nl80211FrameTypeMgmt, Nl80211MacHeader,
+    Nl80211MgmtBody, Nl80211MgmtFrame, Nl80211ReasonCode, Nl80211StatusCode,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Result of `NL80211_CMD_AUTHENTICATE`, `NL80211_CMD_ASSOCIATE`,
+/// `NL80211_CMD_DEAUTHENTICATE` or `NL80211_CMD_DISASSOCIATE` (including
+/// the unprotected variants), sent by kernel to the "mlme" multicast group.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211MlmeEvent {
+    pub wiphy: Option<u32>,
+    pub if_index: Option<u32>,
+    /// Address of the BSS, only included when `timed_out` is true
+    pub bssid: Option<[u8; ETH_ALEN]>,
+    /// The received or transmitted management frame, including the
+    /// IEEE 802.11 header
+    pub frame: Vec<u8>,
+    /// No response received from the AP
+    pub timed_out: bool,
+    /// All the attributes of the event
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl Nl80211MlmeEvent {
+    /// Decode `frame` into IEEE 802.11 management frame
+    pub fn mgmt_frame(&self) -> Result<Nl80211MgmtFrame, DecodeError> {
+        Nl80211MgmtFrame::parse(&self.frame)
+    }
+
+    /// Management frame subtype of `frame`
+    pub fn frame_subtype(&self) -> Option<Nl80211FrameTypeMgmt> {
+        match Nl80211MacHeader::parse(&self.frame).ok()?.frame_type {
+            Nl80211FrameType::Management(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Status code carried by authentication or (re)association
+    /// response frame
+    pub fn status_code(&self) -> Option<Nl80211StatusCode> {
+        match self.mgmt_frame().ok()?.body {
+            Nl80211MgmtBody::Auth { status, .. }
+            | Nl80211MgmtBody::AssocResp { status, .. }
+            | Nl80211MgmtBody::ReassocResp { status, .. } => Some(status),
+            _ => None,
+        }
+    }
+
+    /// Reason code carried by deauthentication or disassociation frame
+    pub fn reason_code(&self) -> Option<Nl80211ReasonCode> {
+        match self.mgmt_frame().ok()?.body {
+            Nl80211MgmtBody::Deauth { reason, .. }
+            | Nl80211MgmtBody::Disassoc { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+impl From<Vec<Nl80211Attr>> for Nl80211MlmeEvent {
+    fn from(attributes: Vec<Nl80211Attr>) -> Self {
+        let mut ret = Self::default();
+        for attr in attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Mac(d) => ret.bssid = Some(*d),
+                Nl80211Attr::Frame(d) => ret.frame = d.clone(),
+                Nl80211Attr::TimedOut => ret.timed_out = true,
+                _ => (),
+            }
+        }
+        ret.attributes = attributes;
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Nl80211BssCapabilities;
+
+    const AP: [u8; ETH_ALEN] = [0x02, 0, 0, 0, 0, 0x01];
+    const STA: [u8; ETH_ALEN] = [0x02, 0, 0, 0, 0, 0x02];
+
+    fn event(
+        subtype: Nl80211FrameTypeMgmt,
+        body: Nl80211MgmtBody,
+    ) -> Nl80211MlmeEvent {
+        let frame = Nl80211MgmtFrame::new(subtype, STA, AP, AP, body);
+        Nl80211MlmeEvent::from(vec![Nl80211Attr::Frame(frame.to_bytes())])
+    }
+
+    #[test]
+    fn status_of_assoc_resp() {
+        let event = event(
+            Nl80211FrameTypeMgmt::AssocResp,
+            Nl80211MgmtBody::AssocResp {
+                capabilities: Nl80211BssCapabilities::Ess,
+                status: Nl80211StatusCode::AssocRejectedTemporarily,
+                aid: 0xc001,
+                elements: vec![],
+            },
+        );
+        assert_eq!(
+            event.frame_subtype(),
+            Some(Nl80211FrameTypeMgmt::AssocResp)
+        );
+        assert_eq!(
+            event.status_code(),
+            Some(Nl80211StatusCode::AssocRejectedTemporarily)
+        );
+        assert_eq!(event.reason_code(), None);
+    }
+
+    #[test]
+    fn reason_of_deauth() {
+        let event = event(
+            Nl80211FrameTypeMgmt::Deauth,
+            Nl80211MgmtBody::Deauth {
+                reason: Nl80211ReasonCode::DeauthLeaving,
+                elements: vec![],
+            },
+        );
+        assert_eq!(event.frame_subtype(), Some(Nl80211FrameTypeMgmt::Deauth));
+        assert_eq!(event.status_code(), None);
+        assert_eq!(event.reason_code(), Some(Nl80211ReasonCode::DeauthLeaving));
+    }
+
+    #[test]
+    fn timed_out_without_frame() {
+        let event = Nl80211MlmeEvent::from(vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::Mac(AP),
+            Nl80211Attr::TimedOut,
+        ]);
+        assert!(event.timed_out);
+        assert_eq!(event.bssid, Some(AP));
+        assert_eq!(event.frame_subtype(), None);
+        assert_eq!(event.status_code(), None);
+        assert_eq!(event.reason_code(), None);
+    }
+}
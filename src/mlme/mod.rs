@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod assoc;
+mod auth;
+mod deauth;
+mod event;
+
+pub use self::assoc::Nl80211AssociateRequest;
+pub use self::auth::Nl80211AuthenticateRequest;
+pub use self::deauth::{
+    Nl80211DeauthenticateRequest, Nl80211DisassociateRequest,
+};
+pub use self::event::Nl80211MlmeEvent;
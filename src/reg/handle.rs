@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211GetRegulatory, Nl80211Handle, Nl80211Regdom, Nl80211ReloadRegdb,
+    Nl80211ReqSetRegulatory, Nl80211SetRegulatory,
+};
+
+pub struct Nl80211RegulatoryHandle(Nl80211Handle);
+
+impl Nl80211RegulatoryHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211RegulatoryHandle(handle)
+    }
+
+    /// Retrieve the global regulatory domain
+    /// (equivalent to `iw reg get`)
+    pub fn get(&mut self) -> Nl80211GetRegulatory {
+        Nl80211GetRegulatory::new(self.0.clone())
+    }
+
+    /// Retrieve the regulatory domain of self-managed wiphy
+    /// (equivalent to `iw phy PHY reg get`)
+    pub fn get_wiphy(&mut self, wiphy: u32) -> Nl80211GetRegulatory {
+        Nl80211GetRegulatory::new(self.0.clone()).with_wiphy(wiphy)
+    }
+
+    /// Retrieve the global regulatory domain and the ones of all
+    /// self-managed wiphys
+    pub fn dump(&mut self) -> Nl80211GetRegulatory {
+        Nl80211GetRegulatory::new(self.0.clone()).as_dump()
+    }
+
+    /// Request kernel to change the regulatory domain to specified country
+    /// (equivalent to `iw reg set ALPHA2`)
+    pub fn set_country(&mut self, alpha2: String) -> Nl80211ReqSetRegulatory {
+        Nl80211ReqSetRegulatory::new(self.0.clone(), alpha2)
+    }
+
+    /// Install the regulatory domain, only permitted for self-managed
+    /// drivers or the regulatory agent
+    pub fn set(&mut self, regdom: &Nl80211Regdom) -> Nl80211SetRegulatory {
+        Nl80211SetRegulatory::new(self.0.clone(), regdom)
+    }
+
+    /// Reload the regulatory database
+    /// (equivalent to `iw reg reload`)
+    pub fn reload_db(&mut self) -> Nl80211ReloadRegdb {
+        Nl80211ReloadRegdb::new(self.0.clone())
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+mod regdom;
+mod request;
+mod rule;
+mod types;
+
+pub use self::handle::Nl80211RegulatoryHandle;
+pub use self::regdom::Nl80211Regdom;
+pub use self::request::{
+    Nl80211GetRegulatory, Nl80211ReloadRegdb, Nl80211ReqSetRegulatory,
+    Nl80211SetRegulatory,
+};
+pub use self::rule::{Nl80211RegRule, Nl80211RegRuleFlags};
+pub use self::types::{
+    Nl80211DfsRegion, Nl80211RegDomType, Nl80211RegdomInitiator,
+};
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211DfsRegion, Nl80211Error, Nl80211Message,
+};
+
+/// Regulatory domain, decoded from the reply of
+/// [crate::Nl80211GetRegulatory] (equivalent to one section of
+/// `iw reg get`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211Regdom {
+    /// Only set for regulatory domain of self-managed wiphy, `None` means
+    /// the global regulatory domain
+    pub wiphy: Option<u32>,
+    /// ISO/IEC 3166-1 alpha2 country code, "00" for world regulatory
+    /// domain
+    pub alpha2: String,
+    pub dfs_region: Option<Nl80211DfsRegion>,
+    /// The wiphy manages its own regulatory domain
+    pub self_managed: bool,
+    pub rules: Vec<Nl80211RegRule>,
+    /// All the attributes of the reply
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl TryFrom<Nl80211Message> for Nl80211Regdom {
+    type Error = Nl80211Error;
+
+    fn try_from(msg: Nl80211Message) -> Result<Self, Self::Error> {
+        if msg.cmd != Nl80211Command::GetReg {
+            return Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                format!(
+                    "Expecting NL80211_CMD_GET_REG for regulatory domain, \
+                    got {:?}",
+                    msg.cmd
+                ),
+            )));
+        }
+        let mut ret = Self::default();
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::RegAlpha2(d) => ret.alpha2 = d.clone(),
+                Nl80211Attr::DfsRegion(d) => ret.dfs_region = Some(*d),
+                Nl80211Attr::WiphySelfManagedReg => ret.self_managed = true,
+                Nl80211Attr::RegRules(d) => ret.rules = d.clone(),
+                _ => (),
+            }
+        }
+        ret.attributes = msg.attributes;
+        Ok(ret)
+    }
+}
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use futures::{future::ready, TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_ACK, NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211Regdom,
+};
+
+/// Prepare and send a CMD_GET_REG - used to request the regulatory domain
+pub struct Nl80211GetRegulatory {
+    handle: Nl80211Handle,
+    message: Nl80211Message,
+    dump: bool,
+}
+
+impl Nl80211GetRegulatory {
+    pub(crate) fn new(handle: Nl80211Handle) -> Self {
+        Nl80211GetRegulatory {
+            handle,
+            message: Nl80211Message {
+                cmd: Nl80211Command::GetReg,
+                attributes: vec![],
+            },
+            dump: false,
+        }
+    }
+
+    /// Query the regulatory domain of specified self-managed wiphy
+    pub(crate) fn with_wiphy(mut self, wiphy: u32) -> Self {
+        self.message.attributes.push(Nl80211Attr::Wiphy(wiphy));
+        self
+    }
+
+    /// Query the global regulatory domain and the ones of all self-managed
+    /// wiphys
+    pub(crate) fn as_dump(mut self) -> Self {
+        self.dump = true;
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211GetRegulatory {
+            mut handle,
+            message,
+            dump,
+        } = self;
+
+        let flags = if dump {
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST | NLM_F_ACK
+        };
+
+        nl80211_execute(&mut handle, message, flags).await
+    }
+
+    /// Execute the request and decode the replies into [Nl80211Regdom]
+    pub async fn collect(self) -> Result<Vec<Nl80211Regdom>, Nl80211Error> {
+        self.execute()
+            .await
+            .and_then(|msg| ready(Nl80211Regdom::try_from(msg.payload)))
+            .try_collect()
+            .await
+    }
+}
+
+/// Prepare and send a CMD_REQ_SET_REG - used to request a change to
+/// regulatory domain (equivalent to `iw reg set ALPHA2`)
+pub struct Nl80211ReqSetRegulatory {
+    handle: Nl80211Handle,
+    message: Nl80211Message,
+}
+
+impl Nl80211ReqSetRegulatory {
+    pub(crate) fn new(handle: Nl80211Handle, alpha2: String) -> Self {
+        Nl80211ReqSetRegulatory {
+            handle,
+            message: Nl80211Message {
+                cmd: Nl80211Command::ReqSetReg,
+                attributes: vec![Nl80211Attr::RegAlpha2(alpha2)],
+            },
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211ReqSetRegulatory {
+            mut handle,
+            message,
+        } = self;
+
+        nl80211_execute(&mut handle, message, NLM_F_REQUEST | NLM_F_ACK).await
+    }
+}
+
+/// Prepare and send a CMD_SET_REG - used by the regulatory agent (or
+/// userspace for self-managed drivers) to install a regulatory domain
+pub struct Nl80211SetRegulatory {
+    handle: Nl80211Handle,
+    message: Nl80211Message,
+}
+
+impl Nl80211SetRegulatory {
+    /// Use the alpha2, DFS region and rules of specified regulatory domain
+    pub(crate) fn new(handle: Nl80211Handle, regdom: &Nl80211Regdom) -> Self {
+        let mut attributes =
+            vec![Nl80211Attr::RegAlpha2(regdom.alpha2.clone())];
+        if let Some(dfs_region) = regdom.dfs_region {
+            attributes.push(Nl80211Attr::DfsRegion(dfs_region));
+        }
+        attributes.push(Nl80211Attr::RegRules(regdom.rules.clone()));
+        Nl80211SetRegulatory {
+            handle,
+            message: Nl80211Message {
+                cmd: Nl80211Command::SetReg,
+                attributes,
+            },
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211SetRegulatory {
+            mut handle,
+            message,
+        } = self;
+
+        nl80211_execute(&mut handle, message, NLM_F_REQUEST | NLM_F_ACK).await
+    }
+}
+
+/// Prepare and send a CMD_RELOAD_REGDB - used to reload the regulatory
+/// database from firmware file after it was updated
+pub struct Nl80211ReloadRegdb {
+    handle: Nl80211Handle,
+    message: Nl80211Message,
+}
+
+impl Nl80211ReloadRegdb {
+    pub(crate) fn new(handle: Nl80211Handle) -> Self {
+        Nl80211ReloadRegdb {
+            handle,
+            message: Nl80211Message {
+                cmd: Nl80211Command::ReloadRegdb,
+                attributes: vec![],
+            },
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211ReloadRegdb {
+            mut handle,
+            message,
+        } = self;
+
+        nl80211_execute(&mut handle, message, NLM_F_REQUEST | NLM_F_ACK).await
+    }
+}
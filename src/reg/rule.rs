@@ -0,0 +1,369 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::bytes::write_u32;
+
+const NL80211_ATTR_REG_RULE_FLAGS: u16 = 1;
+const NL80211_ATTR_FREQ_RANGE_START: u16 = 2;
+const NL80211_ATTR_FREQ_RANGE_END: u16 = 3;
+const NL80211_ATTR_FREQ_RANGE_MAX_BW: u16 = 4;
+const NL80211_ATTR_POWER_RULE_MAX_ANT_GAIN: u16 = 5;
+const NL80211_ATTR_POWER_RULE_MAX_EIRP: u16 = 6;
+const NL80211_ATTR_DFS_CAC_TIME: u16 = 7;
+const NL80211_ATTR_POWER_RULE_PSD: u16 = 8;
+
+bitflags::bitflags! {
+    /// Regulatory rule flags (`NL80211_RRF_*`)
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[non_exhaustive]
+    pub struct Nl80211RegRuleFlags: u32 {
+        /// OFDM modulation not allowed
+        const NoOfdm = 1 << 0;
+        /// CCK modulation not allowed
+        const NoCck = 1 << 1;
+        /// Indoor operation not allowed
+        const NoIndoor = 1 << 2;
+        /// Outdoor operation not allowed
+        const NoOutdoor = 1 << 3;
+        /// DFS support is required to be used
+        const Dfs = 1 << 4;
+        /// This is only for Point To Point links
+        const PtpOnly = 1 << 5;
+        /// This is only for Point To Multi Point links
+        const PtmpOnly = 1 << 6;
+        /// No mechanisms that initiate radiation are allowed, this
+        /// includes probe requests or modes of operation that require
+        /// beaconing
+        const NoIr = 1 << 7;
+        /// Maximum available bandwidth should be calculated based on
+        /// contiguous rules and wider channels will be allowed to cross
+        /// multiple contiguous/overlapping frequency ranges
+        const AutoBw = 1 << 11;
+        /// See `NL80211_FREQUENCY_ATTR_IR_CONCURRENT`
+        const IrConcurrent = 1 << 12;
+        /// Channels can't be used in HT40- operation
+        const NoHt40Minus = 1 << 13;
+        /// Channels can't be used in HT40+ operation
+        const NoHt40Plus = 1 << 14;
+        /// 80MHz operation not allowed
+        const No80Mhz = 1 << 15;
+        /// 160MHz operation not allowed
+        const No160Mhz = 1 << 16;
+        /// HE operation not allowed
+        const NoHe = 1 << 17;
+        /// 320MHz operation not allowed
+        const No320Mhz = 1 << 18;
+        /// EHT operation not allowed
+        const NoEht = 1 << 19;
+        /// Ruleset has power spectral density value
+        const Psd = 1 << 20;
+        /// See `NL80211_FREQUENCY_ATTR_DFS_CONCURRENT`
+        const DfsConcurrent = 1 << 21;
+        /// Client connection to VLP AP not allowed
+        const No6GhzVlpClient = 1 << 22;
+        /// Client connection to AFC AP not allowed
+        const No6GhzAfcClient = 1 << 23;
+        /// Allow using this rule for AP operation as VLP AP
+        const Allow6GhzVlpAp = 1 << 24;
+        /// Allow activity in 20 MHz bandwidth, despite NoIr
+        const Allow20MhzActivity = 1 << 25;
+        const _ = !0;
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Nl80211RegRuleNla {
+    Flags(u32),
+    FreqRangeStart(u32),
+    FreqRangeEnd(u32),
+    FreqRangeMaxBw(u32),
+    PowerRuleMaxAntGain(u32),
+    PowerRuleMaxEirp(u32),
+    DfsCacTime(u32),
+    PowerRulePsd(i8),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211RegRuleNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::PowerRulePsd(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+            _ => 4,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Flags(_) => NL80211_ATTR_REG_RULE_FLAGS,
+            Self::FreqRangeStart(_) => NL80211_ATTR_FREQ_RANGE_START,
+            Self::FreqRangeEnd(_) => NL80211_ATTR_FREQ_RANGE_END,
+            Self::FreqRangeMaxBw(_) => NL80211_ATTR_FREQ_RANGE_MAX_BW,
+            Self::PowerRuleMaxAntGain(_) => {
+                NL80211_ATTR_POWER_RULE_MAX_ANT_GAIN
+            }
+            Self::PowerRuleMaxEirp(_) => NL80211_ATTR_POWER_RULE_MAX_EIRP,
+            Self::DfsCacTime(_) => NL80211_ATTR_DFS_CAC_TIME,
+            Self::PowerRulePsd(_) => NL80211_ATTR_POWER_RULE_PSD,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Flags(d)
+            | Self::FreqRangeStart(d)
+            | Self::FreqRangeEnd(d)
+            | Self::FreqRangeMaxBw(d)
+            | Self::PowerRuleMaxAntGain(d)
+            | Self::PowerRuleMaxEirp(d)
+            | Self::DfsCacTime(d) => write_u32(buffer, *d),
+            Self::PowerRulePsd(d) => buffer[0] = *d as u8,
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211RegRuleNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_ATTR_REG_RULE_FLAGS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_REG_RULE_FLAGS value {:?}",
+                    payload
+                );
+                Self::Flags(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FREQ_RANGE_START => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FREQ_RANGE_START value {:?}",
+                    payload
+                );
+                Self::FreqRangeStart(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FREQ_RANGE_END => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FREQ_RANGE_END value {:?}",
+                    payload
+                );
+                Self::FreqRangeEnd(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FREQ_RANGE_MAX_BW => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FREQ_RANGE_MAX_BW value {:?}",
+                    payload
+                );
+                Self::FreqRangeMaxBw(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_POWER_RULE_MAX_ANT_GAIN => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_POWER_RULE_MAX_ANT_GAIN value {:?}",
+                    payload
+                );
+                Self::PowerRuleMaxAntGain(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_POWER_RULE_MAX_EIRP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_POWER_RULE_MAX_EIRP value {:?}",
+                    payload
+                );
+                Self::PowerRuleMaxEirp(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_DFS_CAC_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_DFS_CAC_TIME value {:?}",
+                    payload
+                );
+                Self::DfsCacTime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_POWER_RULE_PSD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_POWER_RULE_PSD value {:?}",
+                    payload
+                );
+                Self::PowerRulePsd(parse_u8(payload).context(err_msg)? as i8)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Regulatory rule of a regulatory domain, one entry of
+/// `NL80211_ATTR_REG_RULES`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211RegRule {
+    pub flags: Nl80211RegRuleFlags,
+    /// Starting frequency of the range in KHz
+    pub start_freq_khz: u32,
+    /// Ending frequency of the range in KHz
+    pub end_freq_khz: u32,
+    /// Maximum allowed bandwidth in KHz
+    pub max_bandwidth_khz: u32,
+    /// Maximum allowed antenna gain in mBi (100 * dBi)
+    pub max_antenna_gain: u32,
+    /// Maximum allowed EIRP in mBm (100 * dBm)
+    pub max_eirp: u32,
+    /// DFS CAC time in milliseconds, 0 means default value
+    pub dfs_cac_time_ms: Option<u32>,
+    /// Power spectral density in dBm/MHz, only with
+    /// [Nl80211RegRuleFlags::Psd]
+    pub psd: Option<i8>,
+}
+
+impl Nl80211RegRule {
+    /// Length of the NLAs of a `NL80211_ATTR_REG_RULES` list
+    pub(crate) fn list_len(rules: &[Self]) -> usize {
+        rules
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| IndexedRegRule(i as u16, rule).buffer_len())
+            .sum()
+    }
+
+    /// Emit the NLAs of a `NL80211_ATTR_REG_RULES` list, kernel uses the
+    /// position of the rule as the kind of nested attribute
+    pub(crate) fn emit_list(rules: &[Self], buffer: &mut [u8]) {
+        let list: Vec<IndexedRegRule> = rules
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| IndexedRegRule(i as u16, rule))
+            .collect();
+        list.as_slice().emit(buffer)
+    }
+}
+
+impl From<&Nl80211RegRule> for Vec<Nl80211RegRuleNla> {
+    fn from(rule: &Nl80211RegRule) -> Self {
+        let mut nlas = vec![
+            Nl80211RegRuleNla::Flags(rule.flags.bits()),
+            Nl80211RegRuleNla::FreqRangeStart(rule.start_freq_khz),
+            Nl80211RegRuleNla::FreqRangeEnd(rule.end_freq_khz),
+            Nl80211RegRuleNla::FreqRangeMaxBw(rule.max_bandwidth_khz),
+            Nl80211RegRuleNla::PowerRuleMaxAntGain(rule.max_antenna_gain),
+            Nl80211RegRuleNla::PowerRuleMaxEirp(rule.max_eirp),
+        ];
+        if let Some(d) = rule.dfs_cac_time_ms {
+            nlas.push(Nl80211RegRuleNla::DfsCacTime(d));
+        }
+        if let Some(d) = rule.psd {
+            nlas.push(Nl80211RegRuleNla::PowerRulePsd(d));
+        }
+        nlas
+    }
+}
+
+struct IndexedRegRule<'a>(u16, &'a Nl80211RegRule);
+
+impl Nla for IndexedRegRule<'_> {
+    fn value_len(&self) -> usize {
+        Vec::<Nl80211RegRuleNla>::from(self.1)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.0
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211RegRuleNla>::from(self.1)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211RegRule
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_REG_RULES value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211RegRuleNla::parse(nla).context(err_msg.clone())? {
+                Nl80211RegRuleNla::Flags(d) => {
+                    ret.flags = Nl80211RegRuleFlags::from_bits_retain(d)
+                }
+                Nl80211RegRuleNla::FreqRangeStart(d) => ret.start_freq_khz = d,
+                Nl80211RegRuleNla::FreqRangeEnd(d) => ret.end_freq_khz = d,
+                Nl80211RegRuleNla::FreqRangeMaxBw(d) => {
+                    ret.max_bandwidth_khz = d
+                }
+                Nl80211RegRuleNla::PowerRuleMaxAntGain(d) => {
+                    ret.max_antenna_gain = d
+                }
+                Nl80211RegRuleNla::PowerRuleMaxEirp(d) => ret.max_eirp = d,
+                Nl80211RegRuleNla::DfsCacTime(d) => {
+                    ret.dfs_cac_time_ms = Some(d)
+                }
+                Nl80211RegRuleNla::PowerRulePsd(d) => ret.psd = Some(d),
+                Nl80211RegRuleNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_REG_RULES value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reg_rules_round_trip() {
+        let rules = vec![
+            Nl80211RegRule {
+                flags: Nl80211RegRuleFlags::NoOutdoor
+                    | Nl80211RegRuleFlags::AutoBw,
+                start_freq_khz: 5_170_000,
+                end_freq_khz: 5_250_000,
+                max_bandwidth_khz: 80_000,
+                max_antenna_gain: 0,
+                max_eirp: 2300,
+                dfs_cac_time_ms: None,
+                psd: None,
+            },
+            Nl80211RegRule {
+                flags: Nl80211RegRuleFlags::Dfs
+                    | Nl80211RegRuleFlags::NoIr
+                    | Nl80211RegRuleFlags::Psd,
+                start_freq_khz: 5_250_000,
+                end_freq_khz: 5_330_000,
+                max_bandwidth_khz: 80_000,
+                max_antenna_gain: 300,
+                max_eirp: 2000,
+                dfs_cac_time_ms: Some(60_000),
+                psd: Some(-1),
+            },
+        ];
+
+        let mut buffer = vec![0; Nl80211RegRule::list_len(&rules)];
+        Nl80211RegRule::emit_list(&rules, &mut buffer);
+
+        let mut parsed = Vec::new();
+        for (i, nla) in NlasIterator::new(&buffer).enumerate() {
+            let nla = nla.unwrap();
+            assert_eq!(nla.kind(), i as u16);
+            parsed.push(Nl80211RegRule::parse(&nla).unwrap());
+        }
+        assert_eq!(parsed, rules);
+    }
+}
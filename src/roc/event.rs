@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Nl80211Attr, Nl80211ChannelWidth};
+
+/// Notification of `NL80211_CMD_REMAIN_ON_CHANNEL` (device is now on the
+/// requested channel) or `NL80211_CMD_CANCEL_REMAIN_ON_CHANNEL` (the
+/// remain-on-channel expired or was cancelled), sent by kernel to the
+/// "mlme" multicast group.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211RemainOnChannelEvent {
+    pub wiphy: Option<u32>,
+    pub if_index: Option<u32>,
+    pub wdev: Option<u64>,
+    /// Cookie returned when the remain-on-channel was requested
+    pub cookie: Option<u64>,
+    pub freq: Option<u32>,
+    pub channel_width: Option<Nl80211ChannelWidth>,
+    /// Duration in milliseconds, only included when ready
+    pub duration: Option<u32>,
+    /// All the attributes of the event
+    pub attributes: Vec<Nl80211Attr>,
+}
+
+impl From<Vec<Nl80211Attr>> for Nl80211RemainOnChannelEvent {
+    fn from(attributes: Vec<Nl80211Attr>) -> Self {
+        let mut ret = Self::default();
+        for attr in attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Wiphy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(*d),
+                Nl80211Attr::Cookie(d) => ret.cookie = Some(*d),
+                Nl80211Attr::WiphyFreq(d) => ret.freq = Some(*d),
+                Nl80211Attr::ChannelWidth(d) => ret.channel_width = Some(*d),
+                Nl80211Attr::Duration(d) => ret.duration = Some(*d),
+                _ => (),
+            }
+        }
+        ret.attributes = attributes;
+        ret
+    }
+}
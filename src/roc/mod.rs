@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+
+mod event;
+mod request;
+
+pub use self::event::Nl80211RemainOnChannelEvent;
+pub use self::request::{
+    Nl80211CancelRemainOnChannelRequest, Nl80211RemainOnChannel,
+    Nl80211RemainOnChannelRequest,
+};
@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use futures::{TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+use netlink_sys::AsyncSocket;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211AttrsBuilder, Nl80211ChannelWidth,
+    Nl80211Command, Nl80211Error, Nl80211Event, Nl80211EventStream,
+    Nl80211Handle, Nl80211Message, Nl80211MulticastGroup,
+    Nl80211RemainOnChannelEvent, Nl80211WiphyGetRequest,
+};
+
+/// Stay on specified channel for a while
+/// (`NL80211_CMD_REMAIN_ON_CHANNEL`), e.g. for off-channel GAS/ANQP
+/// queries or P2P discovery. Management frames could be transmitted on
+/// it by [crate::Nl80211FrameTxRequest] and received by registering them.
+pub struct Nl80211RemainOnChannelRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    duration: Duration,
+    attrs: Nl80211AttrsBuilder<Nl80211RemainOnChannelRequest>,
+}
+
+impl Nl80211RemainOnChannelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        freq: u32,
+        duration: Duration,
+    ) -> Self {
+        Nl80211RemainOnChannelRequest {
+            handle,
+            if_index,
+            duration,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::WiphyFreq(freq)),
+        }
+    }
+
+    /// Channel width other than 20MHz, `center_freq1` is the center
+    /// frequency of the whole channel
+    pub fn with_channel_width(
+        mut self,
+        width: Nl80211ChannelWidth,
+        center_freq1: u32,
+    ) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::ChannelWidth(width))
+            .replace(Nl80211Attr::CenterFreq1(center_freq1));
+        self
+    }
+
+    /// Send the request with the duration as it is. Kernel will reject
+    /// duration longer than the `max_remain_on_channel_duration` of wiphy.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211RemainOnChannelRequest {
+            mut handle,
+            duration,
+            attrs,
+            ..
+        } = self;
+
+        let duration = u32::try_from(duration.as_millis()).unwrap_or(u32::MAX);
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::RemainOnChannel,
+            attributes: attrs.replace(Nl80211Attr::Duration(duration)).build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+
+    /// Cap the duration by the `max_remain_on_channel_duration` of wiphy,
+    /// request remain-on-channel and return the kernel cookie along with
+    /// the subscription waiting for its ready and expired notifications.
+    #[cfg(feature = "tokio_socket")]
+    pub async fn start(
+        self,
+    ) -> Result<Nl80211RemainOnChannel<netlink_sys::TokioSocket>, Nl80211Error>
+    {
+        self.start_with_socket().await
+    }
+
+    /// Same as [Nl80211RemainOnChannelRequest::start()] with specified
+    /// socket type.
+    pub async fn start_with_socket<S>(
+        mut self,
+    ) -> Result<Nl80211RemainOnChannel<S>, Nl80211Error>
+    where
+        S: AsyncSocket,
+    {
+        let mut handle = self.handle.clone();
+        let if_index = self.if_index;
+
+        let wiphys = Nl80211WiphyGetRequest::new(handle.clone())
+            .match_iface_index(if_index)
+            .collect()
+            .await?;
+        if let Some(max) = wiphys
+            .iter()
+            .find_map(|wiphy| wiphy.max_remain_on_channel_duration)
+        {
+            self.duration =
+                self.duration.min(Duration::from_millis(max.into()));
+        }
+        let duration = self.duration;
+
+        // Notifications are sent to the "mlme" multicast group, subscribe
+        // before requesting so they cannot be missed.
+        let events = handle
+            .events_with_socket(&[Nl80211MulticastGroup::Mlme])
+            .await?;
+
+        let mut cookie = None;
+        let mut stream = Box::pin(self.execute().await);
+        while let Some(msg) = stream.try_next().await? {
+            for attr in msg.payload.attributes {
+                if let Nl80211Attr::Cookie(d) = attr {
+                    cookie = Some(d);
+                }
+            }
+        }
+        let cookie = cookie.ok_or_else(|| {
+            Nl80211Error::RequestFailed(
+                "No cookie in reply of remain-on-channel request".to_string(),
+            )
+        })?;
+
+        Ok(Nl80211RemainOnChannel {
+            cookie,
+            duration,
+            if_index,
+            handle,
+            events,
+        })
+    }
+}
+
+/// Remain-on-channel requested by
+/// [Nl80211RemainOnChannelRequest::start()].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Nl80211RemainOnChannel<S> {
+    /// Cookie identifying the remain-on-channel
+    pub cookie: u64,
+    /// Requested duration after capped by the wiphy
+    pub duration: Duration,
+    if_index: u32,
+    handle: Nl80211Handle,
+    events: Nl80211EventStream<S>,
+}
+
+impl<S> Nl80211RemainOnChannel<S>
+where
+    S: AsyncSocket,
+{
+    /// Wait for the device to be on the channel
+    /// (`NL80211_CMD_REMAIN_ON_CHANNEL` notification), the duration is
+    /// counted from then on.
+    pub async fn ready(
+        &mut self,
+    ) -> Result<Nl80211RemainOnChannelEvent, Nl80211Error> {
+        while let Some(event) = self.events.try_next().await? {
+            match event {
+                Nl80211Event::RemainOnChannel(e) if self.is_mine(&e) => {
+                    return Ok(e)
+                }
+                Nl80211Event::CancelRemainOnChannel(e) if self.is_mine(&e) => {
+                    return Err(Nl80211Error::RequestFailed(
+                        "Remain-on-channel expired before ready".to_string(),
+                    ))
+                }
+                _ => (),
+            }
+        }
+        Err(Nl80211Error::RequestFailed(
+            "nl80211 event stream closed before remain-on-channel ready"
+                .to_string(),
+        ))
+    }
+
+    /// Wait for the remain-on-channel to expire or be cancelled
+    /// (`NL80211_CMD_CANCEL_REMAIN_ON_CHANNEL` notification)
+    pub async fn expired(
+        mut self,
+    ) -> Result<Nl80211RemainOnChannelEvent, Nl80211Error> {
+        while let Some(event) = self.events.try_next().await? {
+            if let Nl80211Event::CancelRemainOnChannel(e) = event {
+                if self.is_mine(&e) {
+                    return Ok(e);
+                }
+            }
+        }
+        Err(Nl80211Error::RequestFailed(
+            "nl80211 event stream closed before remain-on-channel expired"
+                .to_string(),
+        ))
+    }
+
+    /// Leave the channel before the duration ends, the expired
+    /// notification is still sent by kernel
+    pub async fn cancel(&mut self) -> Result<(), Nl80211Error> {
+        let mut stream = Box::pin(
+            Nl80211CancelRemainOnChannelRequest::new(
+                self.handle.clone(),
+                self.if_index,
+                self.cookie,
+            )
+            .execute()
+            .await,
+        );
+        while stream.try_next().await?.is_some() {}
+        Ok(())
+    }
+
+    fn is_mine(&self, event: &Nl80211RemainOnChannelEvent) -> bool {
+        event.cookie == Some(self.cookie)
+            && event.if_index == Some(self.if_index)
+    }
+}
+
+/// Cancel remain-on-channel (`NL80211_CMD_CANCEL_REMAIN_ON_CHANNEL`)
+/// identified by the cookie
+pub struct Nl80211CancelRemainOnChannelRequest {
+    handle: Nl80211Handle,
+    attrs: Vec<Nl80211Attr>,
+}
+
+impl Nl80211CancelRemainOnChannelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        cookie: u64,
+    ) -> Self {
+        Nl80211CancelRemainOnChannelRequest {
+            handle,
+            attrs: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::Cookie(cookie),
+            ],
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211CancelRemainOnChannelRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::CancelRemainOnChannel,
+            attributes: attrs,
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
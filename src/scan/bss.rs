@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    Nl80211Attr, Nl80211BssCapabilities, Nl80211BssInfo, Nl80211BssUseFor,
+    Nl80211Command, Nl80211Element, Nl80211Error, Nl80211Message,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Basic Service Set found by scan, decoded from the reply of
+/// [crate::Nl80211ScanGetRequest] (equivalent to one entry of
+/// `iw dev DEVICE scan dump`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211Bss {
+    pub if_index: u32,
+    pub wdev: Option<u64>,
+    pub generation: Option<u32>,
+    pub bssid: [u8; ETH_ALEN],
+    /// Frequency in MHz
+    pub frequency: u32,
+    /// Frequency offset in KHz
+    pub frequency_offset: Option<u32>,
+    /// Timing Synchronization Function (TSF) in microsecond(μs)
+    pub tsf: Option<u64>,
+    pub beacon_interval: Option<u16>,
+    pub capability: Nl80211BssCapabilities,
+    /// Information elements from the probe response or beacon
+    pub ies: Vec<Nl80211Element>,
+    /// Information elements from the last beacon
+    pub beacon_ies: Vec<Nl80211Element>,
+    /// Signal strength in mBm (dBm * 100)
+    pub signal_mbm: Option<i32>,
+    /// Signal strength in unspecified units, scaled to 0..100
+    pub signal_unspec: Option<u8>,
+    /// Status of this BSS: 0 for authenticated, 1 for associated,
+    /// 2 for IBSS joined. `None` when not connected.
+    pub status: Option<u32>,
+    pub seen_ms_ago: Option<u32>,
+    /// `CLOCK_BOOTTIME` timestamp in nanoseconds when this entry was last
+    /// updated by a received frame.
+    pub last_seen_boottime: Option<u64>,
+    pub use_for: Option<Nl80211BssUseFor>,
+}
+
+impl TryFrom<Nl80211Message> for Nl80211Bss {
+    type Error = Nl80211Error;
+
+    fn try_from(msg: Nl80211Message) -> Result<Self, Self::Error> {
+        if msg.cmd != Nl80211Command::NewScanResults {
+            return Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                format!(
+                    "Expecting NL80211_CMD_NEW_SCAN_RESULTS for BSS, got {:?}",
+                    msg.cmd
+                ),
+            )));
+        }
+        let mut ret = Self::default();
+        let mut if_index = None;
+        let mut bssid = None;
+        let mut frequency = None;
+        for attr in msg.attributes {
+            match attr {
+                Nl80211Attr::IfIndex(d) => if_index = Some(d),
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(d),
+                Nl80211Attr::Generation(d) => ret.generation = Some(d),
+                Nl80211Attr::Bss(infos) => {
+                    for info in infos {
+                        match info {
+                            Nl80211BssInfo::Bssid(d) => bssid = Some(d),
+                            Nl80211BssInfo::Frequency(d) => {
+                                frequency = Some(d)
+                            }
+                            Nl80211BssInfo::FrequencyOffset(d) => {
+                                ret.frequency_offset = Some(d)
+                            }
+                            Nl80211BssInfo::Tsf(d) => ret.tsf = Some(d),
+                            Nl80211BssInfo::BeaconInterval(d) => {
+                                ret.beacon_interval = Some(d)
+                            }
+                            Nl80211BssInfo::Capability(d) => {
+                                ret.capability = d
+                            }
+                            Nl80211BssInfo::InformationElements(d) => {
+                                ret.ies = d
+                            }
+                            Nl80211BssInfo::BeaconInformationElements(d) => {
+                                ret.beacon_ies = d
+                            }
+                            Nl80211BssInfo::SignalMbm(d) => {
+                                ret.signal_mbm = Some(d)
+                            }
+                            Nl80211BssInfo::SignalUnspec(d) => {
+                                ret.signal_unspec = Some(d)
+                            }
+                            Nl80211BssInfo::Status(d) => ret.status = Some(d),
+                            Nl80211BssInfo::SeenMsAgo(d) => {
+                                ret.seen_ms_ago = Some(d)
+                            }
+                            Nl80211BssInfo::LastSeenBootTime(d) => {
+                                ret.last_seen_boottime = Some(d)
+                            }
+                            Nl80211BssInfo::UseFor(d) => {
+                                ret.use_for = Some(d)
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        match (if_index, bssid, frequency) {
+            (Some(if_index), Some(bssid), Some(frequency)) => {
+                ret.if_index = if_index;
+                ret.bssid = bssid;
+                ret.frequency = frequency;
+                Ok(ret)
+            }
+            _ => Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                "Scan reply does not contain NL80211_ATTR_IFINDEX, \
+                NL80211_BSS_BSSID or NL80211_BSS_FREQUENCY",
+            ))),
+        }
+    }
+}
+
+impl Nl80211Bss {
+    /// SSID from the information elements, `None` for hidden SSID
+    pub fn ssid(&self) -> Option<&str> {
+        self.ies.iter().chain(self.beacon_ies.iter()).find_map(|ie| {
+            if let Nl80211Element::Ssid(s) = ie {
+                if s.is_empty() {
+                    None
+                } else {
+                    Some(s.as_str())
+                }
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Signal strength in dBm
+    pub fn signal_dbm(&self) -> Option<f32> {
+        self.signal_mbm.map(|d| d as f32 / 100.0)
+    }
+}
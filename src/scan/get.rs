@@ -1,34 +1,42 @@
 // SPDX-License-Identifier: MIT
 
-use futures::TryStream;
+use std::convert::TryFrom;
+
+use futures::{future::ready, TryStream, TryStreamExt};
 use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
 use crate::{
-    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
-    Nl80211Message,
+    nl80211_execute, Nl80211Attr, Nl80211Bss, Nl80211Command, Nl80211Error,
+    Nl80211Handle, Nl80211Message,
 };
 
 pub struct Nl80211ScanGetRequest {
     handle: Nl80211Handle,
-    if_index: u32,
+    device: Nl80211Attr,
 }
 
 impl Nl80211ScanGetRequest {
     pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
-        Nl80211ScanGetRequest { handle, if_index }
+        Self::new_device(handle, Nl80211Attr::IfIndex(if_index))
+    }
+
+    /// Dump the scan results of the wireless device identified by `device`,
+    /// either [Nl80211Attr::IfIndex] or [Nl80211Attr::Wdev].
+    pub(crate) fn new_device(
+        handle: Nl80211Handle,
+        device: Nl80211Attr,
+    ) -> Self {
+        Nl80211ScanGetRequest { handle, device }
     }
 
     pub async fn execute(
         self,
     ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
     {
-        let Nl80211ScanGetRequest {
-            mut handle,
-            if_index,
-        } = self;
+        let Nl80211ScanGetRequest { mut handle, device } = self;
 
-        let attributes = vec![Nl80211Attr::IfIndex(if_index)];
+        let attributes = vec![device];
         let nl80211_msg = Nl80211Message {
             cmd: Nl80211Command::GetScan,
             attributes,
@@ -38,4 +46,13 @@ impl Nl80211ScanGetRequest {
 
         nl80211_execute(&mut handle, nl80211_msg, flags).await
     }
+
+    /// Execute the request and decode the replies into [Nl80211Bss]
+    pub async fn collect(self) -> Result<Vec<Nl80211Bss>, Nl80211Error> {
+        self.execute()
+            .await
+            .and_then(|msg| ready(Nl80211Bss::try_from(msg.payload)))
+            .try_collect()
+            .await
+    }
 }
@@ -8,6 +8,8 @@ use crate::{
     Nl80211ScanScheduleStopRequest, Nl80211ScanTriggerRequest,
     Nl80211SchedScanMatch, Nl80211SchedScanPlan,
 };
+#[cfg(feature = "tokio_socket")]
+use crate::Nl80211ScanTriggerWaitRequest;
 
 #[derive(Debug, Clone)]
 pub struct Nl80211ScanHandle(Nl80211Handle);
@@ -38,6 +40,22 @@ impl Nl80211ScanHandle {
         Nl80211ScanTriggerRequest::new(self.0.clone(), attributes)
     }
 
+    /// Trigger a scan and wait for kernel to finish it, then return the
+    /// scan results of the interface.
+    /// Unlike [Nl80211ScanHandle::trigger()], this listens on the "scan"
+    /// multicast group for `NL80211_CMD_NEW_SCAN_RESULTS` or
+    /// `NL80211_CMD_SCAN_ABORTED` of the interface. The `attributes` must
+    /// contain the interface index, for example generated by
+    /// [Nl80211Scan::new()], or the wireless device identifier
+    /// ([Nl80211Attr::Wdev]) for P2P device.
+    #[cfg(feature = "tokio_socket")]
+    pub fn trigger_and_wait(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211ScanTriggerWaitRequest {
+        Nl80211ScanTriggerWaitRequest::new(self.0.clone(), attributes)
+    }
+
     /// Start a scan schedule (equivalent to `iw dev DEVICE scan sched_start`)
     pub fn schedule_start(
         &mut self,
@@ -1,13 +1,17 @@
 // SPDX-License-Identifier: MIT
 
 mod attr;
+mod bss;
 mod bss_info;
 mod get;
 mod handle;
 mod schedule;
 mod trigger;
+#[cfg(feature = "tokio_socket")]
+mod wait;
 
 pub use self::attr::Nl80211ScanFlags;
+pub use self::bss::Nl80211Bss;
 pub use self::bss_info::{
     Nl80211BssCapabilities, Nl80211BssInfo, Nl80211BssUseFor,
 };
@@ -18,5 +22,7 @@ pub use self::schedule::{
     Nl80211SchedScanMatch, Nl80211SchedScanPlan,
 };
 pub use self::trigger::Nl80211ScanTriggerRequest;
+#[cfg(feature = "tokio_socket")]
+pub use self::wait::Nl80211ScanTriggerWaitRequest;
 
 pub(crate) use self::attr::{Nla80211ScanFreqNlas, Nla80211ScanSsidNlas};
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use futures::TryStreamExt;
+use netlink_sys::AsyncSocket;
+
+use crate::{
+    Nl80211Attr, Nl80211Bss, Nl80211Error, Nl80211Event, Nl80211EventStream,
+    Nl80211Handle, Nl80211MulticastGroup, Nl80211ScanGetRequest,
+    Nl80211ScanTriggerRequest,
+};
+
+const DEFAULT_SCAN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Trigger a scan, wait for the kernel to finish it and dump the results.
+/// Created by [crate::Nl80211ScanHandle::trigger_and_wait()].
+pub struct Nl80211ScanTriggerWaitRequest {
+    handle: Nl80211Handle,
+    attributes: Vec<Nl80211Attr>,
+    timeout: Duration,
+}
+
+impl Nl80211ScanTriggerWaitRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Self {
+        Nl80211ScanTriggerWaitRequest {
+            handle,
+            attributes,
+            timeout: DEFAULT_SCAN_TIMEOUT,
+        }
+    }
+
+    /// Maximum time to wait for the scan to finish, default is 30 seconds
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Return the [Nl80211Bss] list dumped once kernel notified
+    /// `NL80211_CMD_NEW_SCAN_RESULTS` for the scanned interface.
+    /// [Nl80211Error::ScanAborted] is returned if kernel aborted the scan,
+    /// [Nl80211Error::Timeout] if no notification arrived in time.
+    pub async fn execute(self) -> Result<Vec<Nl80211Bss>, Nl80211Error> {
+        let Nl80211ScanTriggerWaitRequest {
+            mut handle,
+            attributes,
+            timeout,
+        } = self;
+
+        let if_index = attributes.iter().find_map(|attr| {
+            if let Nl80211Attr::IfIndex(d) = attr {
+                Some(*d)
+            } else {
+                None
+            }
+        });
+        let wdev = attributes.iter().find_map(|attr| {
+            if let Nl80211Attr::Wdev(d) = attr {
+                Some(*d)
+            } else {
+                None
+            }
+        });
+        // P2P device has no netdev, its scan is identified by wdev only.
+        let device = if_index
+            .map(Nl80211Attr::IfIndex)
+            .or(wdev.map(Nl80211Attr::Wdev))
+            .ok_or_else(|| {
+                Nl80211Error::RequestFailed(
+                    "Scan attributes does not contain NL80211_ATTR_IFINDEX \
+                     or NL80211_ATTR_WDEV"
+                        .to_string(),
+                )
+            })?;
+
+        // Subscribe before triggering, otherwise fast scan could finish
+        // before we joined the multicast group.
+        let events = handle.events(&[Nl80211MulticastGroup::Scan]).await?;
+
+        let mut trigger = Box::pin(
+            Nl80211ScanTriggerRequest::new(handle.clone(), attributes)
+                .execute()
+                .await,
+        );
+        while trigger.try_next().await?.is_some() {}
+
+        tokio::time::timeout(timeout, wait_scan_done(events, if_index, wdev))
+            .await
+            .map_err(|_| {
+                Nl80211Error::Timeout(format!("scan on {device:?} to finish"))
+            })??;
+
+        Nl80211ScanGetRequest::new_device(handle, device)
+            .collect()
+            .await
+    }
+}
+
+async fn wait_scan_done<S>(
+    events: Nl80211EventStream<S>,
+    if_index: Option<u32>,
+    wdev: Option<u64>,
+) -> Result<(), Nl80211Error>
+where
+    S: AsyncSocket,
+{
+    let mut events = Box::pin(events);
+    while let Some(event) = events.try_next().await? {
+        let is_ours = is_scan_of(&event, if_index, wdev);
+        match event {
+            Nl80211Event::NewScanResults(_) if is_ours => return Ok(()),
+            Nl80211Event::ScanAborted(_) if is_ours => {
+                return Err(Nl80211Error::ScanAborted)
+            }
+            _ => (),
+        }
+    }
+    Err(Nl80211Error::RequestFailed(
+        "nl80211 event stream closed before scan finished".to_string(),
+    ))
+}
+
+// Kernel includes both `NL80211_ATTR_IFINDEX` and `NL80211_ATTR_WDEV` in scan
+// notifications of a netdev, but only `NL80211_ATTR_WDEV` for P2P device.
+fn is_scan_of(
+    event: &Nl80211Event,
+    if_index: Option<u32>,
+    wdev: Option<u64>,
+) -> bool {
+    (if_index.is_some() && event.if_index() == if_index)
+        || (wdev.is_some() && event.wdev() == wdev)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_scan_of_netdev() {
+        let event = Nl80211Event::NewScanResults(vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::Wdev(0x1_0000_0001),
+        ]);
+        assert!(is_scan_of(&event, Some(3), None));
+        assert!(is_scan_of(&event, None, Some(0x1_0000_0001)));
+        assert!(!is_scan_of(&event, Some(4), None));
+        assert!(!is_scan_of(&event, None, None));
+    }
+
+    #[test]
+    fn match_scan_of_p2p_device() {
+        let event =
+            Nl80211Event::ScanAborted(vec![Nl80211Attr::Wdev(0x1_0000_0002)]);
+        assert!(is_scan_of(&event, None, Some(0x1_0000_0002)));
+        assert!(is_scan_of(&event, Some(3), Some(0x1_0000_0002)));
+        assert!(!is_scan_of(&event, Some(3), None));
+        assert!(!is_scan_of(&event, None, Some(0x1_0000_0001)));
+    }
+}
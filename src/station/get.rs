@@ -1,12 +1,14 @@
 // SPDX-License-Identifier: MIT
 
-use futures::TryStream;
+use std::convert::TryFrom;
+
+use futures::{future::ready, TryStream, TryStreamExt};
 use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
 use crate::{
     nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
-    Nl80211Message,
+    Nl80211Message, Nl80211Station,
 };
 
 const ETH_ALEN: usize = 6;
@@ -54,4 +56,13 @@ impl Nl80211StationGetRequest {
 
         nl80211_execute(&mut handle, nl80211_msg, flags).await
     }
+
+    /// Execute the request and decode the replies into [Nl80211Station]
+    pub async fn collect(self) -> Result<Vec<Nl80211Station>, Nl80211Error> {
+        self.execute()
+            .await
+            .and_then(|msg| ready(Nl80211Station::try_from(msg.payload)))
+            .try_collect()
+            .await
+    }
 }
@@ -1,6 +1,11 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{Nl80211Handle, Nl80211StationGetRequest};
+use crate::{
+    Nl80211Handle, Nl80211StationDelRequest, Nl80211StationGetRequest,
+    Nl80211StationNewRequest, Nl80211StationSetRequest,
+};
+
+const ETH_ALEN: usize = 6;
 
 pub struct Nl80211StationHandle(Nl80211Handle);
 
@@ -14,4 +19,49 @@ impl Nl80211StationHandle {
     pub fn dump(&mut self, if_index: u32) -> Nl80211StationGetRequest {
         Nl80211StationGetRequest::new(self.0.clone(), if_index, None)
     }
+
+    /// Add a station, e.g. a client associated to AP with MLME handled by
+    /// userspace
+    pub fn add(
+        &mut self,
+        if_index: u32,
+        mac: [u8; ETH_ALEN],
+        aid: u16,
+        listen_interval: u16,
+        supported_rates: Vec<u8>,
+    ) -> Nl80211StationNewRequest {
+        Nl80211StationNewRequest::new(
+            self.0.clone(),
+            if_index,
+            mac,
+            aid,
+            listen_interval,
+            supported_rates,
+        )
+    }
+
+    /// Change the station
+    /// (equivalent to `iw dev DEV station set MAC`)
+    pub fn set(
+        &mut self,
+        if_index: u32,
+        mac: [u8; ETH_ALEN],
+    ) -> Nl80211StationSetRequest {
+        Nl80211StationSetRequest::new(self.0.clone(), if_index, mac)
+    }
+
+    /// Remove the station
+    /// (equivalent to `iw dev DEV station del MAC`)
+    pub fn del(
+        &mut self,
+        if_index: u32,
+        mac: [u8; ETH_ALEN],
+    ) -> Nl80211StationDelRequest {
+        Nl80211StationDelRequest::new(self.0.clone(), if_index, Some(mac))
+    }
+
+    /// Remove all the stations
+    pub fn del_all(&mut self, if_index: u32) -> Nl80211StationDelRequest {
+        Nl80211StationDelRequest::new(self.0.clone(), if_index, None)
+    }
 }
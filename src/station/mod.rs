@@ -2,15 +2,22 @@
 
 mod get;
 mod handle;
+mod modify;
 mod rate_info;
+mod sta;
 mod station_info;
 
 pub use self::get::Nl80211StationGetRequest;
 pub use self::handle::Nl80211StationHandle;
+pub use self::modify::{
+    Nl80211StationDelRequest, Nl80211StationNewRequest,
+    Nl80211StationSetRequest,
+};
 pub use self::rate_info::{
     Nl80211EhtGi, Nl80211EhtRuAllocation, Nl80211HeGi, Nl80211HeRuAllocation,
     Nl80211RateInfo,
 };
+pub use self::sta::Nl80211Station;
 pub use self::station_info::{
     Nl80211MeshPowerMode, Nl80211PeerLinkState, Nl80211StationBssParam,
     Nl80211StationFlag, Nl80211StationFlagUpdate, Nl80211StationInfo,
@@ -0,0 +1,324 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211AttrsBuilder, Nl80211BssCapabilities,
+    Nl80211Command, Nl80211ElementHtCap, Nl80211Error, Nl80211Handle,
+    Nl80211He6GhzCapa, Nl80211Message, Nl80211ReasonCode, Nl80211StationFlag,
+    Nl80211StationFlagUpdate, Nl80211VhtCapability,
+};
+
+const ETH_ALEN: usize = 6;
+
+// Management frame subtype field (not the shifted frame control value)
+const MGMT_SUBTYPE_DISASSOC: u8 = 0xa;
+
+/// Add a station (`NL80211_CMD_NEW_STATION`), e.g. a client associated to
+/// an AP with MLME handled by userspace.
+///
+/// A station added to an AP interface is assumed to be associated
+/// already, unless the driver supports
+/// [crate::Nl80211Features::FullApClientState] and the
+/// [Nl80211StationFlag::Associated] flag is left unset.
+pub struct Nl80211StationNewRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211StationNewRequest>,
+    flags: Option<Nl80211StationFlagUpdate>,
+}
+
+impl Nl80211StationNewRequest {
+    /// The supported rates are in 500 kbps units as in the Supported
+    /// Rates and Extended Supported Rates elements
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; ETH_ALEN],
+        aid: u16,
+        listen_interval: u16,
+        supported_rates: Vec<u8>,
+    ) -> Self {
+        Nl80211StationNewRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::Mac(mac))
+                .replace(Nl80211Attr::StaAid(aid))
+                .replace(Nl80211Attr::StaListenInterval(listen_interval))
+                .replace(Nl80211Attr::StaSupportedRates(supported_rates)),
+            flags: None,
+        }
+    }
+
+    /// Capability information from the (re)association request
+    pub fn with_capability(mut self, capa: Nl80211BssCapabilities) -> Self {
+        self.attrs =
+            self.attrs.replace(Nl80211Attr::StaCapability(capa.bits()));
+        self
+    }
+
+    /// Extended capabilities element body from the (re)association
+    /// request
+    pub fn with_ext_capability(mut self, ext_capa: Vec<u8>) -> Self {
+        self.attrs =
+            self.attrs.replace(Nl80211Attr::StaExtCapability(ext_capa));
+        self
+    }
+
+    pub fn with_ht_capability(mut self, ht_cap: Nl80211ElementHtCap) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::HtCapability(ht_cap));
+        self
+    }
+
+    pub fn with_vht_capability(
+        mut self,
+        vht_cap: Nl80211VhtCapability,
+    ) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::VhtCap(vht_cap));
+        self
+    }
+
+    /// HE capabilities element body without the element ID extension
+    pub fn with_he_capability(mut self, he_cap: Vec<u8>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::HeCapability(he_cap));
+        self
+    }
+
+    pub fn with_he_6ghz_capability(
+        mut self,
+        he_6ghz_cap: Nl80211He6GhzCapa,
+    ) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::He6GhzCapability(he_6ghz_cap));
+        self
+    }
+
+    /// EHT capabilities element body without the element ID extension
+    pub fn with_eht_capability(mut self, eht_cap: Vec<u8>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::EhtCapability(eht_cap));
+        self
+    }
+
+    /// Replace all the station flags to update
+    pub fn with_flags(mut self, flags: Nl80211StationFlagUpdate) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Set or clear a single station flag, e.g.
+    /// [Nl80211StationFlag::Wme] or [Nl80211StationFlag::Mfp]
+    pub fn with_flag(mut self, flag: Nl80211StationFlag, value: bool) -> Self {
+        self.flags = Some(self.flags.unwrap_or_default().with(flag, value));
+        self
+    }
+
+    /// Add the station on specified MLO link
+    pub fn with_link_id(mut self, link_id: u8) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MloLinkId(link_id));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211StationNewRequest {
+            mut handle,
+            mut attrs,
+            flags: sta_flags,
+        } = self;
+        if let Some(sta_flags) = sta_flags {
+            attrs = attrs.replace(Nl80211Attr::StaFlags2(sta_flags));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::NewStation,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+/// Change a station (`NL80211_CMD_SET_STATION`), e.g. authorize the
+/// client once the 4-way handshake completed.
+pub struct Nl80211StationSetRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211StationSetRequest>,
+    flags: Option<Nl80211StationFlagUpdate>,
+}
+
+impl Nl80211StationSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; ETH_ALEN],
+    ) -> Self {
+        Nl80211StationSetRequest {
+            handle,
+            attrs: Nl80211AttrsBuilder::new()
+                .if_index(if_index)
+                .replace(Nl80211Attr::Mac(mac)),
+            flags: None,
+        }
+    }
+
+    /// Mark the station as authorized (802.1X) or not, data frames other
+    /// than EAPOL are only passed for authorized station
+    pub fn with_authorized(self, authorized: bool) -> Self {
+        self.with_flag(Nl80211StationFlag::Authorized, authorized)
+    }
+
+    /// Replace all the station flags to update
+    pub fn with_flags(mut self, flags: Nl80211StationFlagUpdate) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Set or clear a single station flag
+    pub fn with_flag(mut self, flag: Nl80211StationFlag, value: bool) -> Self {
+        self.flags = Some(self.flags.unwrap_or_default().with(flag, value));
+        self
+    }
+
+    /// Only allowed when the station transitions into associated state
+    pub fn with_aid(mut self, aid: u16) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::StaAid(aid));
+        self
+    }
+
+    pub fn with_listen_interval(mut self, listen_interval: u16) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::StaListenInterval(listen_interval));
+        self
+    }
+
+    /// The supported rates are in 500 kbps units
+    pub fn with_supported_rates(mut self, supported_rates: Vec<u8>) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::StaSupportedRates(supported_rates));
+        self
+    }
+
+    pub fn with_capability(mut self, capa: Nl80211BssCapabilities) -> Self {
+        self.attrs =
+            self.attrs.replace(Nl80211Attr::StaCapability(capa.bits()));
+        self
+    }
+
+    pub fn with_ht_capability(mut self, ht_cap: Nl80211ElementHtCap) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::HtCapability(ht_cap));
+        self
+    }
+
+    pub fn with_vht_capability(
+        mut self,
+        vht_cap: Nl80211VhtCapability,
+    ) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::VhtCap(vht_cap));
+        self
+    }
+
+    /// HE capabilities element body without the element ID extension
+    pub fn with_he_capability(mut self, he_cap: Vec<u8>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::HeCapability(he_cap));
+        self
+    }
+
+    /// EHT capabilities element body without the element ID extension
+    pub fn with_eht_capability(mut self, eht_cap: Vec<u8>) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::EhtCapability(eht_cap));
+        self
+    }
+
+    /// Change the station on specified MLO link
+    pub fn with_link_id(mut self, link_id: u8) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::MloLinkId(link_id));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211StationSetRequest {
+            mut handle,
+            mut attrs,
+            flags: sta_flags,
+        } = self;
+        if let Some(sta_flags) = sta_flags {
+            attrs = attrs.replace(Nl80211Attr::StaFlags2(sta_flags));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetStation,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+/// Remove a station (`NL80211_CMD_DEL_STATION`), or all the stations if
+/// no MAC address specified. On AP interface kernel sends a
+/// deauthentication frame to the station before removing it.
+pub struct Nl80211StationDelRequest {
+    handle: Nl80211Handle,
+    attrs: Nl80211AttrsBuilder<Nl80211StationDelRequest>,
+}
+
+impl Nl80211StationDelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: Option<[u8; ETH_ALEN]>,
+    ) -> Self {
+        let mut attrs = Nl80211AttrsBuilder::new().if_index(if_index);
+        if let Some(mac) = mac {
+            attrs = attrs.replace(Nl80211Attr::Mac(mac));
+        }
+        Nl80211StationDelRequest { handle, attrs }
+    }
+
+    /// Reason code of the deauthentication (by default) or
+    /// disassociation frame
+    pub fn with_reason(mut self, reason: Nl80211ReasonCode) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::ReasonCode(reason));
+        self
+    }
+
+    /// Send disassociation frame with the reason code instead of
+    /// deauthentication frame
+    pub fn with_disassoc(mut self, reason: Nl80211ReasonCode) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::ReasonCode(reason))
+            .replace(Nl80211Attr::MgmtSubtype(MGMT_SUBTYPE_DISASSOC));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211StationDelRequest { mut handle, attrs } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::DelStation,
+            attributes: attrs.build(),
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Message,
+    Nl80211RateInfo, Nl80211StationFlagUpdate, Nl80211StationInfo,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Station(peer) information, decoded from the reply of
+/// [crate::Nl80211StationGetRequest] (equivalent to one entry of
+/// `iw dev DEVICE station dump`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211Station {
+    pub if_index: u32,
+    pub mac: [u8; ETH_ALEN],
+    pub generation: Option<u32>,
+    /// Time since last activity (msecs)
+    pub inactive_time: Option<u32>,
+    /// Total received bytes, prefer 64 bits counter if available
+    pub rx_bytes: Option<u64>,
+    /// Total transmitted bytes, prefer 64 bits counter if available
+    pub tx_bytes: Option<u64>,
+    pub rx_packets: Option<u32>,
+    pub tx_packets: Option<u32>,
+    pub tx_retries: Option<u32>,
+    pub tx_failed: Option<u32>,
+    /// Signal strength of last received PPDU (dBm)
+    pub signal: Option<i8>,
+    /// Signal strength average (dBm)
+    pub signal_avg: Option<i8>,
+    pub tx_bitrate: Vec<Nl80211RateInfo>,
+    pub rx_bitrate: Vec<Nl80211RateInfo>,
+    /// Time since the station is last connected (seconds)
+    pub connected_time: Option<u32>,
+    /// Expected throughput considering also the 802.11 header (kbps)
+    pub expected_throughput: Option<u32>,
+    pub beacon_loss: Option<u32>,
+    pub flags: Option<Nl80211StationFlagUpdate>,
+    /// All the station information attributes
+    pub info: Vec<Nl80211StationInfo>,
+}
+
+impl TryFrom<Nl80211Message> for Nl80211Station {
+    type Error = Nl80211Error;
+
+    fn try_from(msg: Nl80211Message) -> Result<Self, Self::Error> {
+        if msg.cmd != Nl80211Command::NewStation {
+            return Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                format!(
+                    "Expecting NL80211_CMD_NEW_STATION for station, got {:?}",
+                    msg.cmd
+                ),
+            )));
+        }
+        let mut ret = Self::default();
+        let mut if_index = None;
+        let mut mac = None;
+        for attr in msg.attributes {
+            match attr {
+                Nl80211Attr::IfIndex(d) => if_index = Some(d),
+                Nl80211Attr::Mac(d) => mac = Some(d),
+                Nl80211Attr::Generation(d) => ret.generation = Some(d),
+                Nl80211Attr::StationInfo(info) => {
+                    for nla in info.as_slice() {
+                        ret.apply_info(nla);
+                    }
+                    ret.info = info;
+                }
+                _ => (),
+            }
+        }
+        match (if_index, mac) {
+            (Some(if_index), Some(mac)) => {
+                ret.if_index = if_index;
+                ret.mac = mac;
+                Ok(ret)
+            }
+            _ => Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                "Station reply does not contain NL80211_ATTR_IFINDEX or \
+                NL80211_ATTR_MAC",
+            ))),
+        }
+    }
+}
+
+impl Nl80211Station {
+    fn apply_info(&mut self, info: &Nl80211StationInfo) {
+        match info {
+            Nl80211StationInfo::InactiveTime(d) => {
+                self.inactive_time = Some(*d)
+            }
+            Nl80211StationInfo::RxBytes(d) => {
+                if self.rx_bytes.is_none() {
+                    self.rx_bytes = Some(*d as u64)
+                }
+            }
+            Nl80211StationInfo::TxBytes(d) => {
+                if self.tx_bytes.is_none() {
+                    self.tx_bytes = Some(*d as u64)
+                }
+            }
+            Nl80211StationInfo::RxBytes64(d) => self.rx_bytes = Some(*d),
+            Nl80211StationInfo::TxBytes64(d) => self.tx_bytes = Some(*d),
+            Nl80211StationInfo::RxPackets(d) => self.rx_packets = Some(*d),
+            Nl80211StationInfo::TxPackets(d) => self.tx_packets = Some(*d),
+            Nl80211StationInfo::TxRetries(d) => self.tx_retries = Some(*d),
+            Nl80211StationInfo::TxFailed(d) => self.tx_failed = Some(*d),
+            Nl80211StationInfo::Signal(d) => self.signal = Some(*d),
+            Nl80211StationInfo::SignalAvg(d) => self.signal_avg = Some(*d),
+            Nl80211StationInfo::TxBitrate(d) => self.tx_bitrate = d.clone(),
+            Nl80211StationInfo::RxBitrate(d) => self.rx_bitrate = d.clone(),
+            Nl80211StationInfo::ConnectedTime(d) => {
+                self.connected_time = Some(*d)
+            }
+            Nl80211StationInfo::ExpectedThroughput(d) => {
+                self.expected_throughput = Some(*d)
+            }
+            Nl80211StationInfo::BeaconLoss(d) => self.beacon_loss = Some(*d),
+            Nl80211StationInfo::StationFlags(d) => self.flags = Some(d.clone()),
+            _ => (),
+        }
+    }
+}
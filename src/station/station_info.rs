@@ -7,7 +7,7 @@ use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
     parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
-    Emitable, Parseable,
+    DecodeError, Emitable, Parseable,
 };
 
 use std::fmt::Debug;
@@ -861,7 +861,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct Nl80211StationFlagUpdate {
     /// Mask of station flags to set
     mask: VecNl80211StationFlag,
@@ -869,6 +869,71 @@ pub struct Nl80211StationFlagUpdate {
     set: VecNl80211StationFlag,
 }
 
+impl Nl80211StationFlagUpdate {
+    pub const LENGTH: usize = 8;
+
+    /// Update the flags in `mask`, those also in `set` are set and the
+    /// others cleared. Flags not in `mask` are left unchanged.
+    pub fn new(
+        mask: &[Nl80211StationFlag],
+        set: &[Nl80211StationFlag],
+    ) -> Self {
+        Self {
+            mask: VecNl80211StationFlag(mask.to_vec()),
+            set: VecNl80211StationFlag(
+                set.iter().filter(|f| mask.contains(f)).copied().collect(),
+            ),
+        }
+    }
+
+    /// Include the flag in the mask with specified value
+    pub fn with(mut self, flag: Nl80211StationFlag, value: bool) -> Self {
+        self.mask.0.retain(|f| *f != flag);
+        self.set.0.retain(|f| *f != flag);
+        self.mask.0.push(flag);
+        if value {
+            self.set.0.push(flag);
+        }
+        self
+    }
+
+    /// Value of the flag, `None` if not included in the mask
+    pub fn get(&self, flag: Nl80211StationFlag) -> Option<bool> {
+        if self.mask.0.contains(&flag) {
+            Some(self.set.0.contains(&flag))
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid length of nl80211_sta_flag_update, expected length \
+                {} got {:?}",
+                Self::LENGTH,
+                payload
+            )
+            .into());
+        }
+        Ok(Self {
+            mask: NativeEndian::read_u32(&payload[0..4]).into(),
+            set: NativeEndian::read_u32(&payload[4..8]).into(),
+        })
+    }
+}
+
+impl Emitable for Nl80211StationFlagUpdate {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(&mut buffer[0..4], (&self.mask).into());
+        NativeEndian::write_u32(&mut buffer[4..8], (&self.set).into());
+    }
+}
+
 pub const NL80211_STA_FLAG_AUTHORIZED: u32 = 1;
 pub const NL80211_STA_FLAG_SHORT_PREAMBLE: u32 = 2;
 pub const NL80211_STA_FLAG_WME: u32 = 3;
@@ -957,30 +1022,17 @@ impl std::fmt::Display for Nl80211StationFlag {
     }
 }
 
-const ALL_STATION_FLAGS: [Nl80211StationFlag; 7] = [
-    Nl80211StationFlag::Associated,
-    Nl80211StationFlag::Authenticated,
-    Nl80211StationFlag::Authorized,
-    Nl80211StationFlag::Mfp,
-    Nl80211StationFlag::ShortPreamble,
-    Nl80211StationFlag::TdlsPeer,
-    Nl80211StationFlag::Wme,
-];
-
+// The flag values are bit positions in the mask of
+// `struct nl80211_sta_flag_update`
 impl From<u32> for VecNl80211StationFlag {
     fn from(d: u32) -> Self {
-        let mut got: u32 = 0;
         let mut ret = Vec::new();
 
-        for flag in ALL_STATION_FLAGS {
-            if (d & u32::from(flag)) > 0 {
-                ret.push(flag);
-                got += u32::from(flag);
+        for pos in 0..u32::BITS {
+            if (d & (1 << pos)) > 0 {
+                ret.push(Nl80211StationFlag::from(pos));
             }
         }
-        if got != d {
-            ret.push(Nl80211StationFlag::Other(d - got));
-        }
 
         Self(ret)
     }
@@ -990,7 +1042,7 @@ impl From<&VecNl80211StationFlag> for u32 {
     fn from(v: &VecNl80211StationFlag) -> u32 {
         let mut d: u32 = 0;
         for flag in &v.0 {
-            d += u32::from(*flag);
+            d |= 1u32.checked_shl(u32::from(*flag)).unwrap_or_default();
         }
         d
     }
@@ -1034,3 +1086,28 @@ impl From<Nl80211MeshPowerMode> for u32 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sta_flag_update() {
+        // mask: AUTHORIZED | WME | ASSOCIATED | bit 9
+        // set: AUTHORIZED | ASSOCIATED
+        let mut payload = [0u8; Nl80211StationFlagUpdate::LENGTH];
+        NativeEndian::write_u32(&mut payload[0..4], 0x28a);
+        NativeEndian::write_u32(&mut payload[4..8], 0x82);
+
+        let update = Nl80211StationFlagUpdate::parse(&payload).unwrap();
+        assert_eq!(update.get(Nl80211StationFlag::Authorized), Some(true));
+        assert_eq!(update.get(Nl80211StationFlag::Wme), Some(false));
+        assert_eq!(update.get(Nl80211StationFlag::Associated), Some(true));
+        assert_eq!(update.get(Nl80211StationFlag::Other(9)), Some(false));
+        assert_eq!(update.get(Nl80211StationFlag::Mfp), None);
+
+        let mut buffer = [0u8; Nl80211StationFlagUpdate::LENGTH];
+        update.emit(&mut buffer);
+        assert_eq!(buffer, payload);
+    }
+}
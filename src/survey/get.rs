@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use futures::{future::ready, TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211SurveyInfo,
+};
+
+pub struct Nl80211SurveyGetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    radio_stats: bool,
+}
+
+impl Nl80211SurveyGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211SurveyGetRequest {
+            handle,
+            if_index,
+            radio_stats: false,
+        }
+    }
+
+    /// Also request the radio-wide statistics, reported by an additional
+    /// entry without frequency
+    pub fn with_radio_stats(mut self) -> Self {
+        self.radio_stats = true;
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211SurveyGetRequest {
+            mut handle,
+            if_index,
+            radio_stats,
+        } = self;
+
+        let mut attributes = vec![Nl80211Attr::IfIndex(if_index)];
+        if radio_stats {
+            attributes.push(Nl80211Attr::SurveyRadioStats);
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::GetSurvey,
+            attributes,
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+
+    /// Execute the request and decode the replies into [Nl80211SurveyInfo]
+    pub async fn collect(self) -> Result<Vec<Nl80211SurveyInfo>, Nl80211Error> {
+        self.execute()
+            .await
+            .and_then(|msg| ready(Nl80211SurveyInfo::try_from(msg.payload)))
+            .try_collect()
+            .await
+    }
+}
@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Message,
+    Nl80211SurveyInfoAttr,
+};
+
+/// Channel survey, decoded from the reply of [crate::Nl80211SurveyGetRequest]
+/// (equivalent to one entry of `iw dev DEVICE survey dump`).
+///
+/// The time counters are accumulated by driver since the channel was
+/// first surveyed, use [Nl80211SurveyInfo::utilisation()] to compare two
+/// snapshots.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211SurveyInfo {
+    pub if_index: u32,
+    /// Center frequency of channel in MHz, not included in the radio-wide
+    /// entry requested by [crate::Nl80211SurveyGetRequest::with_radio_stats]
+    pub frequency: Option<u32>,
+    /// Noise level of channel (dBm)
+    pub noise: Option<i8>,
+    /// Channel is currently being used
+    pub in_use: bool,
+    /// Time (msecs) the radio was turned on
+    pub time: Option<u64>,
+    /// Time (msecs) the primary channel was sensed busy
+    pub time_busy: Option<u64>,
+    /// Time (msecs) the extension channel was sensed busy
+    pub time_ext_busy: Option<u64>,
+    /// Time (msecs) the radio spent receiving data
+    pub time_rx: Option<u64>,
+    /// Time (msecs) the radio spent transmitting data
+    pub time_tx: Option<u64>,
+    /// Time (msecs) the radio spent for scan
+    pub time_scan: Option<u64>,
+    /// Time (msecs) the radio spent receiving data on a local BSS
+    pub time_bss_rx: Option<u64>,
+    /// Center frequency offset in KHz
+    pub frequency_offset: Option<u32>,
+    /// All the survey information attributes
+    pub info: Vec<Nl80211SurveyInfoAttr>,
+}
+
+impl TryFrom<Nl80211Message> for Nl80211SurveyInfo {
+    type Error = Nl80211Error;
+
+    fn try_from(msg: Nl80211Message) -> Result<Self, Self::Error> {
+        if msg.cmd != Nl80211Command::NewSurveyResults {
+            return Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                format!(
+                    "Expecting NL80211_CMD_NEW_SURVEY_RESULTS for survey, \
+                    got {:?}",
+                    msg.cmd
+                ),
+            )));
+        }
+        let mut ret = Self::default();
+        let mut if_index = None;
+        for attr in msg.attributes {
+            match attr {
+                Nl80211Attr::IfIndex(d) => if_index = Some(d),
+                Nl80211Attr::SurveyInfo(info) => {
+                    for nla in info.as_slice() {
+                        ret.apply_info(nla);
+                    }
+                    ret.info = info;
+                }
+                _ => (),
+            }
+        }
+        match if_index {
+            Some(if_index) => {
+                ret.if_index = if_index;
+                Ok(ret)
+            }
+            None => Err(Nl80211Error::DecodeFailed(DecodeError::from(
+                "Survey reply does not contain NL80211_ATTR_IFINDEX",
+            ))),
+        }
+    }
+}
+
+impl Nl80211SurveyInfo {
+    fn apply_info(&mut self, info: &Nl80211SurveyInfoAttr) {
+        match info {
+            Nl80211SurveyInfoAttr::Frequency(d) => self.frequency = Some(*d),
+            Nl80211SurveyInfoAttr::Noise(d) => self.noise = Some(*d),
+            Nl80211SurveyInfoAttr::InUse => self.in_use = true,
+            Nl80211SurveyInfoAttr::Time(d) => self.time = Some(*d),
+            Nl80211SurveyInfoAttr::TimeBusy(d) => self.time_busy = Some(*d),
+            Nl80211SurveyInfoAttr::TimeExtBusy(d) => {
+                self.time_ext_busy = Some(*d)
+            }
+            Nl80211SurveyInfoAttr::TimeRx(d) => self.time_rx = Some(*d),
+            Nl80211SurveyInfoAttr::TimeTx(d) => self.time_tx = Some(*d),
+            Nl80211SurveyInfoAttr::TimeScan(d) => self.time_scan = Some(*d),
+            Nl80211SurveyInfoAttr::TimeBssRx(d) => self.time_bss_rx = Some(*d),
+            Nl80211SurveyInfoAttr::FrequencyOffset(d) => {
+                self.frequency_offset = Some(*d)
+            }
+            _ => (),
+        }
+    }
+
+    /// Fraction (`0.0` to `1.0`) of time the channel was sensed busy
+    /// between the `previous` snapshot of the same channel and this one.
+    ///
+    /// Returns `None` when the channels differ, the driver does not report
+    /// the active and busy time, or the counters did not advance (e.g.
+    /// reset by driver).
+    pub fn utilisation(&self, previous: &Self) -> Option<f64> {
+        if self.frequency != previous.frequency {
+            return None;
+        }
+        let time = self.time?.checked_sub(previous.time?)?;
+        let busy = self.time_busy?.checked_sub(previous.time_busy?)?;
+        if time == 0 {
+            return None;
+        }
+        Some((busy as f64 / time as f64).min(1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+
+    use super::*;
+
+    fn survey(freq: u32, time: u64, time_busy: u64) -> Nl80211SurveyInfo {
+        Nl80211SurveyInfo {
+            frequency: Some(freq),
+            time: Some(time),
+            time_busy: Some(time_busy),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn survey_info_round_trip() {
+        let info = vec![
+            Nl80211SurveyInfoAttr::Frequency(5180),
+            Nl80211SurveyInfoAttr::Noise(-92),
+            Nl80211SurveyInfoAttr::InUse,
+            Nl80211SurveyInfoAttr::Time(1_000_000),
+            Nl80211SurveyInfoAttr::TimeBusy(250_000),
+            Nl80211SurveyInfoAttr::TimeExtBusy(1_000),
+            Nl80211SurveyInfoAttr::TimeRx(120_000),
+            Nl80211SurveyInfoAttr::TimeTx(80_000),
+            Nl80211SurveyInfoAttr::TimeScan(3_000),
+            Nl80211SurveyInfoAttr::TimeBssRx(60_000),
+            Nl80211SurveyInfoAttr::FrequencyOffset(500),
+        ];
+        let attr = Nl80211Attr::SurveyInfo(info.clone());
+        let mut buffer = vec![0u8; attr.buffer_len()];
+        attr.emit(&mut buffer);
+        let parsed = Nl80211Attr::parse(&NlaBuffer::new(&buffer)).unwrap();
+        assert_eq!(parsed, attr);
+
+        let survey = Nl80211SurveyInfo::try_from(Nl80211Message {
+            cmd: Nl80211Command::NewSurveyResults,
+            attributes: vec![Nl80211Attr::IfIndex(3), parsed],
+        })
+        .unwrap();
+        assert_eq!(
+            survey,
+            Nl80211SurveyInfo {
+                if_index: 3,
+                frequency: Some(5180),
+                noise: Some(-92),
+                in_use: true,
+                time: Some(1_000_000),
+                time_busy: Some(250_000),
+                time_ext_busy: Some(1_000),
+                time_rx: Some(120_000),
+                time_tx: Some(80_000),
+                time_scan: Some(3_000),
+                time_bss_rx: Some(60_000),
+                frequency_offset: Some(500),
+                info,
+            }
+        );
+    }
+
+    #[test]
+    fn utilisation_between_snapshots() {
+        let previous = survey(5180, 1000, 200);
+        assert_eq!(survey(5180, 2000, 700).utilisation(&previous), Some(0.5));
+        // Busy time of the radio could exceed the active time of channel
+        assert_eq!(survey(5180, 1100, 400).utilisation(&previous), Some(1.0));
+    }
+
+    #[test]
+    fn utilisation_of_invalid_snapshots() {
+        let previous = survey(5180, 1000, 200);
+        // Other channel
+        assert_eq!(survey(5200, 2000, 700).utilisation(&previous), None);
+        // Counters going backwards, e.g. reset by driver
+        assert_eq!(survey(5180, 500, 300).utilisation(&previous), None);
+        assert_eq!(survey(5180, 2000, 100).utilisation(&previous), None);
+        // Counters not advanced
+        assert_eq!(survey(5180, 1000, 200).utilisation(&previous), None);
+        // Busy time not reported
+        let mut current = survey(5180, 2000, 700);
+        current.time_busy = None;
+        assert_eq!(current.utilisation(&previous), None);
+    }
+}
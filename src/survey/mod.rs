@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod info;
+
+pub use self::attr::Nl80211SurveyInfoAttr;
+pub use self::get::Nl80211SurveyGetRequest;
+pub use self::handle::Nl80211SurveyHandle;
+pub use self::info::Nl80211SurveyInfo;
@@ -84,6 +84,47 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Band {
     }
 }
 
+impl Nl80211Band {
+    /// Merge band information of the same band from another message of split
+    /// wiphy dump. Kernel might split the frequency list of a band into
+    /// several messages, those frequencies are appended; other band
+    /// information is overridden by the newer one.
+    pub(crate) fn merge(&mut self, other: Nl80211Band) {
+        for info in other.info {
+            match info {
+                Nl80211BandInfo::Freqs(freqs) => {
+                    let existing = self.info.iter_mut().find_map(|i| {
+                        if let Nl80211BandInfo::Freqs(f) = i {
+                            Some(f)
+                        } else {
+                            None
+                        }
+                    });
+                    if let Some(existing) = existing {
+                        for freq in freqs {
+                            if !existing.iter().any(|f| f.index == freq.index)
+                            {
+                                existing.push(freq);
+                            }
+                        }
+                    } else {
+                        self.info.push(Nl80211BandInfo::Freqs(freqs));
+                    }
+                }
+                info => {
+                    if let Some(pos) =
+                        self.info.iter().position(|i| i.kind() == info.kind())
+                    {
+                        self.info[pos] = info;
+                    } else {
+                        self.info.push(info);
+                    }
+                }
+            }
+        }
+    }
+}
+
 const NL80211_BAND_2GHZ: u16 = 0;
 const NL80211_BAND_5GHZ: u16 = 1;
 const NL80211_BAND_60GHZ: u16 = 2;
@@ -1,13 +1,12 @@
 // SPDX-License-Identifier: MIT
 
-use futures::TryStream;
-use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+use futures::{TryStream, TryStreamExt};
 use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
 use crate::{
     nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
-    Nl80211Message,
+    Nl80211Message, Nl80211Wiphy,
 };
 
 pub struct Nl80211WiphyGetRequest {
@@ -35,6 +34,18 @@ impl Nl80211WiphyGetRequest {
         nl80211_execute(&mut handle, message, flags).await
     }
 
+    /// Execute the request and merge the split replies into one
+    /// [Nl80211Wiphy] per radio
+    pub async fn collect(self) -> Result<Vec<Nl80211Wiphy>, Nl80211Error> {
+        let msgs: Vec<Nl80211Message> = self
+            .execute()
+            .await
+            .map_ok(|msg| msg.payload)
+            .try_collect()
+            .await?;
+        Nl80211Wiphy::from_split_dump(msgs)
+    }
+
     /// Lookup a wiphy by index
     pub fn match_index(mut self, index: u32) -> Self {
         self.message.attributes.push(Nl80211Attr::Wiphy(index));
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{Nl80211Handle, Nl80211WiphyGetRequest};
+use crate::{Nl80211Handle, Nl80211WiphyGetRequest, Nl80211WiphySetRequest};
 
 #[derive(Debug)]
 pub struct Nl80211WiphyHandle(Nl80211Handle);
@@ -15,4 +15,10 @@ impl Nl80211WiphyHandle {
     pub fn get(&mut self) -> Nl80211WiphyGetRequest {
         Nl80211WiphyGetRequest::new(self.0.clone())
     }
+
+    /// Change the settings of specified wiphy index
+    /// (equivalent to `iw phy PHY set`)
+    pub fn set(&mut self, wiphy: u32) -> Nl80211WiphySetRequest {
+        Nl80211WiphySetRequest::new(self.0.clone(), wiphy)
+    }
 }
@@ -6,16 +6,20 @@ mod command;
 mod get;
 mod handle;
 mod ifmode;
+mod phy;
+mod set;
 mod wowlan;
 
 pub use self::band::{
     Nl80211Band, Nl80211BandInfo, Nl80211BandType, Nl80211BandTypes,
-    Nl80211Frequency, Nl80211FrequencyInfo,
+    Nl80211DfsState, Nl80211Frequency, Nl80211FrequencyInfo,
 };
 pub use self::cipher::Nl80211CipherSuite;
 pub use self::get::Nl80211WiphyGetRequest;
 pub use self::handle::Nl80211WiphyHandle;
 pub use self::ifmode::Nl80211IfMode;
+pub use self::phy::Nl80211Wiphy;
+pub use self::set::{Nl80211TxPowerSetting, Nl80211WiphySetRequest};
 pub use self::wowlan::{
     Nl80211WowlanTcpTrigerSupport, Nl80211WowlanTrigerPatternSupport,
     Nl80211WowlanTrigersSupport,
@@ -0,0 +1,343 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    Nl80211Attr, Nl80211Band, Nl80211CipherSuite, Nl80211Command,
+    Nl80211DfsChannel, Nl80211Error, Nl80211ExtFeature,
+    Nl80211ExtendedCapability, Nl80211Features, Nl80211HtCapabilityMask,
+    Nl80211IfMode, Nl80211IfTypeExtCapa, Nl80211IfaceComb,
+    Nl80211IfaceFrameType, Nl80211InterfaceType, Nl80211Message,
+    Nl80211VhtCapability, Nl80211WowlanTrigersSupport,
+};
+
+/// Wireless physical device, decoded from the reply of
+/// [crate::Nl80211WiphyGetRequest] (equivalent to one entry of `iw phy`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Nl80211Wiphy {
+    pub index: u32,
+    pub name: String,
+    pub generation: Option<u32>,
+    pub bands: Vec<Nl80211Band>,
+    pub supported_iftypes: Vec<Nl80211IfMode>,
+    pub software_iftypes: Vec<Nl80211InterfaceType>,
+    pub supported_commands: Vec<Nl80211Command>,
+    pub interface_combinations: Vec<Nl80211IfaceComb>,
+    pub cipher_suites: Vec<Nl80211CipherSuite>,
+    pub features: Nl80211Features,
+    pub ext_features: Vec<Nl80211ExtFeature>,
+    pub tx_frame_types: Vec<Nl80211IfaceFrameType>,
+    pub rx_frame_types: Vec<Nl80211IfaceFrameType>,
+    pub wowlan_triggers: Vec<Nl80211WowlanTrigersSupport>,
+    pub iftype_ext_cap: Vec<Nl80211IfTypeExtCapa>,
+    pub ext_cap: Option<Nl80211ExtendedCapability>,
+    pub ext_cap_mask: Option<Nl80211ExtendedCapability>,
+    pub ht_capability_mask: Option<Nl80211HtCapabilityMask>,
+    pub vht_cap: Option<Nl80211VhtCapability>,
+    pub vht_cap_mask: Option<Nl80211VhtCapability>,
+    pub max_num_scan_ssids: Option<u8>,
+    pub max_num_sched_scan_ssids: Option<u8>,
+    pub max_scan_ie_len: Option<u16>,
+    pub max_sched_scan_ie_len: Option<u16>,
+    pub max_match_sets: Option<u8>,
+    pub max_num_pmkids: Option<u8>,
+    pub max_num_akm_suites: Option<u16>,
+    pub max_csa_counters: Option<u8>,
+    /// In milliseconds
+    pub max_remain_on_channel_duration: Option<u32>,
+    pub antenna_avail_tx: Option<u32>,
+    pub antenna_avail_rx: Option<u32>,
+    pub antenna_tx: Option<u32>,
+    pub antenna_rx: Option<u32>,
+    pub retry_short: Option<u8>,
+    pub retry_long: Option<u8>,
+    pub frag_threshold: Option<u32>,
+    pub rts_threshold: Option<u32>,
+    pub coverage_class: Option<u8>,
+    pub txq_limit: Option<u32>,
+    pub txq_memory_limit: Option<u32>,
+    pub txq_quantum: Option<u32>,
+    pub offchannel_tx_ok: bool,
+    pub roam_support: bool,
+    pub self_managed_reg: bool,
+}
+
+impl TryFrom<Nl80211Message> for Nl80211Wiphy {
+    type Error = Nl80211Error;
+
+    /// Decode a single reply. For split wiphy dump, please use
+    /// [Nl80211Wiphy::from_split_dump()] instead.
+    fn try_from(msg: Nl80211Message) -> Result<Self, Self::Error> {
+        let index = wiphy_index_of(&msg)?;
+        let mut ret = Self {
+            index,
+            ..Default::default()
+        };
+        ret.merge(msg.attributes);
+        Ok(ret)
+    }
+}
+
+fn wiphy_index_of(msg: &Nl80211Message) -> Result<u32, Nl80211Error> {
+    if msg.cmd != Nl80211Command::NewWiphy {
+        return Err(Nl80211Error::DecodeFailed(DecodeError::from(format!(
+            "Expecting NL80211_CMD_NEW_WIPHY for wiphy, got {:?}",
+            msg.cmd
+        ))));
+    }
+    msg.attributes
+        .iter()
+        .find_map(|attr| {
+            if let Nl80211Attr::Wiphy(d) = attr {
+                Some(*d)
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| {
+            Nl80211Error::DecodeFailed(DecodeError::from(
+                "Wiphy reply does not contain NL80211_ATTR_WIPHY",
+            ))
+        })
+}
+
+impl Nl80211Wiphy {
+    /// Kernel splits the information of a single wiphy into multiple messages
+    /// when [Nl80211Attr::SplitWiphyDump] is requested, each holding
+    /// different attributes and even fragments of the band frequency list.
+    /// This function groups the messages by wiphy index and merges them into
+    /// one [Nl80211Wiphy] per radio, kept in the order kernel replied.
+    pub fn from_split_dump<I>(messages: I) -> Result<Vec<Self>, Nl80211Error>
+    where
+        I: IntoIterator<Item = Nl80211Message>,
+    {
+        let mut wiphys: Vec<Self> = Vec::new();
+        for msg in messages {
+            let index = wiphy_index_of(&msg)?;
+            match wiphys.iter_mut().find(|w| w.index == index) {
+                Some(wiphy) => wiphy.merge(msg.attributes),
+                None => {
+                    let mut wiphy = Self {
+                        index,
+                        ..Default::default()
+                    };
+                    wiphy.merge(msg.attributes);
+                    wiphys.push(wiphy);
+                }
+            }
+        }
+        Ok(wiphys)
+    }
+
+    /// DFS state of the channels requiring radar detection in current
+    /// regulatory domain, e.g. 5GHz channels 52-144 in most countries.
+    /// The wiphy should be retrieved by dump with
+    /// [Nl80211Attr::SplitWiphyDump] to include all the frequencies.
+    pub fn dfs_channels(&self) -> Vec<Nl80211DfsChannel> {
+        self.bands
+            .iter()
+            .flat_map(Nl80211DfsChannel::from_band)
+            .collect()
+    }
+
+    /// Monitor interface can be created with
+    /// [crate::Nl80211MonitorFlags::Active] to ACK the incoming unicast
+    /// frames
+    pub fn supports_active_monitor(&self) -> bool {
+        self.features.contains(Nl80211Features::ActiveMonitor)
+    }
+
+    /// Monitor interface can follow the VHT MU-MIMO groups by
+    /// [Nl80211Attr::MuMimoGroupData] or [Nl80211Attr::MuMimoFollowMacAddr]
+    pub fn supports_mu_mimo_air_sniffer(&self) -> bool {
+        self.ext_features.contains(&Nl80211ExtFeature::MuMimoAirSniffer)
+    }
+
+    /// Merge attributes of a split wiphy dump message into this wiphy
+    fn merge(&mut self, attributes: Vec<Nl80211Attr>) {
+        for attr in attributes {
+            self.apply_attr(attr);
+        }
+    }
+
+    fn merge_band(&mut self, band: Nl80211Band) {
+        match self.bands.iter_mut().find(|b| b.kind == band.kind) {
+            Some(existing) => existing.merge(band),
+            None => self.bands.push(band),
+        }
+    }
+
+    fn apply_attr(&mut self, attr: Nl80211Attr) {
+        match attr {
+            Nl80211Attr::WiphyName(s) => self.name = s,
+            Nl80211Attr::Generation(d) => self.generation = Some(d),
+            Nl80211Attr::WiphyBands(bands) => {
+                for band in bands {
+                    self.merge_band(band);
+                }
+            }
+            Nl80211Attr::SupportedIftypes(d) => self.supported_iftypes = d,
+            Nl80211Attr::SoftwareIftypes(d) => self.software_iftypes = d,
+            Nl80211Attr::SupportedCommand(d) => self.supported_commands = d,
+            Nl80211Attr::InterfaceCombination(d) => {
+                self.interface_combinations = d
+            }
+            Nl80211Attr::CipherSuites(d) => self.cipher_suites = d,
+            Nl80211Attr::Features(d) => self.features = d,
+            Nl80211Attr::ExtFeatures(d) => self.ext_features = d,
+            Nl80211Attr::TxFrameTypes(d) => self.tx_frame_types = d,
+            Nl80211Attr::RxFrameTypes(d) => self.rx_frame_types = d,
+            Nl80211Attr::WowlanTrigersSupport(d) => self.wowlan_triggers = d,
+            Nl80211Attr::IfTypeExtCap(d) => self.iftype_ext_cap = d,
+            Nl80211Attr::ExtCap(d) => self.ext_cap = Some(d),
+            Nl80211Attr::ExtCapMask(d) => self.ext_cap_mask = Some(d),
+            Nl80211Attr::HtCapabilityMask(d) => {
+                self.ht_capability_mask = Some(d)
+            }
+            Nl80211Attr::VhtCap(d) => self.vht_cap = Some(d),
+            Nl80211Attr::VhtCapMask(d) => self.vht_cap_mask = Some(d),
+            Nl80211Attr::MaxNumScanSsids(d) => {
+                self.max_num_scan_ssids = Some(d)
+            }
+            Nl80211Attr::MaxNumSchedScanSsids(d) => {
+                self.max_num_sched_scan_ssids = Some(d)
+            }
+            Nl80211Attr::MaxScanIeLen(d) => self.max_scan_ie_len = Some(d),
+            Nl80211Attr::MaxSchedScanIeLen(d) => {
+                self.max_sched_scan_ie_len = Some(d)
+            }
+            Nl80211Attr::MaxMatchSets(d) => self.max_match_sets = Some(d),
+            Nl80211Attr::MaxNumPmkids(d) => self.max_num_pmkids = Some(d),
+            Nl80211Attr::MaxNumAkmSuites(d) => {
+                self.max_num_akm_suites = Some(d)
+            }
+            Nl80211Attr::MaxCsaCounters(d) => self.max_csa_counters = Some(d),
+            Nl80211Attr::MaxRemainOnChannelDuration(d) => {
+                self.max_remain_on_channel_duration = Some(d)
+            }
+            Nl80211Attr::WiphyAntennaAvailTx(d) => {
+                self.antenna_avail_tx = Some(d)
+            }
+            Nl80211Attr::WiphyAntennaAvailRx(d) => {
+                self.antenna_avail_rx = Some(d)
+            }
+            Nl80211Attr::WiphyAntennaTx(d) => self.antenna_tx = Some(d),
+            Nl80211Attr::WiphyAntennaRx(d) => self.antenna_rx = Some(d),
+            Nl80211Attr::WiphyRetryShort(d) => self.retry_short = Some(d),
+            Nl80211Attr::WiphyRetryLong(d) => self.retry_long = Some(d),
+            Nl80211Attr::WiphyFragThreshold(d) => {
+                self.frag_threshold = Some(d)
+            }
+            Nl80211Attr::WiphyRtsThreshold(d) => self.rts_threshold = Some(d),
+            Nl80211Attr::WiphyCoverageClass(d) => {
+                self.coverage_class = Some(d)
+            }
+            Nl80211Attr::TransmitQueueLimit(d) => self.txq_limit = Some(d),
+            Nl80211Attr::TransmitQueueMemoryLimit(d) => {
+                self.txq_memory_limit = Some(d)
+            }
+            Nl80211Attr::TransmitQueueQuantum(d) => {
+                self.txq_quantum = Some(d)
+            }
+            Nl80211Attr::OffchannelTxOk => self.offchannel_tx_ok = true,
+            Nl80211Attr::RoamSupport => self.roam_support = true,
+            Nl80211Attr::WiphySelfManagedReg => self.self_managed_reg = true,
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Nl80211BandInfo, Nl80211BandType, Nl80211Frequency,
+        Nl80211FrequencyInfo,
+    };
+
+    fn freq(index: u16, freq: u32) -> Nl80211Frequency {
+        Nl80211Frequency {
+            index,
+            info: vec![Nl80211FrequencyInfo::Freq(freq)],
+        }
+    }
+
+    fn message(attributes: Vec<Nl80211Attr>) -> Nl80211Message {
+        Nl80211Message {
+            cmd: Nl80211Command::NewWiphy,
+            attributes,
+        }
+    }
+
+    #[test]
+    fn merge_split_dump_band() {
+        let messages = vec![
+            message(vec![
+                Nl80211Attr::Wiphy(0),
+                Nl80211Attr::WiphyName("phy0".to_string()),
+            ]),
+            message(vec![
+                Nl80211Attr::Wiphy(0),
+                Nl80211Attr::WiphyBands(vec![Nl80211Band {
+                    kind: Nl80211BandType::Band2GHz,
+                    info: vec![
+                        Nl80211BandInfo::HtAmpduFactor(1),
+                        Nl80211BandInfo::Freqs(vec![
+                            freq(0, 2412),
+                            freq(1, 2417),
+                        ]),
+                    ],
+                }]),
+            ]),
+            message(vec![Nl80211Attr::Wiphy(1)]),
+            // The rest of the 2.4 GHz frequencies, the last one of the
+            // previous message is repeated
+            message(vec![
+                Nl80211Attr::Wiphy(0),
+                Nl80211Attr::WiphyBands(vec![
+                    Nl80211Band {
+                        kind: Nl80211BandType::Band2GHz,
+                        info: vec![
+                            Nl80211BandInfo::Freqs(vec![
+                                freq(1, 2417),
+                                freq(2, 2422),
+                            ]),
+                            Nl80211BandInfo::HtAmpduFactor(3),
+                        ],
+                    },
+                    Nl80211Band {
+                        kind: Nl80211BandType::Band5GHz,
+                        info: vec![Nl80211BandInfo::Freqs(vec![freq(0, 5180)])],
+                    },
+                ]),
+            ]),
+        ];
+
+        let wiphys = Nl80211Wiphy::from_split_dump(messages).unwrap();
+        assert_eq!(wiphys.len(), 2);
+        assert_eq!(wiphys[1].index, 1);
+
+        let wiphy = &wiphys[0];
+        assert_eq!(wiphy.index, 0);
+        assert_eq!(wiphy.name, "phy0");
+        assert_eq!(wiphy.bands.len(), 2);
+        assert_eq!(
+            wiphy.bands[0],
+            Nl80211Band {
+                kind: Nl80211BandType::Band2GHz,
+                info: vec![
+                    Nl80211BandInfo::HtAmpduFactor(3),
+                    Nl80211BandInfo::Freqs(vec![
+                        freq(0, 2412),
+                        freq(1, 2417),
+                        freq(2, 2422),
+                    ]),
+                ],
+            }
+        );
+        assert_eq!(wiphy.bands[1].kind, Nl80211BandType::Band5GHz);
+    }
+}
@@ -0,0 +1,295 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{future::Either, stream, TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+use netlink_packet_utils::nla::Nla;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211AttrsBuilder, Nl80211Command,
+    Nl80211Error, Nl80211Handle, Nl80211Message, Nl80211WiphyGetRequest,
+};
+
+const NL80211_TX_POWER_AUTOMATIC: u32 = 0;
+const NL80211_TX_POWER_LIMITED: u32 = 1;
+const NL80211_TX_POWER_FIXED: u32 = 2;
+
+// Distance in meters covered by each coverage class step, IEEE 802.11-2020
+// 9.4.2.8: the air propagation time is 3us per coverage class
+const COVERAGE_CLASS_DISTANCE: u32 = 450;
+
+/// How the TX power level is applied
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211TxPowerSetting {
+    /// Let the driver decide the TX power
+    Automatic,
+    /// Limit the TX power to the level
+    Limited,
+    /// Fix the TX power at the level
+    Fixed,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211TxPowerSetting {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_TX_POWER_AUTOMATIC => Self::Automatic,
+            NL80211_TX_POWER_LIMITED => Self::Limited,
+            NL80211_TX_POWER_FIXED => Self::Fixed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211TxPowerSetting> for u32 {
+    fn from(v: Nl80211TxPowerSetting) -> u32 {
+        match v {
+            Nl80211TxPowerSetting::Automatic => NL80211_TX_POWER_AUTOMATIC,
+            Nl80211TxPowerSetting::Limited => NL80211_TX_POWER_LIMITED,
+            Nl80211TxPowerSetting::Fixed => NL80211_TX_POWER_FIXED,
+            Nl80211TxPowerSetting::Other(d) => d,
+        }
+    }
+}
+
+/// Change the settings of a wiphy (`NL80211_CMD_SET_WIPHY`)
+/// (equivalent to `iw phy PHY set ...`)
+pub struct Nl80211WiphySetRequest {
+    handle: Nl80211Handle,
+    wiphy: u32,
+    attrs: Nl80211AttrsBuilder<Nl80211WiphySetRequest>,
+}
+
+impl Nl80211WiphySetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wiphy: u32) -> Self {
+        Nl80211WiphySetRequest {
+            handle,
+            wiphy,
+            attrs: Nl80211AttrsBuilder::new()
+                .replace(Nl80211Attr::Wiphy(wiphy)),
+        }
+    }
+
+    /// Rename the wiphy
+    /// (equivalent to `iw phy PHY set name NAME`)
+    pub fn with_name(mut self, name: String) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::WiphyName(name));
+        self
+    }
+
+    /// Let the driver decide the TX power
+    /// (equivalent to `iw phy PHY set txpower auto`)
+    pub fn with_tx_power_auto(mut self) -> Self {
+        self.attrs = self
+            .attrs
+            .remove(Nl80211Attr::WiphyTxPowerLevel(0).kind())
+            .replace(Nl80211Attr::WiphyTxPowerSetting(
+                Nl80211TxPowerSetting::Automatic,
+            ));
+        self
+    }
+
+    /// Fix the TX power at the level in mBm (100 * dBm)
+    /// (equivalent to `iw phy PHY set txpower fixed MBM`)
+    pub fn with_tx_power_fixed(mut self, mbm: u32) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::WiphyTxPowerSetting(
+                Nl80211TxPowerSetting::Fixed,
+            ))
+            .replace(Nl80211Attr::WiphyTxPowerLevel(mbm));
+        self
+    }
+
+    /// Limit the TX power to the level in mBm (100 * dBm)
+    /// (equivalent to `iw phy PHY set txpower limit MBM`)
+    pub fn with_tx_power_limit(mut self, mbm: u32) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::WiphyTxPowerSetting(
+                Nl80211TxPowerSetting::Limited,
+            ))
+            .replace(Nl80211Attr::WiphyTxPowerLevel(mbm));
+        self
+    }
+
+    /// Set the RTS threshold in octets, `u32::MAX` disables RTS/CTS
+    /// (equivalent to `iw phy PHY set rts THRESHOLD|off`)
+    pub fn with_rts_threshold(mut self, threshold: u32) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::WiphyRtsThreshold(threshold));
+        self
+    }
+
+    /// Set the fragmentation threshold in octets, `u32::MAX` disables
+    /// fragmentation
+    /// (equivalent to `iw phy PHY set frag THRESHOLD|off`)
+    pub fn with_frag_threshold(mut self, threshold: u32) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::WiphyFragThreshold(threshold));
+        self
+    }
+
+    /// Set the retry limit for frames shorter than or equal to the RTS
+    /// threshold
+    /// (equivalent to `iw phy PHY set retry short LIMIT`)
+    pub fn with_retry_short(mut self, limit: u8) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::WiphyRetryShort(limit));
+        self
+    }
+
+    /// Set the retry limit for frames longer than the RTS threshold
+    /// (equivalent to `iw phy PHY set retry long LIMIT`)
+    pub fn with_retry_long(mut self, limit: u8) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::WiphyRetryLong(limit));
+        self
+    }
+
+    /// Set the coverage class (0-255), each step extends the ACK timeout
+    /// by 3us, i.e. about 450 meters of distance
+    /// (equivalent to `iw phy PHY set coverage CLASS`)
+    pub fn with_coverage_class(mut self, coverage_class: u8) -> Self {
+        self.attrs = self
+            .attrs
+            .remove(Nl80211Attr::WiphyDynAck.kind())
+            .replace(Nl80211Attr::WiphyCoverageClass(coverage_class));
+        self
+    }
+
+    /// Set the coverage class from the distance in meters, capped at the
+    /// maximum coverage class
+    /// (equivalent to `iw phy PHY set distance DISTANCE`)
+    pub fn with_distance(self, meters: u32) -> Self {
+        let coverage_class = meters.saturating_add(COVERAGE_CLASS_DISTANCE - 1)
+            / COVERAGE_CLASS_DISTANCE;
+        self.with_coverage_class(coverage_class.min(u8::MAX.into()) as u8)
+    }
+
+    /// Estimate the ACK timeout dynamically instead of using the coverage
+    /// class, requires [crate::Nl80211Features::AcktoEstimation]
+    /// (equivalent to `iw phy PHY set distance auto`)
+    pub fn with_distance_auto(mut self) -> Self {
+        self.attrs = self
+            .attrs
+            .remove(Nl80211Attr::WiphyCoverageClass(0).kind())
+            .replace(Nl80211Attr::WiphyDynAck);
+        self
+    }
+
+    /// Set the TX and RX antenna bitmaps, `u32::MAX` enables all the
+    /// available antennas. The bitmaps are checked against
+    /// [crate::Nl80211Wiphy::antenna_avail_tx] and
+    /// [crate::Nl80211Wiphy::antenna_avail_rx] before applying.
+    /// (equivalent to `iw phy PHY set antenna TX_BITMAP RX_BITMAP`)
+    pub fn with_antenna(mut self, tx: u32, rx: u32) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::WiphyAntennaTx(tx))
+            .replace(Nl80211Attr::WiphyAntennaRx(rx));
+        self
+    }
+
+    /// Set the maximum number of packets queued per TXQ
+    /// (equivalent to `iw phy PHY set txq limit LIMIT`)
+    pub fn with_txq_limit(mut self, limit: u32) -> Self {
+        self.attrs = self.attrs.replace(Nl80211Attr::TransmitQueueLimit(limit));
+        self
+    }
+
+    /// Set the maximum memory in bytes used by all TXQs
+    /// (equivalent to `iw phy PHY set txq memory_limit LIMIT`)
+    pub fn with_txq_memory_limit(mut self, limit: u32) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::TransmitQueueMemoryLimit(limit));
+        self
+    }
+
+    /// Set the quantum in bytes of the TXQ scheduler
+    /// (equivalent to `iw phy PHY set txq quantum QUANTUM`)
+    pub fn with_txq_quantum(mut self, quantum: u32) -> Self {
+        self.attrs = self
+            .attrs
+            .replace(Nl80211Attr::TransmitQueueQuantum(quantum));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211WiphySetRequest {
+            mut handle,
+            wiphy,
+            attrs,
+        } = self;
+        let attrs = attrs.build();
+
+        if let Err(e) = check_antenna(&handle, wiphy, &attrs).await {
+            return Either::Right(stream::once(futures::future::err(e)));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetWiphy,
+            attributes: attrs,
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        Either::Left(
+            nl80211_execute(&mut handle, nl80211_msg, flags)
+                .await
+                .into_stream(),
+        )
+    }
+}
+
+/// Check the requested antenna bitmaps against the available antennas of
+/// the wiphy
+async fn check_antenna(
+    handle: &Nl80211Handle,
+    wiphy: u32,
+    attrs: &[Nl80211Attr],
+) -> Result<(), Nl80211Error> {
+    let mut tx = None;
+    let mut rx = None;
+    for attr in attrs {
+        match attr {
+            Nl80211Attr::WiphyAntennaTx(d) => tx = Some(*d),
+            Nl80211Attr::WiphyAntennaRx(d) => rx = Some(*d),
+            _ => (),
+        }
+    }
+    if tx.is_none() && rx.is_none() {
+        return Ok(());
+    }
+
+    let wiphys = Nl80211WiphyGetRequest::new(handle.clone())
+        .match_index(wiphy)
+        .collect()
+        .await?;
+    let wiphy = wiphys.iter().find(|w| w.index == wiphy).ok_or_else(|| {
+        Nl80211Error::RequestFailed(format!("Wiphy {wiphy} not found"))
+    })?;
+
+    for (name, mask, avail) in [
+        ("TX", tx, wiphy.antenna_avail_tx),
+        ("RX", rx, wiphy.antenna_avail_rx),
+    ] {
+        let avail = avail.unwrap_or(0);
+        let mask = match mask {
+            Some(d) if d != u32::MAX => d,
+            _ => continue,
+        };
+        if avail == 0 || mask & !avail != 0 {
+            return Err(Nl80211Error::RequestFailed(format!(
+                "{name} antenna bitmap {mask:#x} is not within the available \
+                antennas {avail:#x} of wiphy {}",
+                wiphy.index
+            )));
+        }
+    }
+    Ok(())
+}